@@ -1,6 +1,7 @@
 //! Indexed BAM reader.
 
 mod builder;
+mod reference_sequences;
 
 use std::io::{self, Read};
 
@@ -9,7 +10,10 @@ use noodles_core::Region;
 use noodles_csi::BinningIndex;
 use noodles_sam::{self as sam, alignment::RecordBuf};
 
-pub use self::builder::Builder;
+pub use self::{
+    builder::Builder,
+    reference_sequences::{check_reference_sequences, CheckReferenceSequencesError},
+};
 use super::{
     reader::{Query, RecordBufs, Records},
     Reader,
@@ -77,6 +81,17 @@ where
     pub fn index(&self) -> &dyn BinningIndex {
         &self.index
     }
+
+    /// Checks that the reference sequences in the given header match those in the associated
+    /// index.
+    ///
+    /// See [`check_reference_sequences`].
+    pub fn check_reference_sequences(
+        &self,
+        header: &sam::Header,
+    ) -> Result<(), CheckReferenceSequencesError> {
+        check_reference_sequences(header, &self.index)
+    }
 }
 
 impl<R> IndexedReader<bgzf::Reader<R>>
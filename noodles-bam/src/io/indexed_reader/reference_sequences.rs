@@ -0,0 +1,181 @@
+use std::{error, fmt};
+
+use noodles_csi::BinningIndex;
+use noodles_sam as sam;
+
+/// An error returned when the reference sequences of a header and an index do not match.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CheckReferenceSequencesError {
+    /// The number of reference sequences differs.
+    ///
+    /// This includes the number of reference sequences in the header and in the index,
+    /// respectively.
+    CountMismatch(usize, usize),
+    /// A reference sequence name differs.
+    ///
+    /// This includes the reference sequence index and the names in the header and in the index,
+    /// respectively.
+    NameMismatch(usize, String, String),
+}
+
+impl error::Error for CheckReferenceSequencesError {}
+
+impl fmt::Display for CheckReferenceSequencesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CountMismatch(header_count, index_count) => write!(
+                f,
+                "reference sequence count mismatch: expected {header_count} (header), got {index_count} (index)"
+            ),
+            Self::NameMismatch(i, header_name, index_name) => write!(
+                f,
+                "reference sequence name mismatch at index {i}: expected {header_name} (header), got {index_name} (index)"
+            ),
+        }
+    }
+}
+
+/// Checks that the reference sequences in a header match those in an index.
+///
+/// Records are queried by reference sequence index, so a header whose reference sequences are
+/// ordered differently than the index silently returns the wrong records.
+///
+/// This compares the number of reference sequences and, if the index has a header with
+/// reference sequence names (e.g., a CSI with tabix metadata), their names. A BAI does not store
+/// reference sequence names or lengths, so only the counts are compared.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bam::{bai, io::indexed_reader::check_reference_sequences};
+/// use noodles_sam as sam;
+///
+/// let header = sam::Header::default();
+/// let index = bai::Index::default();
+/// assert!(check_reference_sequences(&header, &index).is_ok());
+/// ```
+pub fn check_reference_sequences(
+    header: &sam::Header,
+    index: &dyn BinningIndex,
+) -> Result<(), CheckReferenceSequencesError> {
+    let reference_sequences = header.reference_sequences();
+
+    let header_count = reference_sequences.len();
+    let index_count = index.reference_sequences().count();
+
+    if header_count != index_count {
+        return Err(CheckReferenceSequencesError::CountMismatch(
+            header_count,
+            index_count,
+        ));
+    }
+
+    let Some(index_header) = index.header() else {
+        return Ok(());
+    };
+
+    let index_names = index_header.reference_sequence_names();
+
+    if index_names.is_empty() {
+        return Ok(());
+    }
+
+    for (i, (header_name, index_name)) in reference_sequences.keys().zip(index_names).enumerate() {
+        if header_name.as_slice() != index_name.as_bytes() {
+            return Err(CheckReferenceSequencesError::NameMismatch(
+                i,
+                header_name.to_string(),
+                index_name.clone(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_csi::{
+        self as csi,
+        binning_index::index::{
+            header::ReferenceSequenceNames, reference_sequence::index::LinearIndex,
+            ReferenceSequence,
+        },
+    };
+    use sam::header::record::value::{map::ReferenceSequence as ReferenceSequenceMap, Map};
+
+    use super::*;
+    use crate::bai;
+
+    const LENGTH: NonZeroUsize = match NonZeroUsize::new(8) {
+        Some(n) => n,
+        None => unreachable!(),
+    };
+
+    fn build_header(names: &[&str]) -> sam::Header {
+        names
+            .iter()
+            .fold(sam::Header::builder(), |builder, name| {
+                builder.add_reference_sequence(*name, Map::<ReferenceSequenceMap>::new(LENGTH))
+            })
+            .build()
+    }
+
+    fn build_reference_sequences(n: usize) -> Vec<ReferenceSequence<LinearIndex>> {
+        (0..n)
+            .map(|_| ReferenceSequence::new(Default::default(), LinearIndex::default(), None))
+            .collect()
+    }
+
+    #[test]
+    fn test_check_reference_sequences_with_bai() {
+        let index = bai::Index::builder()
+            .set_reference_sequences(build_reference_sequences(2))
+            .build();
+
+        let header = build_header(&["sq0", "sq1"]);
+        assert_eq!(check_reference_sequences(&header, &index), Ok(()));
+
+        let header = build_header(&["sq0", "sq1", "sq2"]);
+        assert_eq!(
+            check_reference_sequences(&header, &index),
+            Err(CheckReferenceSequencesError::CountMismatch(3, 2))
+        );
+    }
+
+    #[test]
+    fn test_check_reference_sequences_with_reference_sequence_names() {
+        let reference_sequence_names: ReferenceSequenceNames =
+            [String::from("sq0"), String::from("sq1")]
+                .into_iter()
+                .collect();
+
+        let index = csi::Index::builder()
+            .set_header(
+                csi::binning_index::index::Header::builder()
+                    .set_reference_sequence_names(reference_sequence_names)
+                    .build(),
+            )
+            .set_reference_sequences(
+                (0..2)
+                    .map(|_| ReferenceSequence::new(Default::default(), Default::default(), None))
+                    .collect(),
+            )
+            .build();
+
+        let header = build_header(&["sq0", "sq1"]);
+        assert_eq!(check_reference_sequences(&header, &index), Ok(()));
+
+        let header = build_header(&["sq1", "sq0"]);
+        assert_eq!(
+            check_reference_sequences(&header, &index),
+            Err(CheckReferenceSequencesError::NameMismatch(
+                0,
+                String::from("sq1"),
+                String::from("sq0")
+            ))
+        );
+    }
+}
@@ -244,6 +244,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_alignment_record_with_reference_sequence_ids(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use sam::header::record::value::{map::ReferenceSequence, Map};
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let mut writer = Writer::new(io::sink());
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_id(0)
+            .set_mate_reference_sequence_id(0)
+            .build();
+        writer.write_alignment_record(&header, &record)?;
+
+        let record = RecordBuf::builder().set_reference_sequence_id(1).build();
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_id(0)
+            .set_mate_reference_sequence_id(1)
+            .build();
+        assert!(matches!(
+            writer.write_alignment_record(&header, &record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_alignment_record_with_sequence_length_less_than_quality_scores_length(
     ) -> Result<(), Box<dyn std::error::Error>> {
@@ -167,6 +167,24 @@ impl Record {
         self.0.sequence()
     }
 
+    /// Complements the bases of the sequence in place.
+    ///
+    /// The packed 4-bit bases are complemented without being decoded, and the order of the bases
+    /// is preserved, i.e., the sequence is _not_ reversed. IUPAC ambiguity codes are complemented
+    /// to the codes of their complementary bases, e.g., `R` (`A` or `G`) to `Y` (`C` or `T`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bam as bam;
+    /// let mut record = bam::Record::default();
+    /// record.complement_sequence();
+    /// assert!(record.sequence().is_empty());
+    /// ```
+    pub fn complement_sequence(&mut self) {
+        self.0.complement_sequence();
+    }
+
     /// Returns the quality scores.
     ///
     /// # Examples
@@ -342,4 +360,53 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_convert_unmapped_record_to_sam() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_sam::{
+            alignment::{io::Write, RecordBuf},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        static DATA: &[u8] = &[
+            0xff, 0xff, 0xff, 0xff, // ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // pos = -1
+            0x03, // l_read_name = 3
+            0xff, // mapq = 255
+            0x48, 0x12, // bin = 4680
+            0x00, 0x00, // n_cigar_op = 0
+            0x0d, 0x00, // flag = 13
+            0x00, 0x00, 0x00, 0x00, // l_seq = 0
+            0xff, 0xff, 0xff, 0xff, // next_ref_id = -1
+            0xff, 0xff, 0xff, 0xff, // next_pos = -1
+            0x00, 0x00, 0x00, 0x00, // tlen = 0
+            b'r', b'0', 0x00, // read_name = "r0\x00"
+        ];
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let record = Fields::try_from(Vec::from(DATA)).map(Record)?;
+
+        let actual = RecordBuf::try_from_alignment_record(&header, &record)?;
+        let expected = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::SEGMENTED | Flags::UNMAPPED | Flags::MATE_UNMAPPED)
+            .build();
+        assert_eq!(actual, expected);
+
+        let mut writer = sam::io::Writer::new(Vec::new());
+        writer.write_alignment_record(&header, &record)?;
+        assert_eq!(writer.get_ref(), b"r0\t13\t*\t0\t255\t*\t*\t0\t0\t*\t*\n");
+
+        Ok(())
+    }
 }
@@ -111,6 +111,13 @@ impl Fields {
         Sequence::new(src, base_count)
     }
 
+    pub(super) fn complement_sequence(&mut self) {
+        let quality_scores_range = self.bounds.quality_scores_range();
+        let base_count = quality_scores_range.end - quality_scores_range.start;
+        let src = &mut self.buf[self.bounds.sequence_range()];
+        super::sequence::complement(src, base_count);
+    }
+
     pub(super) fn quality_scores(&self) -> QualityScores<'_> {
         let src = &self.buf[self.bounds.quality_scores_range()];
         QualityScores::new(src)
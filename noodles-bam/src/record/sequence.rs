@@ -138,6 +138,44 @@ impl<'a> From<Sequence<'a>> for sam::alignment::record_buf::Sequence {
     }
 }
 
+// Complementing a 4-bit base reverses its bits, e.g., `A` (0b0001) to `T` (0b1000) and `M` (A or
+// C, 0b0011) to `K` (G or T, 0b1100).
+const COMPLEMENTS: [u8; 16] = [
+    0b0000, // = => =
+    0b1000, // A => T
+    0b0100, // C => G
+    0b1100, // M => K
+    0b0010, // G => C
+    0b1010, // R => Y
+    0b0110, // S => S
+    0b1110, // V => B
+    0b0001, // T => A
+    0b1001, // W => W
+    0b0101, // Y => R
+    0b1101, // H => D
+    0b0011, // K => M
+    0b1011, // D => H
+    0b0111, // B => V
+    0b1111, // N => N
+];
+
+pub(super) fn complement(src: &mut [u8], base_count: usize) {
+    let (full, rest) = src.split_at_mut(base_count / 2);
+
+    for b in full {
+        let (l, r) = (*b >> 4, *b & 0x0f);
+        *b = (COMPLEMENTS[usize::from(l)] << 4) | COMPLEMENTS[usize::from(r)];
+    }
+
+    // The low nibble of the last byte is padding when the base count is odd.
+    if base_count % 2 == 1 {
+        if let Some(b) = rest.first_mut() {
+            let l = *b >> 4;
+            *b = (COMPLEMENTS[usize::from(l)] << 4) | (*b & 0x0f);
+        }
+    }
+}
+
 fn decode_base(n: u8) -> u8 {
     match n & 0x0f {
         0 => b'=',
@@ -173,6 +211,26 @@ mod tests {
         assert!(sequence.get(3).is_none());
     }
 
+    #[test]
+    fn test_complement() {
+        // ATGC
+        let mut src = [0x18, 0x42];
+        complement(&mut src, 4);
+        // TACG
+        assert_eq!(src, [0x81, 0x24]);
+
+        // ACG
+        let mut src = [0x12, 0x40];
+        complement(&mut src, 3);
+        // TGC
+        assert_eq!(src, [0x84, 0x20]);
+
+        let mut src: [u8; 16] = std::array::from_fn(|i| (i as u8) << 4);
+        complement(&mut src, 31);
+        let actual: Vec<_> = Sequence::new(&src, 31).iter().step_by(2).collect();
+        assert_eq!(actual, b"=TGKCYSBAWRDMHVN");
+    }
+
     #[test]
     fn test_split_at_checked() {
         let src = [0x10];
@@ -28,6 +28,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut query = reader.query(&header, &index, &region)?;
 
     let mut writer = vcf::r#async::io::Writer::new(io::stdout());
+    writer.write_header(&header).await?;
 
     while let Some(record) = query.try_next().await? {
         writer.write_variant_record(&header, &record).await?;
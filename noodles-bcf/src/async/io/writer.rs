@@ -11,6 +11,7 @@ use crate::Record;
 pub struct Writer<W> {
     inner: W,
     string_maps: StringMaps,
+    is_header_written: bool,
     buf: Vec<u8>,
 }
 
@@ -62,6 +63,9 @@ where
 
     /// Writes a VCF header.
     ///
+    /// The header can only be written once, and it must be written before any records. Writing it
+    /// again returns an [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -80,16 +84,29 @@ where
     /// # }
     /// ```
     pub async fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        if self.is_header_written {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header already written",
+            ));
+        }
+
         write_file_format(&mut self.inner).await?;
 
         self.string_maps = StringMaps::try_from(header)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        write_header(&mut self.inner, header).await
+        write_header(&mut self.inner, header).await?;
+        self.is_header_written = true;
+
+        Ok(())
     }
 
     /// Writes a record.
     ///
+    /// The header must be written first (see [`Self::write_header`]). Otherwise, this returns an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -120,6 +137,9 @@ where
 
     /// Writes a variant record.
     ///
+    /// The header must be written first (see [`Self::write_header`]). Otherwise, this returns an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -156,10 +176,23 @@ where
     ) -> io::Result<()> {
         use crate::io::writer::write_record;
 
+        self.validate_header_is_written()?;
+
         self.buf.clear();
         write_record(&mut self.buf, header, &self.string_maps, record)?;
         self.inner.write_all(&self.buf).await
     }
+
+    fn validate_header_is_written(&self) -> io::Result<()> {
+        if self.is_header_written {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header not written",
+            ))
+        }
+    }
 }
 
 impl<W> Writer<bgzf::AsyncWriter<W>>
@@ -179,6 +212,7 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             string_maps: StringMaps::default(),
+            is_header_written: false,
             buf: Vec::new(),
         }
     }
@@ -219,4 +253,41 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_write_header_twice() -> io::Result<()> {
+        let header = vcf::Header::default();
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_header(&header).await?;
+
+        assert!(matches!(
+            writer.write_header(&header).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_record_before_header() -> io::Result<()> {
+        let header = vcf::Header::default();
+        let mut writer = Writer::from(Vec::new());
+
+        assert!(matches!(
+            writer.write_record(&header, &Record::default()).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            writer
+                .write_variant_record(&header, &vcf::variant::RecordBuf::default())
+                .await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(writer.get_ref().is_empty());
+
+        Ok(())
+    }
 }
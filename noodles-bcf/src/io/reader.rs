@@ -17,7 +17,7 @@ use std::{
 use byteorder::ReadBytesExt;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::index::reference_sequence::bin::Chunk, BinningIndex};
 use noodles_vcf::{self as vcf, header::string_maps::ContigStringMap, variant::RecordBuf};
 
 use self::{header::read_header, record::read_record, record_buf::read_record_buf};
@@ -29,6 +29,8 @@ use crate::Record;
 pub struct Reader<R> {
     inner: R,
     buf: Vec<u8>,
+    lenient_trailing: bool,
+    is_truncated: bool,
 }
 
 impl<R> Reader<R>
@@ -82,6 +84,11 @@ where
     /// This verifies the BCF magic number, discards the file format version, and reads and parses
     /// the raw VCF header. Associated string maps are also built from the raw header.
     ///
+    /// Record reference sequence IDs (`rid`) index into the contig string map
+    /// ([`vcf::Header::string_maps`]), which is ordered by the `IDX` fields of the contig header
+    /// records, if present. This can differ from the order of [`vcf::Header::contigs`], which is
+    /// always the declaration order.
+    ///
     /// The position of the stream is expected to be at the start.
     ///
     /// # Examples
@@ -109,6 +116,10 @@ where
     /// If successful, the record size is returned. If a record size of 0 is returned, the stream
     /// reached EOF.
     ///
+    /// If the reader is lenient with a truncated trailing record (see
+    /// [`Builder::set_lenient_trailing`]), a truncated record returns an
+    /// [`io::ErrorKind::UnexpectedEof`] error once, and subsequent reads return 0.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -128,7 +139,12 @@ where
         header: &vcf::Header,
         record: &mut RecordBuf,
     ) -> io::Result<usize> {
-        read_record_buf(&mut self.inner, header, &mut self.buf, record)
+        if self.is_truncated {
+            return Ok(0);
+        }
+
+        let result = read_record_buf(&mut self.inner, header, &mut self.buf, record);
+        self.check_truncation(result)
     }
 
     /// Reads a single record without eagerly decoding (most of) its fields.
@@ -141,6 +157,10 @@ where
     /// If successful, the record size is returned. If a record size of 0 is returned, the stream
     /// reached EOF.
     ///
+    /// If the reader is lenient with a truncated trailing record (see
+    /// [`Builder::set_lenient_trailing`]), a truncated record returns an
+    /// [`io::ErrorKind::UnexpectedEof`] error once, and subsequent reads return 0.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -155,7 +175,22 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        if self.is_truncated {
+            return Ok(0);
+        }
+
+        let result = read_record(&mut self.inner, record);
+        self.check_truncation(result)
+    }
+
+    fn check_truncation(&mut self, result: io::Result<usize>) -> io::Result<usize> {
+        if let Err(e) = &result {
+            if self.lenient_trailing && e.kind() == io::ErrorKind::UnexpectedEof {
+                self.is_truncated = true;
+            }
+        }
+
+        result
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -229,6 +264,53 @@ where
     }
 }
 
+impl<R> Reader<R>
+where
+    R: bgzf::io::Read,
+{
+    /// Returns an iterator over lazy records and their chunks starting from the current stream
+    /// position.
+    ///
+    /// A chunk is the range of BGZF virtual positions of the record, i.e., from the start of the
+    /// record to the start of the next record. This can be used to build an index while reading.
+    ///
+    /// The stream is expected to be directly after the header or at the start of another record.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_bcf as bcf;
+    ///
+    /// let mut reader = File::open("sample.bcf").map(bcf::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.records_with_chunks() {
+    ///     let (record, chunk) = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn records_with_chunks(
+        &mut self,
+    ) -> impl Iterator<Item = io::Result<(Record, Chunk)>> + '_ {
+        let mut record = Record::default();
+
+        iter::from_fn(move || {
+            let start = self.inner.virtual_position();
+
+            match self.read_record(&mut record) {
+                Ok(0) => None,
+                Ok(_) => {
+                    let end = self.inner.virtual_position();
+                    Some(Ok((record.clone(), Chunk::new(start, end))))
+                }
+                Err(e) => Some(Err(e)),
+            }
+        })
+    }
+}
+
 impl<R> Reader<R>
 where
     R: bgzf::io::BufRead + bgzf::io::Seek,
@@ -283,6 +365,8 @@ impl<R> From<R> for Reader<R> {
         Self {
             inner,
             buf: Vec::new(),
+            lenient_trailing: false,
+            is_truncated: false,
         }
     }
 }
@@ -376,6 +460,171 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_read_header_with_contig_indices() -> io::Result<()> {
+        use noodles_vcf::variant::io::Write;
+
+        use crate::io::Writer;
+
+        const SRC: &str = "##fileformat=VCFv4.3
+##contig=<ID=sq0,IDX=1>
+##contig=<ID=sq1,IDX=0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        let header: vcf::Header = SRC
+            .parse()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq1")
+            .set_variant_start(noodles_core::Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_variant_record(&header, &record)?;
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let header = reader.read_header()?;
+
+        let declaration_order: Vec<_> = header.contigs().keys().map(String::as_str).collect();
+        assert_eq!(declaration_order, ["sq0", "sq1"]);
+
+        let contig_string_map = header.string_maps().contigs();
+        assert_eq!(contig_string_map.get_index(0), Some("sq1"));
+        assert_eq!(contig_string_map.get_index(1), Some("sq0"));
+
+        let mut record = Record::default();
+        reader.read_record(&mut record)?;
+        assert_eq!(record.reference_sequence_id()?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_chunks() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_vcf::{
+            header::record::value::{map::Contig, Map},
+            variant::io::Write,
+        };
+
+        use crate::io::Writer;
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for start in [1, 8, 13] {
+            let record = RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::try_from(start)?)
+                .set_reference_bases("A")
+                .build();
+
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        let data = writer.into_inner().finish()?;
+
+        let mut reader = Reader::new(io::Cursor::new(&data[..]));
+        reader.read_header()?;
+
+        let header_end = reader.get_ref().virtual_position();
+
+        let entries: Vec<_> = reader.records_with_chunks().collect::<io::Result<_>>()?;
+        assert_eq!(entries.len(), 3);
+
+        assert_eq!(entries[0].1.start(), header_end);
+
+        for window in entries.windows(2) {
+            assert_eq!(window[0].1.end(), window[1].1.start());
+        }
+
+        for (expected, chunk) in &entries {
+            reader.get_mut().seek(chunk.start())?;
+
+            let mut record = Record::default();
+            reader.read_record(&mut record)?;
+
+            assert_eq!(&record, expected);
+            assert_eq!(reader.get_ref().virtual_position(), chunk.end());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_lenient_trailing() -> Result<(), Box<dyn std::error::Error>> {
+        use noodles_core::Position;
+        use noodles_vcf::{
+            header::record::value::{map::Contig, Map},
+            variant::io::Write,
+        };
+
+        use crate::io::{writer, CompressionMethod};
+
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let mut data = Vec::new();
+
+        {
+            let mut writer = writer::Builder::default()
+                .set_compression_method(CompressionMethod::None)
+                .build_from_writer(&mut data);
+
+            writer.write_header(&header)?;
+
+            for start in [1, 8, 13] {
+                let record = RecordBuf::builder()
+                    .set_reference_sequence_name("sq0")
+                    .set_variant_start(Position::try_from(start)?)
+                    .set_reference_bases("A")
+                    .build();
+
+                writer.write_variant_record(&header, &record)?;
+            }
+        }
+
+        // Truncates the last record.
+        data.truncate(data.len() - 4);
+
+        let mut reader = Builder::default()
+            .set_compression_method(CompressionMethod::None)
+            .set_lenient_trailing(true)
+            .build_from_reader(&data[..])?;
+
+        let header = reader.read_header()?;
+
+        let mut records = reader.record_bufs(&header);
+
+        let record = records.next().transpose()?.ok_or("missing record")?;
+        assert_eq!(record.variant_start(), Some(Position::try_from(1)?));
+
+        let record = records.next().transpose()?.ok_or("missing record")?;
+        assert_eq!(record.variant_start(), Some(Position::try_from(8)?));
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        assert!(records.next().is_none());
+
+        let mut record = Record::default();
+        assert_eq!(reader.read_record(&mut record)?, 0);
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_format_version() -> io::Result<()> {
         let data = [0x02, 0x01];
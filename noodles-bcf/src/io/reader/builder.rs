@@ -13,6 +13,7 @@ use crate::io::CompressionMethod;
 #[derive(Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    lenient_trailing: bool,
 }
 
 impl Builder {
@@ -29,6 +30,26 @@ impl Builder {
         self
     }
 
+    /// Sets whether to be lenient with a truncated trailing record.
+    ///
+    /// When enabled, a record that is truncated by the end of the stream returns a single
+    /// [`io::ErrorKind::UnexpectedEof`] error, after which the reader is at EOF. All complete
+    /// records before it are still read, and record iterators end after the error. This is useful
+    /// for streams that may be cut off, e.g., a file that is still being written.
+    ///
+    /// By default, this is disabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bcf::io::reader::Builder;
+    /// let builder = Builder::default().set_lenient_trailing(true);
+    /// ```
+    pub fn set_lenient_trailing(mut self, lenient_trailing: bool) -> Self {
+        self.lenient_trailing = lenient_trailing;
+        self
+    }
+
     /// Builds a BCF reader from a path.
     ///
     /// # Examples
@@ -64,6 +85,9 @@ impl Builder {
             Some(CompressionMethod::None) => Box::new(reader),
         };
 
-        Ok(Reader::from(inner))
+        let mut reader = Reader::from(inner);
+        reader.lenient_trailing = self.lenient_trailing;
+
+        Ok(reader)
     }
 }
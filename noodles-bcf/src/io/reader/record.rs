@@ -31,7 +31,7 @@ where
     Ok(l_shared + l_indiv)
 }
 
-fn read_site_length<R>(reader: &mut R) -> io::Result<usize>
+pub(super) fn read_site_length<R>(reader: &mut R) -> io::Result<usize>
 where
     R: Read,
 {
@@ -316,6 +316,42 @@ pub(crate) mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_defers_info_and_samples_decoding() -> Result<(), Box<dyn std::error::Error>>
+    {
+        use noodles_core::Position;
+        use noodles_vcf::{self as vcf, variant::record::Info};
+
+        // Both the first INFO field key and the first FORMAT key have an invalid type (6).
+        let mut data = DATA;
+        data[44] = 0x16;
+        data[59] = 0x16;
+
+        let mut reader = &data[..];
+        let mut record = Record::default();
+        read_record(&mut reader, &mut record)?;
+
+        assert_eq!(record.reference_sequence_id()?, 1);
+        assert_eq!(record.variant_start().transpose()?, Position::new(101));
+        assert_eq!(record.quality_score()?, Some(30.1));
+
+        let header = vcf::Header::default();
+
+        let info = record.info();
+        assert!(matches!(
+            info.iter(&header).next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        let samples = record.samples()?;
+        assert!(samples
+            .series()
+            .next()
+            .is_some_and(|result| result.is_err()));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_record_at_eof() -> io::Result<()> {
         let data = [];
@@ -22,6 +22,7 @@ pub(crate) const MINOR: u8 = 2;
 pub struct Writer<W> {
     inner: W,
     string_maps: StringMaps,
+    is_header_written: bool,
 }
 
 impl<W> Writer<W>
@@ -69,6 +70,9 @@ where
 
     /// Writes a VCF header.
     ///
+    /// The header can only be written once, and it must be written before any records. Writing it
+    /// again returns an [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -83,16 +87,29 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
+        if self.is_header_written {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header already written",
+            ));
+        }
+
         write_file_format(&mut self.inner)?;
 
         self.string_maps = StringMaps::try_from(header)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
 
-        write_header(&mut self.inner, header)
+        write_header(&mut self.inner, header)?;
+        self.is_header_written = true;
+
+        Ok(())
     }
 
     /// Writes a record.
     ///
+    /// The header must be written first (see [`Self::write_header`]). Otherwise, this returns an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -121,8 +138,70 @@ where
     /// # Ok::<_, Box<dyn std::error::Error>>(())
     /// ```
     pub fn write_record(&mut self, header: &vcf::Header, record: &Record) -> io::Result<()> {
+        self.validate_header_is_written()?;
         write_record(&mut self.inner, header, &self.string_maps, record)
     }
+
+    /// Writes a raw VCF record line.
+    ///
+    /// This is a faster path for converting VCF to BCF than reading the line into a
+    /// [`vcf::variant::RecordBuf`] and writing it. The line is only split into fields, and each
+    /// field is decoded as it is encoded, without building owned INFO fields or samples.
+    ///
+    /// The line may include a trailing newline. Any data after the line break returns an
+    /// [`io::ErrorKind::InvalidInput`] error. The header must be written first (see
+    /// [`Self::write_header`]). Otherwise, this returns an [`io::ErrorKind::InvalidInput`] error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bcf as bcf;
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Contig, Map},
+    /// };
+    ///
+    /// let mut writer = bcf::io::Writer::new(io::sink());
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig("sq0", Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// writer.write_header(&header)?;
+    /// writer.write_vcf_line(&header, "sq0\t1\t.\tA\t.\t.\t.\t.")?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_vcf_line(&mut self, header: &vcf::Header, line: &str) -> io::Result<()> {
+        self.validate_header_is_written()?;
+
+        let mut reader = vcf::io::Reader::new(line.as_bytes());
+        let mut record = vcf::Record::default();
+
+        if reader.read_record(&mut record)? == 0 {
+            return Err(io::Error::new(io::ErrorKind::InvalidInput, "empty line"));
+        }
+
+        if !reader.get_ref().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "trailing data after record line",
+            ));
+        }
+
+        write_record(&mut self.inner, header, &self.string_maps, &record)
+    }
+
+    fn validate_header_is_written(&self) -> io::Result<()> {
+        if self.is_header_written {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header not written",
+            ))
+        }
+    }
 }
 
 impl<W> Writer<bgzf::Writer<W>>
@@ -168,6 +247,7 @@ impl<W> From<W> for Writer<W> {
         Self {
             inner,
             string_maps: StringMaps::default(),
+            is_header_written: false,
         }
     }
 }
@@ -185,6 +265,7 @@ where
         header: &vcf::Header,
         record: &dyn vcf::variant::Record,
     ) -> io::Result<()> {
+        self.validate_header_is_written()?;
         write_record(&mut self.inner, header, &self.string_maps, record)
     }
 }
@@ -206,6 +287,106 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_write_header_with_a_duplicate_string_map_position() {
+        use vcf::{
+            header::{
+                record::value::{map::Info, Map},
+                ParseError,
+            },
+            variant::record::info::field::key,
+        };
+
+        let mut ns = Map::<Info>::from(key::SAMPLES_WITH_DATA_COUNT);
+        *ns.idx_mut() = Some(1);
+
+        let mut dp = Map::<Info>::from(key::TOTAL_DEPTH);
+        *dp.idx_mut() = Some(1);
+
+        let header = vcf::Header::builder()
+            .add_info(key::SAMPLES_WITH_DATA_COUNT, ns)
+            .add_info(key::TOTAL_DEPTH, dp)
+            .build();
+
+        let mut writer = Writer::from(Vec::new());
+
+        let e = writer.write_header(&header).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidInput);
+        assert!(matches!(
+            e.get_ref().and_then(|e| e.downcast_ref::<ParseError>()),
+            Some(ParseError::DuplicateStringMapPosition(1, existing_id, id))
+                if existing_id == "NS" && id == "DP"
+        ));
+    }
+
+    #[test]
+    fn test_write_vcf_line() -> Result<(), Box<dyn std::error::Error>> {
+        use vcf::variant::{io::Write, RecordBuf};
+
+        const HEADER: &str = "\
+##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description=\"Number of samples with data\">
+##INFO=<ID=AF,Number=A,Type=Float,Description=\"Allele frequency\">
+##INFO=<ID=DB,Number=0,Type=Flag,Description=\"dbSNP membership\">
+##FILTER=<ID=q10,Description=\"Quality below 10\">
+##FORMAT=<ID=GT,Number=1,Type=String,Description=\"Genotype\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Genotype quality\">
+##FORMAT=<ID=HQ,Number=2,Type=Integer,Description=\"Haplotype quality\">
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+";
+
+        const LINES: &[&str] = &[
+            "sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t0|0\t./.",
+            "sq0\t8\tnd0;nd1\tAC\tA,ACT\t13.5\tPASS\tNS=2;AF=0.25,0.5;DB\tGT:GQ:HQ\t0|1:21:5,8\t1/2:.:.",
+            "sq1\t13\t.\tG\tT\t5\tq10\tNS=1\tGT:GQ\t0/1:3\t./.:.\n",
+        ];
+
+        let header: vcf::Header = HEADER.parse()?;
+
+        let mut expected_writer = Writer::from(Vec::new());
+        expected_writer.write_header(&header)?;
+
+        let mut record = RecordBuf::default();
+
+        for line in LINES {
+            let mut reader = vcf::io::Reader::new(line.as_bytes());
+            reader.read_record_buf(&header, &mut record)?;
+            expected_writer.write_variant_record(&header, &record)?;
+        }
+
+        let mut writer = Writer::from(Vec::new());
+        writer.write_header(&header)?;
+
+        for line in LINES {
+            writer.write_vcf_line(&header, line)?;
+        }
+
+        assert_eq!(writer.get_ref(), expected_writer.get_ref());
+
+        assert!(matches!(
+            writer.write_vcf_line(&header, ""),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        let len = writer.get_ref().len();
+
+        for line in [
+            "sq0\t1\t.\tA\t.\t.\t.\t.\nsq0\t8\t.\tA\t.\t.\t.\t.",
+            "sq0\t1\t.\tA\t.\t.\t.\t.\n\n",
+        ] {
+            assert!(matches!(
+                writer.write_vcf_line(&header, line),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        assert_eq!(writer.get_ref().len(), len);
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_file_format() -> io::Result<()> {
         let mut buf = Vec::new();
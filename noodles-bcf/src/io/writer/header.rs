@@ -25,9 +25,18 @@ where
 }
 
 pub(crate) fn serialize_header(header: &vcf::Header) -> io::Result<Vec<u8>> {
-    let mut writer = vcf::io::Writer::new(Vec::new());
+    let mut buf = Vec::new();
+
+    let mut writer = vcf::io::writer::Builder::default()
+        .set_compression_method(vcf::io::CompressionMethod::None)
+        .set_idx_fields(true)
+        .build_from_writer(&mut buf);
+
     writer.write_header(header)?;
-    Ok(writer.into_inner())
+    writer.get_mut().flush()?;
+    drop(writer);
+
+    Ok(buf)
 }
 
 #[cfg(test)]
@@ -49,4 +58,24 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_serialize_header_with_idx_fields() -> io::Result<()> {
+        use vcf::header::record::value::{map::Contig, Map};
+
+        let mut contig = Map::<Contig>::new();
+        *contig.idx_mut() = Some(1);
+
+        let header = vcf::Header::builder().add_contig("sq0", contig).build();
+
+        let actual = serialize_header(&header)?;
+        let expected = b"##fileformat=VCFv4.4
+##contig=<ID=sq0,IDX=1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -7,6 +7,7 @@ pub mod r#async;
 
 pub mod io;
 pub mod record;
+pub mod sentinel;
 
 pub use self::record::Record;
 
@@ -23,6 +23,12 @@ pub use self::{
 };
 
 /// A BCF record.
+///
+/// The record holds the raw encoded site and samples data. Only the bounds of the variable-length
+/// site fields preceding INFO are indexed when a record is read, so fixed fields (e.g., the
+/// reference sequence ID, position, and quality score) are read directly from the buffer. INFO
+/// fields and samples are decoded only when iterated or queried, so records that are filtered
+/// using only leading fields never pay for decoding them.
 #[derive(Clone, Default, PartialEq)]
 pub struct Record(Fields);
 
@@ -1,3 +1,5 @@
+use crate::sentinel::{INT16_END_OF_VECTOR, INT16_MISSING};
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int16 {
@@ -17,11 +19,11 @@ impl Int16 {
 
 impl From<i16> for Int16 {
     fn from(value: i16) -> Self {
-        match value as u16 {
-            0x8000 => Self::Missing,
-            0x8001 => Self::EndOfVector,
-            0x8002..=0x8007 => Self::Reserved(value),
-            _ => Self::Value(value),
+        match value {
+            INT16_MISSING => Self::Missing,
+            INT16_END_OF_VECTOR => Self::EndOfVector,
+            n if n < Self::MIN_VALUE => Self::Reserved(n),
+            n => Self::Value(n),
         }
     }
 }
@@ -29,8 +31,8 @@ impl From<i16> for Int16 {
 impl From<Int16> for i16 {
     fn from(value: Int16) -> Self {
         match value {
-            Int16::Missing => -32768,
-            Int16::EndOfVector => -32767,
+            Int16::Missing => INT16_MISSING,
+            Int16::EndOfVector => INT16_END_OF_VECTOR,
             Int16::Value(n) | Int16::Reserved(n) => n,
         }
     }
@@ -1,3 +1,5 @@
+use crate::sentinel::{INT32_END_OF_VECTOR, INT32_MISSING};
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int32 {
@@ -17,11 +19,11 @@ impl Int32 {
 
 impl From<i32> for Int32 {
     fn from(value: i32) -> Self {
-        match value as u32 {
-            0x80000000 => Self::Missing,
-            0x80000001 => Self::EndOfVector,
-            0x80000002..=0x80000007 => Self::Reserved(value),
-            _ => Self::Value(value),
+        match value {
+            INT32_MISSING => Self::Missing,
+            INT32_END_OF_VECTOR => Self::EndOfVector,
+            n if n < Self::MIN_VALUE => Self::Reserved(n),
+            n => Self::Value(n),
         }
     }
 }
@@ -29,8 +31,8 @@ impl From<i32> for Int32 {
 impl From<Int32> for i32 {
     fn from(value: Int32) -> Self {
         match value {
-            Int32::Missing => -2147483648,
-            Int32::EndOfVector => -2147483647,
+            Int32::Missing => INT32_MISSING,
+            Int32::EndOfVector => INT32_END_OF_VECTOR,
             Int32::Value(n) | Int32::Reserved(n) => n,
         }
     }
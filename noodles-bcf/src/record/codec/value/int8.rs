@@ -1,3 +1,5 @@
+use crate::sentinel::{INT8_END_OF_VECTOR, INT8_MISSING};
+
 #[derive(Clone, Debug, Eq, PartialEq)]
 #[non_exhaustive]
 pub enum Int8 {
@@ -17,11 +19,11 @@ impl Int8 {
 
 impl From<i8> for Int8 {
     fn from(value: i8) -> Self {
-        match value as u8 {
-            0x80 => Self::Missing,
-            0x81 => Self::EndOfVector,
-            0x82..=0x87 => Self::Reserved(value),
-            _ => Self::Value(value),
+        match value {
+            INT8_MISSING => Self::Missing,
+            INT8_END_OF_VECTOR => Self::EndOfVector,
+            n if n < Self::MIN_VALUE => Self::Reserved(n),
+            n => Self::Value(n),
         }
     }
 }
@@ -29,8 +31,8 @@ impl From<i8> for Int8 {
 impl From<Int8> for i8 {
     fn from(value: Int8) -> Self {
         match value {
-            Int8::Missing => -128,
-            Int8::EndOfVector => -127,
+            Int8::Missing => INT8_MISSING,
+            Int8::EndOfVector => INT8_END_OF_VECTOR,
             Int8::Value(n) | Int8::Reserved(n) => n,
         }
     }
@@ -60,6 +60,17 @@ impl Debug for Genotype<'_> {
     }
 }
 
+impl From<Genotype<'_>> for vcf::variant::record_buf::samples::sample::value::Genotype {
+    fn from(genotype: Genotype<'_>) -> Self {
+        use vcf::variant::record_buf::samples::sample::value::genotype::Allele;
+
+        genotype
+            .iter()
+            .map(|(position, phasing)| Allele::new(position, phasing))
+            .collect()
+    }
+}
+
 impl vcf::variant::record::samples::series::value::Genotype for Genotype<'_> {
     fn iter(&self) -> Box<dyn Iterator<Item = io::Result<(Option<usize>, Phasing)>> + '_> {
         Box::new(self.iter().map(Ok))
@@ -72,6 +83,8 @@ fn is_phased(n: i8) -> bool {
 
 #[cfg(test)]
 mod tests {
+    use vcf::variant::record_buf::samples::sample::value::genotype::ParseError;
+
     use super::*;
 
     #[test]
@@ -123,6 +136,27 @@ mod tests {
         t(&[0x02, 0x81], &[(Some(0), Phasing::Phased)]);
     }
 
+    #[test]
+    fn test_from_genotype_for_vcf_variant_record_buf_samples_sample_value_genotype(
+    ) -> Result<(), ParseError> {
+        use vcf::variant::record_buf::samples::sample::value::Genotype as GenotypeBuf;
+
+        fn t(src: &[u8], expected: &str) -> Result<(), ParseError> {
+            let actual = GenotypeBuf::from(Genotype::new(src));
+            assert_eq!(actual, expected.parse()?);
+            Ok(())
+        }
+
+        t(&[0x02, 0x04], "0/1")?;
+        t(&[0x02, 0x05], "0|1")?;
+        t(&[0x00, 0x00], "./.")?;
+        t(&[0x04], "1")?;
+        // A haploid call padded with the end-of-vector sentinel in a mixed-ploidy series.
+        t(&[0x04, 0x81], "1")?;
+
+        Ok(())
+    }
+
     #[test]
     fn test_is_phased() {
         assert!(!is_phased(0x00));
@@ -0,0 +1,115 @@
+//! BCF typed value sentinels.
+//!
+//! Integer values in BCF typed vectors reserve the smallest values of each width. The smallest
+//! value marks a missing value, and the next smallest marks the end of a vector, i.e., padding
+//! after the last value.
+
+/// The int8 missing value (`0x80`).
+pub const INT8_MISSING: i8 = i8::MIN;
+
+/// The int8 end-of-vector value (`0x81`).
+pub const INT8_END_OF_VECTOR: i8 = i8::MIN + 1;
+
+/// The int16 missing value (`0x8000`).
+pub const INT16_MISSING: i16 = i16::MIN;
+
+/// The int16 end-of-vector value (`0x8001`).
+pub const INT16_END_OF_VECTOR: i16 = i16::MIN + 1;
+
+/// The int32 missing value (`0x80000000`).
+pub const INT32_MISSING: i32 = i32::MIN;
+
+/// The int32 end-of-vector value (`0x80000001`).
+pub const INT32_END_OF_VECTOR: i32 = i32::MIN + 1;
+
+/// A BCF typed integer width.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Width {
+    /// An 8-bit integer.
+    Int8,
+    /// A 16-bit integer.
+    Int16,
+    /// A 32-bit integer.
+    Int32,
+}
+
+/// Returns whether the given value is the missing value for the given width.
+///
+/// The value is expected to be sign-extended from the given width.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::sentinel::{is_missing, Width};
+///
+/// assert!(is_missing(Width::Int8, -128));
+/// assert!(!is_missing(Width::Int16, -128));
+/// ```
+pub fn is_missing(width: Width, value: i32) -> bool {
+    match width {
+        Width::Int8 => value == i32::from(INT8_MISSING),
+        Width::Int16 => value == i32::from(INT16_MISSING),
+        Width::Int32 => value == INT32_MISSING,
+    }
+}
+
+/// Returns whether the given value is the end-of-vector value for the given width.
+///
+/// The value is expected to be sign-extended from the given width.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_bcf::sentinel::{is_end_of_vector, Width};
+///
+/// assert!(is_end_of_vector(Width::Int8, -127));
+/// assert!(!is_end_of_vector(Width::Int16, -127));
+/// ```
+pub fn is_end_of_vector(width: Width, value: i32) -> bool {
+    match width {
+        Width::Int8 => value == i32::from(INT8_END_OF_VECTOR),
+        Width::Int16 => value == i32::from(INT16_END_OF_VECTOR),
+        Width::Int32 => value == INT32_END_OF_VECTOR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_missing() {
+        assert!(is_missing(Width::Int8, i32::from(0x80u8 as i8)));
+        assert!(!is_missing(Width::Int8, i32::from(0x81u8 as i8)));
+        assert!(!is_missing(Width::Int8, 0));
+
+        assert!(is_missing(Width::Int16, i32::from(0x8000u16 as i16)));
+        assert!(!is_missing(Width::Int16, i32::from(0x8001u16 as i16)));
+        assert!(!is_missing(Width::Int16, i32::from(INT8_MISSING)));
+
+        assert!(is_missing(Width::Int32, 0x80000000u32 as i32));
+        assert!(!is_missing(Width::Int32, 0x80000001u32 as i32));
+        assert!(!is_missing(Width::Int32, i32::from(INT16_MISSING)));
+    }
+
+    #[test]
+    fn test_is_end_of_vector() {
+        assert!(is_end_of_vector(Width::Int8, i32::from(0x81u8 as i8)));
+        assert!(!is_end_of_vector(Width::Int8, i32::from(0x80u8 as i8)));
+        assert!(!is_end_of_vector(Width::Int8, 0));
+
+        assert!(is_end_of_vector(Width::Int16, i32::from(0x8001u16 as i16)));
+        assert!(!is_end_of_vector(Width::Int16, i32::from(0x8000u16 as i16)));
+        assert!(!is_end_of_vector(
+            Width::Int16,
+            i32::from(INT8_END_OF_VECTOR)
+        ));
+
+        assert!(is_end_of_vector(Width::Int32, 0x80000001u32 as i32));
+        assert!(!is_end_of_vector(Width::Int32, 0x80000000u32 as i32));
+        assert!(!is_end_of_vector(
+            Width::Int32,
+            i32::from(INT16_END_OF_VECTOR)
+        ));
+    }
+}
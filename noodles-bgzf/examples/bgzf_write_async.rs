@@ -5,10 +5,7 @@
 use std::env;
 
 use noodles_bgzf as bgzf;
-use tokio::{
-    fs::File,
-    io::{self, AsyncWriteExt},
-};
+use tokio::{fs::File, io};
 
 #[tokio::main]
 async fn main() -> io::Result<()> {
@@ -17,7 +17,7 @@ use tokio::io::{AsyncBufRead, AsyncRead, AsyncSeek, ReadBuf};
 
 pub use self::builder::Builder;
 use self::inflater::Inflater;
-use crate::{gzi, Block, VirtualPosition};
+use crate::{gzi, reader::corrupt_block_error, Block, VirtualPosition};
 
 pin_project! {
     /// An async BGZF reader.
@@ -261,7 +261,12 @@ where
                             break;
                         }
                     }
-                    Some(Err(e)) => return Poll::Ready(Err(e)),
+                    Some(Err(e)) => {
+                        return Poll::Ready(Err(corrupt_block_error::set_position(
+                            e,
+                            *this.position,
+                        )))
+                    }
                     None => return Poll::Ready(Ok(&[])),
                 }
             }
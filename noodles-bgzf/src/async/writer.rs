@@ -16,7 +16,6 @@ use tokio::io::{self, AsyncWrite};
 
 pub use self::builder::Builder;
 use self::{deflate::Deflate, deflater::Deflater};
-use crate::writer::MAX_BUF_SIZE;
 
 #[cfg(feature = "libdeflate")]
 type CompressionLevel = libdeflater::CompressionLvl;
@@ -29,6 +28,7 @@ pin_project! {
         #[pin]
         sink: Buffer<Deflater<W>, Deflate>,
         buf: BytesMut,
+        block_size: usize,
         #[pin]
         eof_buf: Bytes,
         compression_level: CompressionLevel,
@@ -53,6 +53,9 @@ where
 
     /// Returns the underlying writer.
     ///
+    /// This does not flush any buffered data or write the BGZF EOF block. Call
+    /// [`Self::shutdown`] before unwrapping the writer to ensure the output is complete.
+    ///
     /// # Examples
     ///
     /// ```
@@ -64,12 +67,34 @@ where
         self.sink.into_inner().into_inner()
     }
 
+    /// Shuts down the output stream.
+    ///
+    /// This flushes the pending block, waits for all blocks to be written, and appends the final
+    /// BGZF EOF block.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// #
+    /// # #[tokio::main]
+    /// # async fn main() -> io::Result<()> {
+    /// use noodles_bgzf as bgzf;
+    /// let mut writer = bgzf::AsyncWriter::new(Vec::new());
+    /// writer.shutdown().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn shutdown(&mut self) -> io::Result<()> {
+        io::AsyncWriteExt::shutdown(self).await
+    }
+
     fn remaining(&self) -> usize {
-        MAX_BUF_SIZE - self.buf.len()
+        self.block_size - self.buf.len()
     }
 
     fn has_remaining(&self) -> bool {
-        self.buf.len() < MAX_BUF_SIZE
+        self.buf.len() < self.block_size
     }
 }
 
@@ -134,3 +159,97 @@ where
         Poll::Ready(Ok(()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Read, num::NonZeroUsize};
+
+    use tokio::io::AsyncWriteExt;
+
+    use super::*;
+    use crate::writer::CompressionLevel;
+
+    fn count_blocks(mut src: &[u8]) -> usize {
+        let mut n = 0;
+
+        while src.len() >= 18 {
+            let bsize = u16::from_le_bytes([src[16], src[17]]);
+            src = &src[usize::from(bsize) + 1..];
+            n += 1;
+        }
+
+        n
+    }
+
+    async fn write(builder: Builder, data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = builder.build_with_writer(Vec::new());
+        writer.write_all(data).await?;
+        writer.shutdown().await?;
+        Ok(writer.into_inner())
+    }
+
+    fn decompress(src: &[u8]) -> io::Result<Vec<u8>> {
+        let mut reader = crate::Reader::new(src);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+
+    #[tokio::test]
+    async fn test_shutdown() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles").await?;
+        writer.shutdown().await?;
+
+        let dst = writer.into_inner();
+        assert!(dst.ends_with(crate::writer::BGZF_EOF));
+        assert_eq!(decompress(&dst)?, b"noodles");
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_with_compression_level() -> io::Result<()> {
+        let data = b"noodles".repeat(4096);
+
+        let uncompressed_data = write(
+            Builder::default().set_compression_level(CompressionLevel::NONE),
+            &data,
+        )
+        .await?;
+
+        let compressed_data = write(
+            Builder::default().set_compression_level(CompressionLevel::BEST),
+            &data,
+        )
+        .await?;
+
+        assert!(uncompressed_data.len() > compressed_data.len());
+        assert_eq!(decompress(&uncompressed_data)?, data);
+        assert_eq!(decompress(&compressed_data)?, data);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_with_block_size() -> Result<(), Box<dyn std::error::Error>> {
+        let data = b"noodles".repeat(4096);
+
+        let dst = write(Builder::default(), &data).await?;
+        // 1 data block + EOF block
+        assert_eq!(count_blocks(&dst), 2);
+        assert_eq!(decompress(&dst)?, data);
+
+        let block_size = NonZeroUsize::try_from(4096)?;
+        let dst = write(Builder::default().set_block_size(block_size), &data).await?;
+        // ceil(28672 / 4096) = 7 data blocks + EOF block
+        assert_eq!(count_blocks(&dst), 8);
+        assert_eq!(decompress(&dst)?, data);
+
+        let block_size = NonZeroUsize::MAX;
+        let dst = write(Builder::default().set_block_size(block_size), &data).await?;
+        assert_eq!(count_blocks(&dst), 2);
+
+        Ok(())
+    }
+}
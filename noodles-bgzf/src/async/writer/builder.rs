@@ -15,6 +15,7 @@ use crate::{
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_level: Option<CompressionLevel>,
+    block_size: Option<NonZeroUsize>,
     worker_count: Option<NonZeroUsize>,
 }
 
@@ -36,6 +37,31 @@ impl Builder {
         self
     }
 
+    /// Sets the target uncompressed block size.
+    ///
+    /// Uncompressed data is buffered until this size is reached and then written as a BGZF
+    /// block. Smaller blocks increase overhead but are written sooner.
+    ///
+    /// Sizes larger than the maximum allowed by the BGZF format (slightly less than 64 KiB) are
+    /// clamped to the maximum. By default, the block size is set to the maximum.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let block_size = NonZeroUsize::try_from(4096)?;
+    /// let builder = bgzf::r#async::writer::Builder::default()
+    ///     .set_block_size(block_size);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_block_size(mut self, block_size: NonZeroUsize) -> Self {
+        self.block_size = Some(block_size);
+        self
+    }
+
     /// Sets a worker count.
     ///
     /// By default, the worker count is set to the number of available logical CPUs.
@@ -73,13 +99,19 @@ impl Builder {
     {
         let compression_level = self.compression_level.unwrap_or_default();
 
+        let block_size = self
+            .block_size
+            .map(|n| n.get().min(MAX_BUF_SIZE))
+            .unwrap_or(MAX_BUF_SIZE);
+
         let worker_count = self.worker_count.unwrap_or_else(|| {
             thread::available_parallelism().unwrap_or_else(|_| NonZeroUsize::new(1).unwrap())
         });
 
         Writer {
             sink: Deflater::new(FramedWrite::new(writer, BlockCodec)).buffer(worker_count.get()),
-            buf: BytesMut::with_capacity(MAX_BUF_SIZE),
+            buf: BytesMut::with_capacity(block_size),
+            block_size,
             eof_buf: Bytes::from_static(BGZF_EOF),
             compression_level: compression_level.into(),
         }
@@ -1,7 +1,7 @@
 use std::cmp;
 
 /// An uncompressed block data buffer with a cursor.
-#[derive(Debug, Default)]
+#[derive(Clone, Debug, Default)]
 pub struct Data {
     buf: Vec<u8>,
     pos: usize,
@@ -0,0 +1,92 @@
+use std::io::{self, Read, Write};
+
+use crate::{reader::frame::read_frame_into, writer::BGZF_EOF};
+
+/// Concatenates BGZF streams.
+///
+/// Blocks are copied from each input in order without being decompressed. EOF blocks in the
+/// inputs are dropped, and a single EOF block is written at the end of the output.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io::{self, Write};
+/// use noodles_bgzf as bgzf;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"noodles")?;
+/// let a = writer.finish()?;
+///
+/// let mut writer = bgzf::Writer::new(Vec::new());
+/// writer.write_all(b"-bgzf")?;
+/// let b = writer.finish()?;
+///
+/// let mut dst = Vec::new();
+/// bgzf::concat([&a[..], &b[..]], &mut dst)?;
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn concat<I, R, W>(inputs: I, output: &mut W) -> io::Result<()>
+where
+    I: IntoIterator<Item = R>,
+    R: Read,
+    W: Write,
+{
+    let mut buf = Vec::new();
+
+    for mut input in inputs {
+        while read_frame_into(&mut input, &mut buf)?.is_some() {
+            if buf != BGZF_EOF {
+                output.write_all(&buf)?;
+            }
+        }
+    }
+
+    output.write_all(BGZF_EOF)?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Reader, Writer};
+
+    #[test]
+    fn test_concat() -> io::Result<()> {
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        let a = writer.finish()?;
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"-bgzf")?;
+        let b = writer.finish()?;
+
+        let mut dst = Vec::new();
+        concat([&a[..], &b[..]], &mut dst)?;
+
+        assert_eq!(
+            dst.len(),
+            (a.len() - BGZF_EOF.len()) + (b.len() - BGZF_EOF.len()) + BGZF_EOF.len()
+        );
+        assert!(dst.ends_with(BGZF_EOF));
+
+        let mut src = &dst[..];
+        let mut eof_block_count = 0;
+        let mut buf = Vec::new();
+
+        while read_frame_into(&mut src, &mut buf)?.is_some() {
+            if buf == BGZF_EOF {
+                eof_block_count += 1;
+            }
+        }
+
+        assert_eq!(eof_block_count, 1);
+
+        let mut reader = Reader::new(&dst[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles-bgzf");
+
+        Ok(())
+    }
+}
@@ -2,26 +2,50 @@ use std::io;
 
 use flate2::Crc;
 
+/// Decompresses a raw DEFLATE stream into `dst`.
+///
+/// This returns the number of bytes written, which may be less than the length of `dst`.
+/// Decompressed data that does not fit in `dst` is an [`io::ErrorKind::WriteZero`] error.
 #[cfg(feature = "libdeflate")]
-pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
-    use libdeflater::Decompressor;
+pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<usize> {
+    use libdeflater::{DecompressionError, Decompressor};
 
     let mut decoder = Decompressor::new();
 
-    decoder
-        .deflate_decompress(src, dst)
-        .map(|_| ())
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    decoder.deflate_decompress(src, dst).map_err(|e| match e {
+        DecompressionError::InsufficientSpace => io::Error::new(io::ErrorKind::WriteZero, e),
+        DecompressionError::BadData => io::Error::new(io::ErrorKind::InvalidData, e),
+    })
 }
 
+/// Decompresses a raw DEFLATE stream into `dst`.
+///
+/// This returns the number of bytes written, which may be less than the length of `dst`.
+/// Decompressed data that does not fit in `dst` is an [`io::ErrorKind::WriteZero`] error.
 #[cfg(not(feature = "libdeflate"))]
-pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<()> {
+pub(crate) fn decode(src: &[u8], dst: &mut [u8]) -> io::Result<usize> {
     use std::io::Read;
 
     use flate2::bufread::DeflateDecoder;
 
     let mut decoder = DeflateDecoder::new(src);
-    decoder.read_exact(dst)
+    let mut n = 0;
+
+    while n < dst.len() {
+        match decoder.read(&mut dst[n..])? {
+            0 => return Ok(n),
+            m => n += m,
+        }
+    }
+
+    if decoder.read(&mut [0])? > 0 {
+        return Err(io::Error::new(
+            io::ErrorKind::WriteZero,
+            "decompressed data exceeds buffer",
+        ));
+    }
+
+    Ok(n)
 }
 
 #[cfg(feature = "libdeflate")]
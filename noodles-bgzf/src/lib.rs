@@ -38,6 +38,7 @@
 pub mod r#async;
 
 mod block;
+mod concat;
 pub(crate) mod deflate;
 mod gz;
 pub mod gzi;
@@ -50,7 +51,7 @@ pub mod virtual_position;
 pub mod writer;
 
 pub use self::{
-    indexed_reader::IndexedReader, multithreaded_reader::MultithreadedReader,
+    concat::concat, indexed_reader::IndexedReader, multithreaded_reader::MultithreadedReader,
     multithreaded_writer::MultithreadedWriter, reader::Reader, virtual_position::VirtualPosition,
     writer::Writer,
 };
@@ -7,7 +7,7 @@ use std::{
 
 use crossbeam_channel::{Receiver, Sender};
 
-use crate::{gzi, Block, VirtualPosition};
+use crate::{gzi, reader::corrupt_block_error, Block, VirtualPosition};
 
 type BufferedTx = Sender<io::Result<Buffer>>;
 type BufferedRx = Receiver<io::Result<Buffer>>;
@@ -241,7 +241,9 @@ where
             panic!("invalid state");
         };
 
-        while let Some(mut buffer) = recv_buffer(read_rx)? {
+        while let Some(mut buffer) =
+            recv_buffer(read_rx).map_err(|e| corrupt_block_error::set_position(e, self.position))?
+        {
             buffer.block.set_position(self.position);
             self.position += buffer.block.size();
 
@@ -231,3 +231,34 @@ fn compress(src: &[u8], compression_level: CompressionLevelImpl) -> io::Result<F
     let crc32 = deflate::encode(src, compression_level, &mut dst)?;
     Ok((dst, crc32, src.len()))
 }
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+
+    #[test]
+    fn test_write_preserves_block_order() -> io::Result<()> {
+        const WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(4) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let data: Vec<u8> = (0..MAX_BUF_SIZE * 9 + 13)
+            .map(|i| (i % 251) as u8 ^ (i / MAX_BUF_SIZE) as u8)
+            .collect();
+
+        let mut writer = MultithreadedWriter::with_worker_count(WORKER_COUNT, Vec::new());
+        writer.write_all(&data)?;
+        let compressed_data = writer.finish()?;
+
+        let mut reader = crate::Reader::new(&compressed_data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+}
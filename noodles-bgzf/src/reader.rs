@@ -1,12 +1,20 @@
 //! BGZF reader.
 
+mod block_cache;
+mod block_offsets;
 mod builder;
+pub(crate) mod corrupt_block_error;
 pub(crate) mod frame;
 
-pub use self::builder::Builder;
+pub use self::{
+    block_offsets::BlockOffsets,
+    builder::Builder,
+    corrupt_block_error::{CorruptBlockError, CorruptBlockErrorKind},
+};
 
 use std::io::{self, BufRead, Read, Seek, SeekFrom};
 
+use self::block_cache::BlockCache;
 use super::{gzi, Block, VirtualPosition, BGZF_MAX_ISIZE};
 
 /// A BGZF reader.
@@ -31,6 +39,9 @@ pub struct Reader<R> {
     buf: Vec<u8>,
     position: u64,
     block: Block,
+    require_eof: bool,
+    is_eof_block: bool,
+    block_cache: Option<BlockCache>,
 }
 
 impl<R> Reader<R> {
@@ -91,11 +102,16 @@ where
     /// let reader = bgzf::Reader::new(&data[..]);
     /// ```
     pub fn new(inner: R) -> Self {
-        Builder.build_from_reader(inner)
+        Builder::default().build_from_reader(inner)
     }
 
     /// Returns the current position of the stream.
     ///
+    /// This is the number of compressed bytes consumed from the underlying reader, i.e., the
+    /// compressed position after the last block read. Unlike the virtual position, it does not
+    /// account for the position in the uncompressed block data, which makes it suitable for
+    /// reporting progress relative to the compressed stream length.
+    ///
     /// # Examples
     ///
     /// ```
@@ -122,14 +138,49 @@ where
         self.block.virtual_position()
     }
 
+    /// Returns an iterator over the compressed offsets of the remaining blocks in the stream.
+    ///
+    /// Each offset is the start of a BGZF block and is a valid virtual position with an
+    /// uncompressed position of 0. This can be used to partition a stream at block boundaries
+    /// without an index. Blocks are not decompressed, and EOF blocks are skipped.
+    ///
+    /// This consumes the stream. Any buffered block data is discarded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::Reader::new(io::empty());
+    /// assert!(reader.block_offsets().next().is_none());
+    /// ```
+    pub fn block_offsets(&mut self) -> BlockOffsets<'_, R> {
+        BlockOffsets::new(self)
+    }
+
     fn read_nonempty_block_with<F>(&mut self, mut f: F) -> io::Result<usize>
     where
         F: FnMut(&[u8], &mut Block) -> io::Result<()>,
     {
         use self::frame::read_frame_into;
+        use crate::writer::BGZF_EOF;
+
+        loop {
+            if read_frame_into(&mut self.inner, &mut self.buf)?.is_none() {
+                if self.require_eof && !self.is_eof_block {
+                    return Err(io::Error::new(
+                        io::ErrorKind::UnexpectedEof,
+                        "missing BGZF EOF block",
+                    ));
+                }
 
-        while read_frame_into(&mut self.inner, &mut self.buf)?.is_some() {
-            f(&self.buf, &mut self.block)?;
+                break;
+            }
+
+            self.is_eof_block = self.buf == BGZF_EOF;
+
+            f(&self.buf, &mut self.block)
+                .map_err(|e| corrupt_block_error::set_position(e, self.position))?;
 
             self.block.set_position(self.position);
             self.position += self.block.size();
@@ -157,11 +208,51 @@ impl<R> Reader<R>
 where
     R: Read + Seek,
 {
+    /// Returns whether the stream ends with a BGZF EOF block.
+    ///
+    /// This checks the last 28 bytes of the underlying stream for the canonical BGZF EOF block.
+    /// The position of the stream is unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_bgzf as bgzf;
+    /// let mut reader = bgzf::Reader::new(Cursor::new(Vec::new()));
+    /// assert!(!reader.has_eof()?);
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn has_eof(&mut self) -> io::Result<bool> {
+        use crate::writer::BGZF_EOF;
+
+        let pos = self.inner.stream_position()?;
+        let len = self.inner.seek(SeekFrom::End(0))?;
+
+        let eof_len = BGZF_EOF.len() as u64;
+
+        let result = if len < eof_len {
+            Ok(false)
+        } else {
+            self.inner.seek(SeekFrom::Start(len - eof_len))?;
+            let mut buf = [0; 28];
+            self.inner
+                .read_exact(&mut buf)
+                .map(|_| buf[..] == *BGZF_EOF)
+        };
+
+        self.inner.seek(SeekFrom::Start(pos))?;
+
+        result
+    }
+
     /// Seeks the stream to the given virtual position.
     ///
     /// The underlying stream's cursor is first moved the the compressed position. A block is read,
     /// decompressed, and has its own cursor moved to the uncompressed position.
     ///
+    /// This returns an [`io::ErrorKind::InvalidInput`] error if the compressed position is beyond
+    /// the end of the stream or if the uncompressed position is beyond the end of the block.
+    ///
     /// # Examples
     ///
     /// ```
@@ -175,12 +266,21 @@ where
     pub fn seek(&mut self, pos: VirtualPosition) -> io::Result<VirtualPosition> {
         let (cpos, upos) = pos.into();
 
-        self.inner.seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
+        self.read_block_at(cpos)?;
 
-        self.read_block()?;
+        let upos = usize::from(upos);
+        let block_len = self.block.data().len();
 
-        self.block.data_mut().set_position(usize::from(upos));
+        if upos > block_len {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "invalid virtual position: uncompressed offset ({upos}) is beyond the end of the block at {cpos} ({block_len})"
+                ),
+            ));
+        }
+
+        self.block.data_mut().set_position(upos);
 
         Ok(pos)
     }
@@ -209,10 +309,7 @@ where
         let record = index[i - 1];
 
         let cpos = record.0;
-        self.inner.seek(SeekFrom::Start(cpos))?;
-        self.position = cpos;
-
-        self.read_block()?;
+        self.read_block_at(cpos)?;
 
         let upos = usize::try_from(pos - record.1)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
@@ -220,6 +317,48 @@ where
 
         Ok(pos)
     }
+
+    fn read_block_at(&mut self, pos: u64) -> io::Result<()> {
+        if let Some(block) = self.block_cache.as_mut().and_then(|cache| cache.get(pos)) {
+            self.block.clone_from(block);
+
+            let next_pos = pos + self.block.size();
+            self.inner.seek(SeekFrom::Start(next_pos))?;
+            self.position = next_pos;
+
+            // Only nonempty blocks are cached, so the last block read is not an EOF block.
+            self.is_eof_block = false;
+
+            return Ok(());
+        }
+
+        self.inner.seek(SeekFrom::Start(pos))?;
+        self.position = pos;
+
+        let result = self.read_block();
+
+        // The stream length is only checked when no block could be read at the given position.
+        if self.position == pos {
+            let len = self.inner.seek(SeekFrom::End(0))?;
+
+            if pos > len {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "invalid compressed offset: {pos} is beyond the end of the stream ({len})"
+                    ),
+                ));
+            }
+        }
+
+        if result? > 0 && self.position > pos {
+            if let Some(block_cache) = self.block_cache.as_mut() {
+                block_cache.insert(self.block.position(), self.block.clone());
+            }
+        }
+
+        Ok(())
+    }
 }
 
 impl<R> Read for Reader<R>
@@ -353,6 +492,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_with_corrupt_block() {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // block 1 (b"bgzf")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1f, 0x00, 0x4b, 0x4a, 0xaf, 0x4a, 0x03, 0x00, 0x20, 0x68, 0xf2, 0x8c,
+            0x04, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        fn t(data: &[u8], i: usize, value: u8, expected: CorruptBlockErrorKind) {
+            let mut data = data.to_vec();
+            data[i] = value;
+
+            let mut reader = Reader::new(&data[..]);
+            let mut buf = Vec::new();
+            let e = reader.read_to_end(&mut buf).unwrap_err();
+
+            assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+            let actual = e
+                .get_ref()
+                .and_then(|e| e.downcast_ref::<CorruptBlockError>())
+                .expect("missing corrupt block error");
+
+            assert_eq!(actual.position(), 35);
+            assert_eq!(actual.kind(), expected);
+        }
+
+        // CDATA[0] = 0xff (BTYPE = 3 (reserved))
+        t(
+            &data,
+            53,
+            0xff,
+            CorruptBlockErrorKind::InvalidCompressedData,
+        );
+        // CRC32[0] = 0x21
+        t(&data, 59, 0x21, CorruptBlockErrorKind::ChecksumMismatch);
+        // ISIZE = 5
+        t(&data, 63, 0x05, CorruptBlockErrorKind::SizeMismatch);
+        // ISIZE = 3
+        t(&data, 63, 0x03, CorruptBlockErrorKind::SizeMismatch);
+    }
+
     #[test]
     fn test_seek() -> Result<(), Box<dyn std::error::Error>> {
         #[rustfmt::skip]
@@ -386,6 +576,51 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_seek_with_out_of_bounds_virtual_positions() -> Result<(), Box<dyn std::error::Error>> {
+        #[rustfmt::skip]
+        let data = [
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+            // EOF block
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x1b, 0x00, 0x03, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        ];
+
+        let mut reader = Reader::new(Cursor::new(&data));
+
+        let pos = VirtualPosition::try_from((0, 4))?;
+        assert_eq!(reader.seek(pos)?, pos);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"les");
+
+        let pos = VirtualPosition::try_from((0, 7))?;
+        assert_eq!(reader.seek(pos)?, pos);
+
+        let pos = VirtualPosition::try_from((63, 0))?;
+        assert_eq!(reader.seek(pos)?, pos);
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((0, 8))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((64, 0))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            reader.seek(VirtualPosition::try_from((1024, 0))?),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_seek_by_uncompressed_position() -> io::Result<()> {
         #[rustfmt::skip]
@@ -419,4 +654,295 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_seek_by_uncompressed_position_with_gzi_index() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let blocks: [&[u8]; 3] = [b"noodles", b"bgzf", b"gzi"];
+
+        let mut writer = Writer::new(Vec::new());
+        let mut index = vec![(0, 0)];
+        let mut uncompressed_position = 0;
+
+        for (i, block) in blocks.iter().enumerate() {
+            writer.write_all(block)?;
+            writer.flush()?;
+
+            uncompressed_position += block.len() as u64;
+
+            if i < blocks.len() - 1 {
+                index.push((writer.position(), uncompressed_position));
+            }
+        }
+
+        let data = writer.finish()?;
+
+        let mut gzi_writer = gzi::Writer::new(Vec::new());
+        gzi_writer.write_index(&index)?;
+
+        let mut gzi_reader = gzi::Reader::new(&gzi_writer.get_ref()[..]);
+        let index = gzi_reader.read_index()?;
+
+        let mut reader = Reader::new(Cursor::new(&data));
+
+        // mid-block
+        reader.seek_by_uncompressed_position(&index, 9)?;
+        let mut buf = [0; 2];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"zf");
+
+        // start of block
+        reader.seek_by_uncompressed_position(&index, 11)?;
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"gzi");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_eof() -> io::Result<()> {
+        use crate::writer::BGZF_EOF;
+
+        #[rustfmt::skip]
+        static BLOCK: &[u8] = &[
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let data = [BLOCK, BGZF_EOF].concat();
+        let mut reader = Reader::new(Cursor::new(&data));
+        assert!(reader.has_eof()?);
+        assert_eq!(reader.get_mut().position(), 0);
+
+        let mut reader = Reader::new(Cursor::new(BLOCK));
+        assert!(!reader.has_eof()?);
+
+        let mut reader = Reader::new(Cursor::new(&BGZF_EOF[1..]));
+        assert!(!reader.has_eof()?);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_require_eof() -> io::Result<()> {
+        use crate::writer::BGZF_EOF;
+
+        #[rustfmt::skip]
+        static BLOCK: &[u8] = &[
+            // block 0 (b"noodles")
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let data = [BLOCK, BGZF_EOF].concat();
+        let mut reader = Builder::default()
+            .set_require_eof(true)
+            .build_from_reader(&data[..]);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        let mut reader = Builder::default()
+            .set_require_eof(true)
+            .build_from_reader(BLOCK);
+        let mut buf = Vec::new();
+        assert!(matches!(
+            reader.read_to_end(&mut buf),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let mut reader = Reader::new(BLOCK);
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodles");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_block_offsets() -> Result<(), Box<dyn std::error::Error>> {
+        use std::io::Write;
+
+        use crate::Writer;
+
+        let mut writer = Writer::new(Vec::new());
+        let mut expected = Vec::new();
+
+        for block in [&b"noodles"[..], b"bgzf", b"block"] {
+            expected.push(writer.position());
+            writer.write_all(block)?;
+            writer.flush()?;
+        }
+
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        let actual: Vec<_> = reader.block_offsets().collect::<io::Result<_>>()?;
+        assert_eq!(actual, expected);
+        assert_eq!(reader.position(), data.len() as u64);
+
+        let mut reader = Reader::new(Cursor::new(&data));
+        reader.seek(VirtualPosition::try_from((actual[2], 0))?)?;
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"block");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_position() -> io::Result<()> {
+        use std::io::Write;
+
+        use crate::{writer::BGZF_EOF, Writer};
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let block_0_size = writer.position();
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(&data[..]);
+        assert_eq!(reader.position(), 0);
+
+        let mut buf = [0; 7];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(reader.position(), block_0_size);
+
+        let block_1_size = data.len() as u64 - block_0_size - BGZF_EOF.len() as u64;
+
+        let mut buf = [0; 1];
+        reader.read_exact(&mut buf)?;
+        assert_eq!(reader.position(), block_0_size + block_1_size);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(reader.position(), data.len() as u64);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_with_block_cache() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{io::Write, num::NonZeroUsize};
+
+        use crate::Writer;
+
+        struct CountingReader<R> {
+            inner: R,
+            bytes_read: usize,
+        }
+
+        impl<R: Read> Read for CountingReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                let n = self.inner.read(buf)?;
+                self.bytes_read += n;
+                Ok(n)
+            }
+        }
+
+        impl<R: Seek> Seek for CountingReader<R> {
+            fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+                self.inner.seek(pos)
+            }
+        }
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(b"noodles")?;
+        writer.flush()?;
+        let block_1_position = writer.position();
+        writer.write_all(b"bgzf")?;
+        let data = writer.finish()?;
+
+        let mut reader = Builder::default()
+            .set_block_cache_capacity(NonZeroUsize::MIN)
+            .build_from_reader(CountingReader {
+                inner: Cursor::new(data),
+                bytes_read: 0,
+            });
+
+        let mut buf = [0; 4];
+
+        reader.seek(VirtualPosition::try_from((0, 3))?)?;
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"dles");
+
+        let bytes_read = reader.get_ref().bytes_read;
+        assert!(bytes_read > 0);
+
+        // cache hit
+        reader.seek(VirtualPosition::try_from((0, 1))?)?;
+        assert_eq!(reader.get_ref().bytes_read, bytes_read);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"oodl");
+
+        // cache miss; evicts block 0
+        reader.seek(VirtualPosition::try_from((block_1_position, 0))?)?;
+        assert!(reader.get_ref().bytes_read > bytes_read);
+        reader.read_exact(&mut buf)?;
+        assert_eq!(&buf, b"bgzf");
+
+        let bytes_read = reader.get_ref().bytes_read;
+        reader.seek(VirtualPosition::try_from((0, 0))?)?;
+        assert!(reader.get_ref().bytes_read > bytes_read);
+
+        let mut buf = Vec::new();
+        reader.read_to_end(&mut buf)?;
+        assert_eq!(buf, b"noodlesbgzf");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_seek_with_block_cache_to_eof() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        #[rustfmt::skip]
+        static BLOCK_0: &[u8] = &[
+            // b"noodles"
+            0x1f, 0x8b, 0x08, 0x04, 0x00, 0x00, 0x00, 0x00, 0x00, 0xff, 0x06, 0x00, 0x42, 0x43,
+            0x02, 0x00, 0x22, 0x00, 0xcb, 0xcb, 0xcf, 0x4f, 0xc9, 0x49, 0x2d, 0x06, 0x00, 0xa1,
+            0x58, 0x2a, 0x80, 0x07, 0x00, 0x00, 0x00,
+        ];
+
+        let mut data = BLOCK_0.to_vec();
+        data.extend_from_slice(crate::writer::BGZF_EOF);
+
+        let mut reader = Builder::default()
+            .set_require_eof(true)
+            .set_block_cache_capacity(NonZeroUsize::MIN)
+            .build_from_reader(Cursor::new(data));
+
+        for _ in 0..2 {
+            reader.seek(VirtualPosition::MIN)?;
+            let mut buf = Vec::new();
+            reader.read_to_end(&mut buf)?;
+            assert_eq!(buf, b"noodles");
+        }
+
+        let mut reader = Builder::default()
+            .set_require_eof(true)
+            .set_block_cache_capacity(NonZeroUsize::MIN)
+            .build_from_reader(Cursor::new(BLOCK_0));
+
+        for _ in 0..2 {
+            reader.seek(VirtualPosition::MIN)?;
+            let mut buf = Vec::new();
+            assert!(matches!(
+                reader.read_to_end(&mut buf),
+                Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+            ));
+        }
+
+        Ok(())
+    }
 }
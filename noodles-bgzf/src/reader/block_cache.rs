@@ -0,0 +1,59 @@
+use std::{collections::VecDeque, num::NonZeroUsize};
+
+use crate::Block;
+
+/// A least recently used (LRU) cache of decompressed blocks keyed by compressed position.
+#[derive(Debug)]
+pub(super) struct BlockCache {
+    capacity: NonZeroUsize,
+    entries: VecDeque<(u64, Block)>,
+}
+
+impl BlockCache {
+    pub fn new(capacity: NonZeroUsize) -> Self {
+        Self {
+            capacity,
+            entries: VecDeque::with_capacity(capacity.get()),
+        }
+    }
+
+    pub fn get(&mut self, position: u64) -> Option<&Block> {
+        let i = self.entries.iter().position(|(pos, _)| *pos == position)?;
+        let entry = self.entries.remove(i)?;
+        self.entries.push_front(entry);
+        self.entries.front().map(|(_, block)| block)
+    }
+
+    pub fn insert(&mut self, position: u64, block: Block) {
+        if let Some(i) = self.entries.iter().position(|(pos, _)| *pos == position) {
+            self.entries.remove(i);
+        }
+
+        self.entries.push_front((position, block));
+        self.entries.truncate(self.capacity.get());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        const CAPACITY: NonZeroUsize = match NonZeroUsize::new(2) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let mut cache = BlockCache::new(CAPACITY);
+
+        cache.insert(0, Block::default());
+        cache.insert(8, Block::default());
+        assert!(cache.get(0).is_some());
+
+        cache.insert(13, Block::default());
+        assert!(cache.get(0).is_some());
+        assert!(cache.get(8).is_none());
+        assert!(cache.get(13).is_some());
+    }
+}
@@ -0,0 +1,48 @@
+use std::io::{self, Read};
+
+use super::{frame::read_frame_into, Reader};
+use crate::{writer::BGZF_EOF, Block};
+
+/// An iterator over the compressed offsets of blocks in a BGZF stream.
+///
+/// This is created by calling [`Reader::block_offsets`].
+pub struct BlockOffsets<'r, R> {
+    reader: &'r mut Reader<R>,
+}
+
+impl<'r, R> BlockOffsets<'r, R>
+where
+    R: Read,
+{
+    pub(super) fn new(reader: &'r mut Reader<R>) -> Self {
+        reader.block = Block::default();
+        reader.block.set_position(reader.position);
+        Self { reader }
+    }
+}
+
+impl<'r, R> Iterator for BlockOffsets<'r, R>
+where
+    R: Read,
+{
+    type Item = io::Result<u64>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match read_frame_into(&mut self.reader.inner, &mut self.reader.buf) {
+                Ok(Some(())) => {}
+                Ok(None) => return None,
+                Err(e) => return Some(Err(e)),
+            }
+
+            let position = self.reader.position;
+
+            self.reader.position += self.reader.buf.len() as u64;
+            self.reader.block.set_position(self.reader.position);
+
+            if self.reader.buf != BGZF_EOF {
+                return Some(Ok(position));
+            }
+        }
+    }
+}
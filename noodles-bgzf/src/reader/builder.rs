@@ -1,17 +1,63 @@
 use std::{
     fs::File,
     io::{self, Read},
+    num::NonZeroUsize,
     path::Path,
 };
 
-use super::Reader;
+use super::{block_cache::BlockCache, Reader};
 use crate::Block;
 
 /// A BGZF reader builder.
 #[derive(Debug, Default)]
-pub struct Builder;
+pub struct Builder {
+    require_eof: bool,
+    block_cache_capacity: Option<NonZeroUsize>,
+}
 
 impl Builder {
+    /// Sets whether the stream is required to end with a BGZF EOF block.
+    ///
+    /// When enabled, reaching the end of the stream without having read an EOF block returns an
+    /// [`io::ErrorKind::UnexpectedEof`] error. A missing EOF block typically indicates a
+    /// truncated stream.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let builder = bgzf::reader::Builder::default().set_require_eof(true);
+    /// ```
+    pub fn set_require_eof(mut self, require_eof: bool) -> Self {
+        self.require_eof = require_eof;
+        self
+    }
+
+    /// Sets the capacity of the decompressed block cache.
+    ///
+    /// When set, up to `capacity` blocks decompressed when seeking are kept in a least recently
+    /// used (LRU) cache keyed by their compressed positions. Seeking into a cached block reuses its
+    /// decompressed data rather than reading and decompressing it again, which benefits repeated
+    /// queries of nearby regions. Blocks read sequentially are not cached.
+    ///
+    /// By default, blocks are not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    /// let capacity = NonZeroUsize::try_from(8)?;
+    /// let builder = bgzf::reader::Builder::default().set_block_cache_capacity(capacity);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_block_cache_capacity(mut self, capacity: NonZeroUsize) -> Self {
+        self.block_cache_capacity = Some(capacity);
+        self
+    }
+
     /// Builds a BGZF reader from a path.
     ///
     /// # Examples
@@ -48,6 +94,9 @@ impl Builder {
             buf: Vec::new(),
             position: 0,
             block: Block::default(),
+            require_eof: self.require_eof,
+            is_eof_block: false,
+            block_cache: self.block_cache_capacity.map(BlockCache::new),
         }
     }
 }
@@ -0,0 +1,107 @@
+use std::{error, fmt, io};
+
+/// The cause of a corrupt BGZF block.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum CorruptBlockErrorKind {
+    /// The compressed data is not a valid DEFLATE stream.
+    InvalidCompressedData,
+    /// The size of the decompressed data does not match the block's `ISIZE`.
+    SizeMismatch,
+    /// The CRC32 of the decompressed data does not match the block's `CRC32`.
+    ChecksumMismatch,
+}
+
+impl fmt::Display for CorruptBlockErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidCompressedData => f.write_str("invalid compressed data"),
+            Self::SizeMismatch => f.write_str("data size mismatch"),
+            Self::ChecksumMismatch => f.write_str("data checksum mismatch"),
+        }
+    }
+}
+
+/// An error returned when a BGZF block fails to decompress or fails its integrity checks.
+///
+/// This is the source of an [`io::ErrorKind::InvalidData`] error returned by the readers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct CorruptBlockError {
+    position: u64,
+    kind: CorruptBlockErrorKind,
+}
+
+impl CorruptBlockError {
+    pub(crate) fn new(kind: CorruptBlockErrorKind) -> Self {
+        Self { position: 0, kind }
+    }
+
+    /// Returns the compressed position of the start of the corrupt block.
+    pub fn position(&self) -> u64 {
+        self.position
+    }
+
+    /// Returns the cause of the corruption.
+    pub fn kind(&self) -> CorruptBlockErrorKind {
+        self.kind
+    }
+}
+
+impl error::Error for CorruptBlockError {}
+
+impl fmt::Display for CorruptBlockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "corrupt BGZF block at position {}: {}",
+            self.position, self.kind
+        )
+    }
+}
+
+impl From<CorruptBlockError> for io::Error {
+    fn from(e: CorruptBlockError) -> Self {
+        Self::new(io::ErrorKind::InvalidData, e)
+    }
+}
+
+/// Sets the block position of a corrupt block error.
+///
+/// Blocks are decompressed without knowledge of their positions in the stream, so the reader
+/// sets it when it receives the error. Other errors are returned unchanged.
+pub(crate) fn set_position(mut e: io::Error, position: u64) -> io::Error {
+    if let Some(corrupt_block_error) = e
+        .get_mut()
+        .and_then(|e| e.downcast_mut::<CorruptBlockError>())
+    {
+        corrupt_block_error.position = position;
+    }
+
+    e
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_position() {
+        let e = io::Error::from(CorruptBlockError::new(
+            CorruptBlockErrorKind::ChecksumMismatch,
+        ));
+        let e = set_position(e, 8);
+
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(
+            e.get_ref()
+                .and_then(|e| e.downcast_ref::<CorruptBlockError>())
+                .copied(),
+            Some(CorruptBlockError {
+                position: 8,
+                kind: CorruptBlockErrorKind::ChecksumMismatch
+            })
+        );
+
+        let e = set_position(io::Error::from(io::ErrorKind::UnexpectedEof), 8);
+        assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof);
+    }
+}
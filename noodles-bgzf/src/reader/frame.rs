@@ -3,6 +3,7 @@ use std::io::{self, Read};
 use bytes::Buf;
 use flate2::Crc;
 
+use super::{CorruptBlockError, CorruptBlockErrorKind};
 use crate::{gz, Block, BGZF_HEADER_SIZE};
 
 const MIN_FRAME_SIZE: usize = BGZF_HEADER_SIZE + gz::TRAILER_SIZE;
@@ -156,7 +157,17 @@ fn block_initialize(block: &mut Block, block_size: u64, r#isize: usize) {
 fn inflate(src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
     use crate::deflate;
 
-    deflate::decode(src, dst)?;
+    let n = deflate::decode(src, dst).map_err(|e| {
+        if e.kind() == io::ErrorKind::WriteZero {
+            CorruptBlockError::new(CorruptBlockErrorKind::SizeMismatch)
+        } else {
+            CorruptBlockError::new(CorruptBlockErrorKind::InvalidCompressedData)
+        }
+    })?;
+
+    if n != dst.len() {
+        return Err(CorruptBlockError::new(CorruptBlockErrorKind::SizeMismatch).into());
+    }
 
     let mut crc = Crc::new();
     crc.update(dst);
@@ -164,10 +175,7 @@ fn inflate(src: &[u8], crc32: u32, dst: &mut [u8]) -> io::Result<()> {
     if crc.sum() == crc32 {
         Ok(())
     } else {
-        Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            "block data checksum mismatch",
-        ))
+        Err(CorruptBlockError::new(CorruptBlockErrorKind::ChecksumMismatch).into())
     }
 }
 
@@ -1,6 +1,6 @@
 //! BGZF virtual position.
 
-use std::{error, fmt};
+use std::{error, fmt, num, str::FromStr};
 
 pub(crate) const MAX_COMPRESSED_POSITION: u64 = (1 << 48) - 1;
 pub(crate) const MAX_UNCOMPRESSED_POSITION: u16 = u16::MAX;
@@ -42,6 +42,9 @@ impl VirtualPosition {
 
     /// Creates a virtual position if the compressed position is valid.
     ///
+    /// The compressed position must fit in 48 bits (i.e., <= 2^48 - 1). Otherwise, this returns
+    /// `None` rather than truncating the compressed position.
+    ///
     /// # Examples
     ///
     /// ```
@@ -92,6 +95,96 @@ impl VirtualPosition {
     }
 }
 
+impl fmt::Display for VirtualPosition {
+    /// Formats a virtual position as `<compressed position>/<uncompressed position>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let virtual_position = bgzf::VirtualPosition::from(3741638);
+    /// assert_eq!(virtual_position.to_string(), "57/6086");
+    /// ```
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.compressed(), self.uncompressed())
+    }
+}
+
+/// An error returned when a raw virtual position fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The delimiter is missing.
+    MissingDelimiter,
+    /// The compressed position is invalid.
+    InvalidCompressedPosition(num::ParseIntError),
+    /// The uncompressed position is invalid.
+    InvalidUncompressedPosition(num::ParseIntError),
+    /// The compressed position is larger than 2^48 - 1.
+    CompressedPositionOverflow,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidCompressedPosition(e) => Some(e),
+            Self::InvalidUncompressedPosition(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::MissingDelimiter => f.write_str("missing delimiter"),
+            Self::InvalidCompressedPosition(_) => f.write_str("invalid compressed position"),
+            Self::InvalidUncompressedPosition(_) => f.write_str("invalid uncompressed position"),
+            Self::CompressedPositionOverflow => {
+                f.write_str("the compressed position is larger than 2^48 - 1")
+            }
+        }
+    }
+}
+
+impl FromStr for VirtualPosition {
+    type Err = ParseError;
+
+    /// Parses a virtual position from `<compressed position>/<uncompressed position>`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_bgzf as bgzf;
+    /// let virtual_position: bgzf::VirtualPosition = "57/6086".parse()?;
+    /// assert_eq!(virtual_position, bgzf::VirtualPosition::from(3741638));
+    /// # Ok::<_, bgzf::virtual_position::ParseError>(())
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const DELIMITER: char = '/';
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let (raw_compressed_pos, raw_uncompressed_pos) = s
+            .split_once(DELIMITER)
+            .ok_or(ParseError::MissingDelimiter)?;
+
+        let compressed_pos = raw_compressed_pos
+            .parse()
+            .map_err(ParseError::InvalidCompressedPosition)?;
+
+        let uncompressed_pos = raw_uncompressed_pos
+            .parse()
+            .map_err(ParseError::InvalidUncompressedPosition)?;
+
+        Self::new(compressed_pos, uncompressed_pos).ok_or(ParseError::CompressedPositionOverflow)
+    }
+}
+
 impl From<u64> for VirtualPosition {
     fn from(pos: u64) -> Self {
         Self(pos)
@@ -158,6 +251,72 @@ impl From<VirtualPosition> for (u64, u16) {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_new() {
+        assert_eq!(VirtualPosition::new(0, 0), Some(VirtualPosition::MIN));
+        assert_eq!(
+            VirtualPosition::new(57, 6086),
+            Some(VirtualPosition::from(3741638))
+        );
+        assert_eq!(
+            VirtualPosition::new(MAX_COMPRESSED_POSITION, MAX_UNCOMPRESSED_POSITION),
+            Some(VirtualPosition::MAX)
+        );
+        assert!(VirtualPosition::new(MAX_COMPRESSED_POSITION + 1, 0).is_none());
+        assert!(VirtualPosition::new(u64::MAX, 0).is_none());
+    }
+
+    #[test]
+    fn test_ord() -> Result<(), TryFromU64U16TupleError> {
+        let a = VirtualPosition::try_from((8, 65535))?;
+        let b = VirtualPosition::try_from((13, 0))?;
+        let c = VirtualPosition::try_from((13, 21))?;
+
+        assert!(a < b);
+        assert!(b < c);
+
+        let mut positions = [c, a, b];
+        positions.sort();
+        assert_eq!(positions, [a, b, c]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() {
+        assert_eq!(VirtualPosition::MIN.to_string(), "0/0");
+        assert_eq!(VirtualPosition::from(3741638).to_string(), "57/6086");
+        assert_eq!(VirtualPosition::MAX.to_string(), "281474976710655/65535");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("0/0".parse(), Ok(VirtualPosition::MIN));
+        assert_eq!("57/6086".parse(), Ok(VirtualPosition::from(3741638)));
+        assert_eq!("281474976710655/65535".parse(), Ok(VirtualPosition::MAX));
+
+        let pos = VirtualPosition::from(26155658182977);
+        assert_eq!(pos.to_string().parse(), Ok(pos));
+
+        assert_eq!("".parse::<VirtualPosition>(), Err(ParseError::Empty));
+        assert_eq!(
+            "57".parse::<VirtualPosition>(),
+            Err(ParseError::MissingDelimiter)
+        );
+        assert!(matches!(
+            "x/6086".parse::<VirtualPosition>(),
+            Err(ParseError::InvalidCompressedPosition(_))
+        ));
+        assert!(matches!(
+            "57/65536".parse::<VirtualPosition>(),
+            Err(ParseError::InvalidUncompressedPosition(_))
+        ));
+        assert_eq!(
+            "281474976710656/0".parse::<VirtualPosition>(),
+            Err(ParseError::CompressedPositionOverflow)
+        );
+    }
+
     #[test]
     fn test_from_u64_for_virtual_position() {
         let pos = VirtualPosition::from(88384945211);
@@ -69,6 +69,7 @@ where
 {
     inner: Option<W>,
     position: u64,
+    block_size: usize,
     staging_buf: Vec<u8>,
     compression_buf: Vec<u8>,
     compression_level: CompressionLevelImpl,
@@ -220,11 +221,11 @@ where
     }
 
     fn remaining(&self) -> usize {
-        MAX_BUF_SIZE - self.staging_buf.len()
+        self.block_size - self.staging_buf.len()
     }
 
     fn has_remaining(&self) -> bool {
-        self.staging_buf.len() < MAX_BUF_SIZE
+        self.staging_buf.len() < self.block_size
     }
 }
 
@@ -290,6 +291,47 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_with_uncompressed_block_size() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        fn count_blocks(mut src: &[u8]) -> usize {
+            let mut n = 0;
+
+            while !src.is_empty() {
+                let bsize = u16::from_le_bytes([src[16], src[17]]);
+                src = &src[usize::from(bsize) + 1..];
+                n += 1;
+            }
+
+            n
+        }
+
+        let data = vec![b'n'; 32768];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_all(&data)?;
+        let default_dst = writer.finish()?;
+
+        let mut writer = Builder::default()
+            .set_uncompressed_block_size(NonZeroUsize::try_from(4096)?)
+            .build_with_writer(Vec::new());
+        writer.write_all(&data)?;
+        let dst = writer.finish()?;
+
+        // 1 data block + EOF block
+        assert_eq!(count_blocks(&default_dst), 2);
+        // 8 data blocks + EOF block
+        assert_eq!(count_blocks(&dst), 9);
+
+        let mut reader = crate::Reader::new(&dst[..]);
+        let mut buf = Vec::new();
+        io::Read::read_to_end(&mut reader, &mut buf)?;
+        assert_eq!(buf, data);
+
+        Ok(())
+    }
+
     #[test]
     fn test_finish() -> io::Result<()> {
         let mut writer = Writer::new(Vec::new());
@@ -1,4 +1,4 @@
-use std::io::Write;
+use std::{io::Write, num::NonZeroUsize};
 
 use super::{CompressionLevel, Writer, MAX_BUF_SIZE};
 
@@ -6,6 +6,7 @@ use super::{CompressionLevel, Writer, MAX_BUF_SIZE};
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_level: CompressionLevel,
+    uncompressed_block_size: Option<NonZeroUsize>,
 }
 
 impl Builder {
@@ -26,6 +27,28 @@ impl Builder {
         self
     }
 
+    /// Sets the target uncompressed block size.
+    ///
+    /// A block is flushed when its uncompressed data reaches this size. Smaller blocks increase
+    /// the compression overhead but reduce the amount of data buffered before each write.
+    ///
+    /// The size is capped at the max BGZF block data size, which is also the default (~64 KiB).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_bgzf as bgzf;
+    ///
+    /// let builder = bgzf::writer::Builder::default()
+    ///     .set_uncompressed_block_size(NonZeroUsize::try_from(4096)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_uncompressed_block_size(mut self, uncompressed_block_size: NonZeroUsize) -> Self {
+        self.uncompressed_block_size = Some(uncompressed_block_size);
+        self
+    }
+
     /// Builds a BGZF writer from a writer.
     ///
     /// # Examples
@@ -39,10 +62,16 @@ impl Builder {
     where
         W: Write,
     {
+        let block_size = self
+            .uncompressed_block_size
+            .map(|n| n.get().min(MAX_BUF_SIZE))
+            .unwrap_or(MAX_BUF_SIZE);
+
         Writer {
             inner: Some(writer),
             position: 0,
-            staging_buf: Vec::with_capacity(MAX_BUF_SIZE),
+            block_size,
+            staging_buf: Vec::with_capacity(block_size),
             compression_buf: Vec::new(),
             compression_level: self.compression_level.into(),
         }
@@ -69,6 +69,43 @@ impl Position {
             None
         }
     }
+
+    /// Subtracts an unsigned integer from a 1-based position.
+    ///
+    /// This returns `None` if the result is less than the minimum position.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let position = Position::try_from(8)?;
+    /// assert_eq!(position.checked_sub(5), Position::new(3));
+    /// assert!(position.checked_sub(8).is_none());
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn checked_sub(self, other: usize) -> Option<Self> {
+        if let Some(n) = self.0.get().checked_sub(other) {
+            Self::new(n)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the absolute distance between two positions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// let start = Position::try_from(8)?;
+    /// let end = Position::try_from(13)?;
+    /// assert_eq!(start.distance_to(end), 5);
+    /// assert_eq!(end.distance_to(start), 5);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub const fn distance_to(self, other: Self) -> usize {
+        self.0.get().abs_diff(other.0.get())
+    }
 }
 
 impl fmt::Display for Position {
@@ -104,3 +141,40 @@ impl From<Position> for usize {
         position.0.get()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_checked_add() -> Result<(), TryFromIntError> {
+        assert_eq!(Position::MIN.checked_add(0), Some(Position::MIN));
+        assert_eq!(Position::MIN.checked_add(7), Some(Position::try_from(8)?));
+        assert_eq!(Position::MAX.checked_add(0), Some(Position::MAX));
+        assert!(Position::MAX.checked_add(1).is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_checked_sub() -> Result<(), TryFromIntError> {
+        let position = Position::try_from(8)?;
+        assert_eq!(position.checked_sub(0), Some(position));
+        assert_eq!(position.checked_sub(7), Some(Position::MIN));
+        assert!(position.checked_sub(8).is_none());
+        assert!(position.checked_sub(usize::MAX).is_none());
+        assert_eq!(
+            Position::MAX.checked_sub(usize::MAX - 1),
+            Some(Position::MIN)
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_distance_to() -> Result<(), TryFromIntError> {
+        let position = Position::try_from(8)?;
+        assert_eq!(position.distance_to(position), 0);
+        assert_eq!(position.distance_to(Position::MIN), 7);
+        assert_eq!(Position::MIN.distance_to(Position::MAX), usize::MAX - 1);
+        Ok(())
+    }
+}
@@ -20,9 +20,11 @@ use super::Position;
 /// name and an interval.
 ///
 /// They are represented in text as `reference-sequence-name[:start[-end]]`, where the start and
-/// end positions are 1-based, inclusive. If no end position is given, it is assumed to span from
-/// the start to the end of the reference sequence. If no interval is given, it is assumed to span
-/// the entirety of the reference sequence.
+/// end positions are 1-based, inclusive. If no end position is given (e.g., `sq0:8` or `sq0:8-`),
+/// it is assumed to span from the start to the end of the reference sequence. If no start position
+/// is given (e.g., `sq0:-13`), it is assumed to span from the start of the reference sequence to
+/// the end position. If no interval is given, it is assumed to span the entirety of the reference
+/// sequence.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Region {
     name: BString,
@@ -219,6 +221,11 @@ mod tests {
 
         let end = Position::try_from(8)?;
         assert_eq!("sq3:5-8".parse(), Ok(Region::new("sq3", start..=end)));
+        assert_eq!("sq4:5-".parse(), Ok(Region::new("sq4", start..)));
+        assert_eq!("sq5:-8".parse(), Ok(Region::new("sq5", ..=end)));
+
+        let end = Position::try_from(5000)?;
+        assert_eq!("chr1:-5000".parse(), Ok(Region::new("chr1", ..=end)));
 
         assert_eq!("".parse::<Region>(), Err(ParseError::Empty));
 
@@ -171,20 +171,21 @@ impl FromStr for Interval {
 
         let mut components = s.splitn(2, '-');
 
+        // An empty start (e.g., `-13`) or end (e.g., `8-`) is unbounded.
         let start = match components.next() {
+            Some("") | None => None,
             Some(t) => t
                 .parse()
                 .map(Some)
                 .map_err(ParseError::InvalidStartPosition)?,
-            None => None,
         };
 
         let end = match components.next() {
+            Some("") | None => None,
             Some(t) => t
                 .parse()
                 .map(Some)
                 .map_err(ParseError::InvalidEndPosition)?,
-            None => None,
         };
 
         Ok(Self { start, end })
@@ -332,6 +333,9 @@ mod tests {
         assert_eq!("".parse(), Ok(Interval::from(..)));
         assert_eq!("8".parse(), Ok(Interval::from(start..)));
         assert_eq!("8-13".parse(), Ok(Interval::from(start..=end)));
+        assert_eq!("8-".parse(), Ok(Interval::from(start..)));
+        assert_eq!("-13".parse(), Ok(Interval::from(..=end)));
+        assert_eq!("-".parse(), Ok(Interval::from(..)));
 
         assert!(matches!(
             "x".parse::<Interval>(),
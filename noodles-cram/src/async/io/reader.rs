@@ -23,6 +23,8 @@ use crate::{crai, file_definition::Version, DataContainer, FileDefinition, Recor
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    require_eof: bool,
+    is_eof: bool,
     buf: BytesMut,
 }
 
@@ -179,7 +181,11 @@ where
     /// Reads a data container.
     ///
     /// This returns `None` if the container header is the EOF container header, which signals the
-    /// end of the stream.
+    /// end of the stream. If the stream ends without an EOF container, this returns an error,
+    /// unless the reader was built with [`Builder::require_eof`] disabled.
+    ///
+    /// After an unexpected EOF error, the stream is considered to be at its end, and subsequent
+    /// reads return `None`.
     ///
     /// # Examples
     ///
@@ -203,7 +209,19 @@ where
     pub async fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf).await
+        if self.is_eof {
+            return Ok(None);
+        }
+
+        let result = read_data_container(&mut self.inner, &mut self.buf, self.require_eof).await;
+
+        if let Err(e) = &result {
+            if e.kind() == io::ErrorKind::UnexpectedEof {
+                self.is_eof = true;
+            }
+        }
+
+        result
     }
 
     /// Returns an (async) stream over records starting from the current (input) stream position.
@@ -263,6 +281,7 @@ where
     /// # }
     /// ```
     pub async fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.is_eof = false;
         self.inner.seek(pos).await
     }
 
@@ -401,6 +420,41 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    async fn test_records_with_missing_eof_container() -> io::Result<()> {
+        use futures::TryStreamExt;
+
+        use crate::io::{writer::container::EOF, Writer};
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, Record::default())?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+        let data = &data[..data.len() - EOF.len()];
+
+        let mut reader = Reader::new(data);
+        reader.read_header().await?;
+        assert!(reader.read_data_container().await?.is_some());
+        assert!(matches!(
+            reader.read_data_container().await,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+        assert!(reader.read_data_container().await?.is_none());
+
+        let mut reader = Builder::default()
+            .require_eof(false)
+            .build_from_reader(data);
+        reader.read_header().await?;
+        let records: Vec<_> = reader.records(&header).try_collect().await?;
+        assert_eq!(records.len(), 1);
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_read_format() -> io::Result<()> {
         let data = [0x03, 0x00];
@@ -10,9 +10,9 @@ use tokio::{
 use super::Reader;
 
 /// An async CRAM reader builder.
-#[derive(Default)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    require_eof: bool,
 }
 
 impl Builder {
@@ -37,6 +37,22 @@ impl Builder {
         self
     }
 
+    /// Sets whether the stream is required to end with an EOF container.
+    ///
+    /// When enabled, reaching the end of the stream without reading an EOF container is an
+    /// error. This typically indicates a truncated file. By default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::r#async::io::reader::Builder;
+    /// let builder = Builder::default().require_eof(false);
+    /// ```
+    pub fn require_eof(mut self, value: bool) -> Self {
+        self.require_eof = value;
+        self
+    }
+
     /// Builds an async CRAM reader from a path.
     ///
     /// # Examples
@@ -74,7 +90,18 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            require_eof: self.require_eof,
+            is_eof: false,
             buf: BytesMut::new(),
         }
     }
 }
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            require_eof: true,
+        }
+    }
+}
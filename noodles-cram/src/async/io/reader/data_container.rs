@@ -12,11 +12,12 @@ use crate::{
 pub async fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    require_eof: bool,
 ) -> io::Result<Option<DataContainer>>
 where
     R: AsyncRead + Unpin,
 {
-    let header = match read_header(reader).await? {
+    let header = match read_header(reader, require_eof).await? {
         Some(header) => header,
         None => return Ok(None),
     };
@@ -1,14 +1,14 @@
 use tokio::io::{self, AsyncRead, AsyncReadExt};
 
 use crate::{
-    data_container::Header,
+    container::Header,
     r#async::io::reader::{
         num::{read_itf8, read_ltf8},
         CrcReader,
     },
 };
 
-pub async fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+pub async fn read_header<R>(reader: &mut R, require_eof: bool) -> io::Result<Option<Header>>
 where
     R: AsyncRead + Unpin,
 {
@@ -16,9 +16,16 @@ where
 
     let mut crc_reader = CrcReader::new(reader);
 
-    let length = crc_reader.read_i32_le().await.and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let Some(length) = read_length(&mut crc_reader).await? else {
+        if require_eof {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "missing EOF container",
+            ));
+        } else {
+            return Ok(None);
+        }
+    };
 
     let reference_sequence_id = read_itf8(&mut crc_reader).await?;
     let alignment_start = read_itf8(&mut crc_reader).await?;
@@ -80,6 +87,26 @@ where
     Ok(Some(header))
 }
 
+// Returns `None` if the stream is at its end.
+async fn read_length<R>(reader: &mut R) -> io::Result<Option<usize>>
+where
+    R: AsyncRead + Unpin,
+{
+    let mut buf = [0; 4];
+
+    let n = reader.read(&mut buf).await?;
+
+    if n == 0 {
+        return Ok(None);
+    }
+
+    reader.read_exact(&mut buf[n..]).await?;
+
+    usize::try_from(i32::from_le_bytes(buf))
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 async fn read_landmarks<R>(reader: &mut R) -> io::Result<Vec<usize>>
 where
     R: AsyncRead + Unpin,
@@ -126,7 +153,7 @@ mod tests {
         ];
 
         let mut reader = &data[..];
-        let actual = read_header(&mut reader).await?;
+        let actual = read_header(&mut reader, true).await?;
 
         let expected = Header::builder()
             .set_length(144)
@@ -146,4 +173,20 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    async fn test_read_header_at_end_of_stream() -> io::Result<()> {
+        let data = [];
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_header(&mut reader, true).await,
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof,
+        ));
+
+        let mut reader = &data[..];
+        assert!(read_header(&mut reader, false).await?.is_none());
+
+        Ok(())
+    }
 }
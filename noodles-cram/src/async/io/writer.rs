@@ -186,13 +186,17 @@ where
         use crate::data_container::builder::AddRecordError;
 
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
                 }
                 Err(e) => match e {
-                    AddRecordError::ContainerFull(r) => {
+                    AddRecordError::ContainerFull(r)
+                    | AddRecordError::ReferenceSequenceChanged(r) => {
                         record = r;
                         self.flush(header).await?;
                     }
@@ -1,4 +1,4 @@
-use std::path::Path;
+use std::{num::NonZeroUsize, path::Path};
 
 use noodles_fasta as fasta;
 use tokio::{
@@ -39,6 +39,17 @@ impl Builder {
         self
     }
 
+    /// Sets whether to encode read names using the name tokenizer.
+    ///
+    /// This overrides the read names encoder in the block content-encoder map and sets the output
+    /// version to CRAM 3.1.
+    ///
+    /// The default is `false`.
+    pub fn tokenize_read_names(mut self, value: bool) -> Self {
+        self.options.tokenize_read_names = value;
+        self
+    }
+
     /// Sets whether to encode alignment start positions as deltas.
     ///
     /// If `false`, record alignment start positions are written with their actual values.
@@ -55,6 +66,35 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum number of records per slice.
+    ///
+    /// Smaller slices allow finer-grained queries, while larger slices typically compress better.
+    ///
+    /// The default is 10000.
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// The default is 1.
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
+    /// Sets whether each data container only holds records from a single reference sequence.
+    ///
+    /// If `true`, the current data container is written when a record has a different reference
+    /// sequence ID than the previous record.
+    ///
+    /// The default is `false`.
+    pub fn single_reference_per_container(mut self, value: bool) -> Self {
+        self.options.single_reference_per_container = value;
+        self
+    }
+
     /// Builds an async CRAM writer from a path.
     ///
     /// # Examples
@@ -89,7 +129,17 @@ impl Builder {
     where
         W: AsyncWrite + Unpin,
     {
-        use crate::io::writer::builder::uses_cram_3_1_codecs;
+        use crate::{
+            codecs::Encoder,
+            data_container::compression_header::data_series_encoding_map::DataSeries,
+            io::writer::builder::uses_cram_3_1_codecs,
+        };
+
+        if self.options.tokenize_read_names {
+            self.options
+                .block_content_encoder_map
+                .set_data_series_encoder(DataSeries::ReadNames, Some(Encoder::NameTokenizer));
+        }
 
         if uses_cram_3_1_codecs(&self.options.block_content_encoder_map) {
             self.options.version = Version::new(3, 1);
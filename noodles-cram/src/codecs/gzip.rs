@@ -45,7 +45,7 @@ pub fn encode(compression_level: Compression, src: &[u8]) -> io::Result<Vec<u8>>
     let mut dst = vec![0; max_len];
 
     let len = encoder
-        .deflate_compress(src, &mut dst)
+        .gzip_compress(src, &mut dst)
         .map_err(|_| io::Error::from(io::ErrorKind::InvalidInput))?;
 
     dst.resize(len, 0);
@@ -63,3 +63,25 @@ pub fn encode(compression_level: Compression, src: &[u8]) -> io::Result<Vec<u8>>
     encoder.write_all(src)?;
     encoder.finish()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_self() -> io::Result<()> {
+        const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+
+        let data = b"noodles";
+
+        let compressed_data = encode(Compression::default(), data)?;
+        assert!(compressed_data.starts_with(&GZIP_MAGIC_NUMBER));
+
+        let mut decompressed_data = vec![0; data.len()];
+        decode(&compressed_data, &mut decompressed_data)?;
+
+        assert_eq!(decompressed_data, data);
+
+        Ok(())
+    }
+}
@@ -1,3 +1,32 @@
+//! CRAM container and fields.
+
 pub mod block;
+mod header;
+
+pub use self::{block::Block, header::Header};
+
+/// A CRAM container.
+///
+/// A container has a header and a list of blocks. The blocks are not decoded, i.e., this is the
+/// raw structure of a container as it is stored in a CRAM file.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Container {
+    header: Header,
+    blocks: Vec<Block>,
+}
+
+impl Container {
+    pub(crate) fn new(header: Header, blocks: Vec<Block>) -> Self {
+        Self { header, blocks }
+    }
+
+    /// Returns the container header.
+    pub fn header(&self) -> &Header {
+        &self.header
+    }
 
-pub use self::block::Block;
+    /// Returns the list of blocks.
+    pub fn blocks(&self) -> &[Block] {
+        &self.blocks
+    }
+}
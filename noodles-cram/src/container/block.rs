@@ -1,11 +1,15 @@
+//! CRAM container block.
+
 mod builder;
+mod checksum_mismatch_error;
 mod compression_method;
 mod content_id;
 mod content_type;
 
+pub(crate) use self::builder::Builder;
 pub use self::{
-    builder::Builder, compression_method::CompressionMethod, content_id::ContentId,
-    content_type::ContentType,
+    checksum_mismatch_error::ChecksumMismatchError, compression_method::CompressionMethod,
+    content_id::ContentId, content_type::ContentType,
 };
 
 use std::{io, mem};
@@ -17,6 +21,10 @@ use crate::{
     num::itf8,
 };
 
+/// A CRAM container block.
+///
+/// A block holds (possibly compressed) data for a particular content type, e.g., the compression
+/// header, a slice header, or core or external data.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Block {
     compression_method: CompressionMethod,
@@ -28,30 +36,39 @@ pub struct Block {
 
 #[allow(clippy::len_without_is_empty)]
 impl Block {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the compression method.
     pub fn compression_method(&self) -> CompressionMethod {
         self.compression_method
     }
 
+    /// Returns the content type.
     pub fn content_type(&self) -> ContentType {
         self.content_type
     }
 
+    /// Returns the content ID.
     pub fn content_id(&self) -> ContentId {
         self.content_id
     }
 
+    /// Returns the size of the uncompressed data.
     pub fn uncompressed_len(&self) -> usize {
         self.uncompressed_len
     }
 
+    /// Returns the raw (possibly compressed) data.
     pub fn data(&self) -> &[u8] {
         &self.data
     }
 
+    /// Decompresses and returns the data.
+    ///
+    /// The stored data is decoded using the block compression method. If the block is
+    /// uncompressed, this returns a shallow copy of the data.
     pub fn decompressed_data(&self) -> io::Result<Bytes> {
         use crate::codecs::{bzip2, gzip, lzma};
 
@@ -95,6 +112,7 @@ impl Block {
         }
     }
 
+    /// Returns the encoded size of the block.
     pub fn len(&self) -> usize {
         // method
         mem::size_of::<u8>()
@@ -125,4 +143,34 @@ mod tests {
 
         assert_eq!(block.len(), 16);
     }
+
+    #[test]
+    fn test_decompressed_data() -> io::Result<()> {
+        use flate2::Compression;
+
+        use crate::codecs::gzip;
+
+        let data = b"noodles";
+
+        let block = Block::builder()
+            .set_content_type(ContentType::ExternalData)
+            .set_uncompressed_len(data.len())
+            .set_data(Bytes::from_static(data))
+            .build();
+
+        assert_eq!(&block.decompressed_data()?[..], data);
+
+        let compressed_data = gzip::encode(Compression::default(), data)?;
+
+        let block = Block::builder()
+            .set_compression_method(CompressionMethod::Gzip)
+            .set_content_type(ContentType::ExternalData)
+            .set_uncompressed_len(data.len())
+            .set_data(Bytes::from(compressed_data))
+            .build();
+
+        assert_eq!(&block.decompressed_data()?[..], data);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,68 @@
+use std::{error, fmt, io};
+
+use super::{ContentId, ContentType};
+
+/// An error returned when the CRC32 of a CRAM block does not match its stored checksum.
+///
+/// This is the source of an [`io::ErrorKind::InvalidData`] error returned by the readers.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ChecksumMismatchError {
+    content_type: ContentType,
+    content_id: ContentId,
+    expected: u32,
+    actual: u32,
+}
+
+impl ChecksumMismatchError {
+    pub(crate) fn new(
+        content_type: ContentType,
+        content_id: ContentId,
+        expected: u32,
+        actual: u32,
+    ) -> Self {
+        Self {
+            content_type,
+            content_id,
+            expected,
+            actual,
+        }
+    }
+
+    /// Returns the content type of the corrupt block.
+    pub fn content_type(&self) -> ContentType {
+        self.content_type
+    }
+
+    /// Returns the content ID of the corrupt block.
+    pub fn content_id(&self) -> ContentId {
+        self.content_id
+    }
+
+    /// Returns the checksum stored in the block.
+    pub fn expected(&self) -> u32 {
+        self.expected
+    }
+
+    /// Returns the checksum calculated from the block.
+    pub fn actual(&self) -> u32 {
+        self.actual
+    }
+}
+
+impl error::Error for ChecksumMismatchError {}
+
+impl fmt::Display for ChecksumMismatchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "container block checksum mismatch ({:?}, content ID {}): expected {:08x}, got {:08x}",
+            self.content_type, self.content_id, self.expected, self.actual
+        )
+    }
+}
+
+impl From<ChecksumMismatchError> for io::Error {
+    fn from(e: ChecksumMismatchError) -> Self {
+        Self::new(io::ErrorKind::InvalidData, e)
+    }
+}
@@ -1,5 +1,10 @@
+//! CRAM container block content ID.
+
 use std::fmt;
 
+/// A CRAM container block content ID.
+///
+/// The content ID is used to associate external blocks with data series or tag encodings.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Hash)]
 pub struct ContentId(i32);
 
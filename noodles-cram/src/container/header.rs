@@ -1,9 +1,10 @@
 mod builder;
 
-pub use self::builder::Builder;
+pub(crate) use self::builder::Builder;
 
-use super::ReferenceSequenceContext;
+use crate::data_container::ReferenceSequenceContext;
 
+/// A CRAM container header.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Header {
     length: usize,
@@ -17,34 +18,43 @@ pub struct Header {
 
 #[allow(clippy::len_without_is_empty)]
 impl Header {
-    pub fn builder() -> Builder {
+    pub(crate) fn builder() -> Builder {
         Builder::default()
     }
 
+    /// Returns the length of the container, excluding the header.
     pub fn len(&self) -> usize {
         self.length
     }
 
+    /// Returns the reference sequence context.
     pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
         self.reference_sequence_context
     }
 
+    /// Returns the number of records in the container.
     pub fn record_count(&self) -> i32 {
         self.record_count
     }
 
+    /// Returns the index of the first record in the container in the stream.
     pub fn record_counter(&self) -> u64 {
         self.record_counter
     }
 
+    /// Returns the number of read bases in the container.
     pub fn base_count(&self) -> u64 {
         self.base_count
     }
 
+    /// Returns the number of blocks in the container.
     pub fn block_count(&self) -> usize {
         self.block_count
     }
 
+    /// Returns the positions of the slices in the container.
+    ///
+    /// These are byte offsets from the end of the container header.
     pub fn landmarks(&self) -> &[usize] {
         &self.landmarks
     }
@@ -12,7 +12,11 @@ pub use self::{reader::Reader, record::Record, writer::Writer};
 #[cfg(feature = "async")]
 pub use self::r#async::{Reader as AsyncReader, Writer as AsyncWriter};
 
-use std::{fs::File, io, path::Path};
+use std::{
+    fs::File,
+    io::{self, BufRead, Write},
+    path::Path,
+};
 
 /// A CRAM index.
 pub type Index = Vec<Record>;
@@ -59,3 +63,91 @@ where
     let mut writer = File::open(dst).map(Writer::new)?;
     writer.write_index(index)
 }
+
+/// Reads a CRAM index from its uncompressed text form.
+///
+/// Each line is a tab-separated record with the reference sequence ID, alignment start, alignment
+/// span, container offset, slice landmark, and slice length. This is the layout of a CRAM index
+/// after it is decompressed.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::Position;
+/// use noodles_cram::crai;
+///
+/// let data = b"0\t10946\t6765\t17711\t233\t317811\n";
+/// let index = crai::read_text(&mut &data[..])?;
+///
+/// assert_eq!(
+///     index,
+///     [crai::Record::new(Some(0), Position::new(10946), 6765, 17711, 233, 317811)]
+/// );
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn read_text<R>(reader: &mut R) -> io::Result<Index>
+where
+    R: BufRead,
+{
+    reader::read_index(reader)
+}
+
+/// Writes a CRAM index in its uncompressed text form.
+///
+/// This writes the human-readable layout described in [`read_text`]. Unlike [`Writer`], the
+/// output is not compressed.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_core::Position;
+/// use noodles_cram::crai;
+///
+/// let index = [crai::Record::new(Some(0), Position::new(10946), 6765, 17711, 233, 317811)];
+///
+/// let mut buf = Vec::new();
+/// crai::write_text(&mut buf, &index)?;
+///
+/// assert_eq!(buf, b"0\t10946\t6765\t17711\t233\t317811\n");
+/// # Ok::<(), io::Error>(())
+/// ```
+pub fn write_text<W>(writer: &mut W, index: &[Record]) -> io::Result<()>
+where
+    W: Write,
+{
+    writer::write_index(writer, index)
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+
+    #[test]
+    fn test_write_text_and_read_text() -> io::Result<()> {
+        let index = vec![
+            Record::new(Some(0), Position::new(10946), 6765, 17711, 233, 317811),
+            Record::new(Some(1), Position::new(17711), 121393, 317811, 233, 317811),
+            Record::new(None, None, 0, 514229, 233, 832040),
+        ];
+
+        let mut buf = Vec::new();
+        write_text(&mut buf, &index)?;
+
+        let expected = b"\
+0\t10946\t6765\t17711\t233\t317811
+1\t17711\t121393\t317811\t233\t317811
+-1\t0\t0\t514229\t233\t832040
+";
+
+        assert_eq!(buf, expected);
+
+        let actual = read_text(&mut &buf[..])?;
+        assert_eq!(actual, index);
+
+        Ok(())
+    }
+}
@@ -42,27 +42,34 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_index(&mut self) -> io::Result<Index> {
-        let mut buf = String::new();
-        let mut index = Vec::new();
-
-        loop {
-            buf.clear();
-
-            match read_line(&mut self.inner, &mut buf) {
-                Ok(0) => break,
-                Ok(_) => {
-                    let record = buf
-                        .parse()
-                        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-
-                    index.push(record);
-                }
-                Err(e) => return Err(e),
+        read_index(&mut self.inner)
+    }
+}
+
+pub(super) fn read_index<R>(reader: &mut R) -> io::Result<Index>
+where
+    R: BufRead,
+{
+    let mut buf = String::new();
+    let mut index = Vec::new();
+
+    loop {
+        buf.clear();
+
+        match read_line(reader, &mut buf) {
+            Ok(0) => break,
+            Ok(_) => {
+                let record = buf
+                    .parse()
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+                index.push(record);
             }
+            Err(e) => return Err(e),
         }
-
-        Ok(index)
     }
+
+    Ok(index)
 }
 
 fn read_line<R>(reader: &mut R, buf: &mut String) -> io::Result<usize>
@@ -89,7 +89,7 @@ where
     }
 }
 
-fn write_index<W>(writer: &mut W, index: &[Record]) -> io::Result<()>
+pub(super) fn write_index<W>(writer: &mut W, index: &[Record]) -> io::Result<()>
 where
     W: Write,
 {
@@ -3,17 +3,20 @@
 pub mod block_content_encoder_map;
 pub(crate) mod builder;
 pub mod compression_header;
-mod header;
-mod reference_sequence_context;
+pub mod reference_sequence_context;
 pub(crate) mod slice;
 
+use std::io;
+
+use noodles_fasta as fasta;
+use noodles_sam as sam;
+
+pub(crate) use self::builder::Builder;
 pub use self::{
     block_content_encoder_map::BlockContentEncoderMap, compression_header::CompressionHeader,
-    slice::Slice,
-};
-pub(crate) use self::{
-    builder::Builder, header::Header, reference_sequence_context::ReferenceSequenceContext,
+    reference_sequence_context::ReferenceSequenceContext, slice::Slice,
 };
+use crate::Record;
 
 /// A CRAM data container.
 pub struct DataContainer {
@@ -42,4 +45,151 @@ impl DataContainer {
     pub fn slices(&self) -> &[Slice] {
         &self.slices
     }
+
+    /// Decodes and returns the records in all slices of this data container.
+    ///
+    /// Records are resolved, i.e., their mates, read names, bases, and quality scores are
+    /// restored. The reference sequence repository supplies the reference bases used to
+    /// reconstruct sequences.
+    ///
+    /// This does not require the reader the data container was read from and can be used to
+    /// decode containers independently, e.g., in parallel.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    /// use noodles_fasta as fasta;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let reference_sequence_repository = fasta::Repository::default();
+    ///
+    /// while let Some(data_container) = reader.read_data_container()? {
+    ///     for record in data_container.records(&reference_sequence_repository, &header)? {
+    ///         // ...
+    ///     }
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn records(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+    ) -> io::Result<Vec<Record>> {
+        let mut records = Vec::new();
+
+        for slice in &self.slices {
+            let mut slice_records = slice.records(&self.compression_header)?;
+
+            slice.resolve_records(
+                reference_sequence_repository,
+                header,
+                &self.compression_header,
+                &mut slice_records,
+            )?;
+
+            records.extend(slice_records);
+        }
+
+        Ok(records)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_records() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::Definition;
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::{writer, Reader};
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let expected: Vec<_> = [(1, b"TTCA"), (3, b"CAGC")]
+            .into_iter()
+            .map(|(alignment_start, sequence)| {
+                Ok(RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(alignment_start)?)
+                    .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect::<Cigar>())
+                    .set_sequence(Sequence::from(sequence))
+                    .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                    .build())
+            })
+            .collect::<Result<_, noodles_core::position::TryFromIntError>>()?;
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &expected {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let data_container = reader
+            .read_data_container()?
+            .expect("missing data container");
+
+        let actual: Vec<_> = data_container
+            .records(&repository, &header)?
+            .into_iter()
+            .map(|record| record.try_into_alignment_record(&header))
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), expected.len());
+
+        for (actual_record, expected_record) in actual.iter().zip(&expected) {
+            assert_eq!(
+                actual_record.alignment_start(),
+                expected_record.alignment_start()
+            );
+            assert_eq!(actual_record.sequence(), expected_record.sequence());
+            assert_eq!(
+                actual_record.quality_scores(),
+                expected_record.quality_scores()
+            );
+        }
+
+        assert!(reader.read_data_container()?.is_none());
+
+        Ok(())
+    }
 }
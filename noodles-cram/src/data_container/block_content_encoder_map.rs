@@ -6,7 +6,10 @@ pub use self::builder::Builder;
 
 use std::collections::HashMap;
 
-use crate::{codecs::Encoder, container::block};
+use crate::{
+    codecs::Encoder, container::block,
+    data_container::compression_header::data_series_encoding_map::DataSeries,
+};
 
 /// A CRAM data container block content-encoder map.
 #[derive(Clone, Debug)]
@@ -45,6 +48,15 @@ impl BlockContentEncoderMap {
         self.data_series_encoders.get(i).map(|e| e.as_ref())
     }
 
+    pub(crate) fn set_data_series_encoder(
+        &mut self,
+        data_series: DataSeries,
+        encoder: Option<Encoder>,
+    ) {
+        let i = (i32::from(block::ContentId::from(data_series)) as usize) - 1;
+        self.data_series_encoders[i] = encoder;
+    }
+
     pub(crate) fn tag_values_encoders(&self) -> &HashMap<block::ContentId, Option<Encoder>> {
         &self.tag_values_encoders
     }
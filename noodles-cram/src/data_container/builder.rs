@@ -6,14 +6,13 @@ use noodles_sam as sam;
 use super::{slice, CompressionHeader, DataContainer, Slice};
 use crate::{io::writer::Options, Record};
 
-const MAX_SLICE_COUNT: usize = 1;
-
 #[derive(Debug)]
 pub struct Builder {
     slice_builder: slice::Builder,
     slice_builders: Vec<slice::Builder>,
     record_counter: u64,
     base_count: u64,
+    reference_sequence_id: Option<usize>,
 }
 
 #[derive(Clone, Debug, PartialEq)]
@@ -21,6 +20,7 @@ pub enum AddRecordError {
     InvalidRecordReadLength(num::TryFromIntError),
     ContainerFull(Record),
     SliceFull(Record),
+    ReferenceSequenceChanged(Record),
 }
 
 impl Builder {
@@ -30,6 +30,7 @@ impl Builder {
             slice_builders: Vec::new(),
             record_counter,
             base_count: 0,
+            reference_sequence_id: None,
         }
     }
 
@@ -42,12 +43,23 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<(), AddRecordError> {
-        if self.slice_builders.len() >= MAX_SLICE_COUNT {
+    pub fn add_record(&mut self, options: &Options, record: Record) -> Result<(), AddRecordError> {
+        if self.slice_builders.len() >= options.slices_per_container.get() {
             return Err(AddRecordError::ContainerFull(record));
         }
 
-        match self.slice_builder.add_record(record) {
+        if options.single_reference_per_container {
+            if self.is_empty() {
+                self.reference_sequence_id = record.reference_sequence_id();
+            } else if record.reference_sequence_id() != self.reference_sequence_id {
+                return Err(AddRecordError::ReferenceSequenceChanged(record));
+            }
+        }
+
+        match self
+            .slice_builder
+            .add_record(options.records_per_slice.get(), record)
+        {
             Ok(r) => {
                 self.base_count += u64::try_from(r.read_length())
                     .map_err(AddRecordError::InvalidRecordReadLength)?;
@@ -122,3 +134,30 @@ fn build_compression_header(
 
     compression_header_builder.build()
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_record_with_single_reference_per_container() {
+        let options = Options {
+            single_reference_per_container: true,
+            ..Default::default()
+        };
+
+        let mut builder = Builder::new(0);
+
+        let record = Record::builder().set_reference_sequence_id(0).build();
+        assert!(builder.add_record(&options, record).is_ok());
+
+        let record = Record::builder().set_reference_sequence_id(0).build();
+        assert!(builder.add_record(&options, record).is_ok());
+
+        let record = Record::builder().set_reference_sequence_id(1).build();
+        assert_eq!(
+            builder.add_record(&options, record.clone()),
+            Err(AddRecordError::ReferenceSequenceChanged(record))
+        );
+    }
+}
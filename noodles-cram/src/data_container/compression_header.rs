@@ -6,6 +6,7 @@ pub(crate) mod encoding;
 pub mod preservation_map;
 mod tag_encoding_map;
 
+pub use self::encoding::Kind as EncodingKind;
 pub(crate) use self::{
     builder::Builder,
     data_series_encoding_map::DataSeriesEncodingMap,
@@ -14,6 +15,9 @@ pub(crate) use self::{
     tag_encoding_map::TagEncodingMap,
 };
 
+use self::data_series_encoding_map::DataSeries;
+use crate::container::block;
+
 /// A CRAM data container compression header.
 ///
 /// The compression header has three maps with information about how the data is compressed: a
@@ -53,4 +57,83 @@ impl CompressionHeader {
     pub(crate) fn tag_encoding_map(&self) -> &TagEncodingMap {
         &self.tag_encoding_map
     }
+
+    /// Returns the encoding kind of each data series in the data series encoding map.
+    ///
+    /// Data series without an encoding are omitted. The list is in the order of the standard
+    /// data series.
+    pub fn data_series_encoding_kinds(&self) -> Vec<(DataSeries, EncodingKind)> {
+        self.data_series_encoding_map.encoding_kinds()
+    }
+
+    /// Returns the encoding kind of each tag in the tag encoding map.
+    ///
+    /// Tags are keyed by their block content IDs, which are composed of the tag and its type
+    /// (`tag[0] << 16 | tag[1] << 8 | type`). The list is sorted by block content ID.
+    pub fn tag_encoding_kinds(&self) -> Vec<(block::ContentId, EncodingKind)> {
+        let mut kinds: Vec<_> = self
+            .tag_encoding_map
+            .iter()
+            .map(|(block_content_id, encoding)| (*block_content_id, encoding.get().kind()))
+            .collect();
+
+        kinds.sort_unstable_by_key(|(block_content_id, _)| i32::from(*block_content_id));
+
+        kinds
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_container::compression_header::encoding::codec::{Byte, ByteArray, Integer};
+
+    #[test]
+    fn test_encoding_kinds() {
+        let mut compression_header = CompressionHeader::builder().build();
+
+        let nm_c = block::ContentId::from(5131587); // NM:C
+        let co_z = block::ContentId::from(4411226); // CO:Z
+
+        compression_header.tag_encoding_map = TagEncodingMap::from(
+            [
+                (
+                    nm_c,
+                    Encoding::new(ByteArray::ByteArrayLen(
+                        Encoding::new(Integer::Huffman(vec![1], vec![0])),
+                        Encoding::new(Byte::External(nm_c)),
+                    )),
+                ),
+                (co_z, Encoding::new(ByteArray::ByteArrayStop(0x00, co_z))),
+            ]
+            .into_iter()
+            .collect::<std::collections::HashMap<_, _>>(),
+        );
+
+        let data_series_encoding_kinds = compression_header.data_series_encoding_kinds();
+        assert_eq!(data_series_encoding_kinds.len(), 28);
+
+        let actual: Vec<_> = data_series_encoding_kinds
+            .into_iter()
+            .map(|(data_series, kind)| (<[u8; 2]>::from(data_series), kind))
+            .filter(|(key, _)| matches!(key, b"BF" | b"RL" | b"QS"))
+            .collect();
+
+        assert_eq!(
+            actual,
+            [
+                (*b"BF", EncodingKind::External),
+                (*b"RL", EncodingKind::External),
+                (*b"QS", EncodingKind::External),
+            ]
+        );
+
+        assert_eq!(
+            compression_header.tag_encoding_kinds(),
+            [
+                (co_z, EncodingKind::ByteArrayStop),
+                (nm_c, EncodingKind::ByteArrayLen),
+            ]
+        );
+    }
 }
@@ -7,7 +7,10 @@ pub(crate) use self::builder::Builder;
 pub use self::data_series::DataSeries;
 
 use super::{
-    encoding::codec::{Byte, ByteArray, Integer},
+    encoding::{
+        codec::{Byte, ByteArray, Integer},
+        Kind,
+    },
     Encoding,
 };
 use crate::container::block;
@@ -148,6 +151,280 @@ impl DataSeriesEncodingMap {
         n
     }
 
+    /// Returns the encoding kind of each data series with an encoding.
+    pub fn encoding_kinds(&self) -> Vec<(DataSeries, Kind)> {
+        [
+            (
+                DataSeries::BamBitFlags,
+                Some(self.bam_bit_flags_encoding().get().kind()),
+            ),
+            (
+                DataSeries::CramBitFlags,
+                Some(self.cram_bit_flags_encoding().get().kind()),
+            ),
+            (
+                DataSeries::ReferenceId,
+                self.reference_id_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::ReadLengths,
+                Some(self.read_lengths_encoding().get().kind()),
+            ),
+            (
+                DataSeries::InSeqPositions,
+                Some(self.in_seq_positions_encoding().get().kind()),
+            ),
+            (
+                DataSeries::ReadGroups,
+                Some(self.read_groups_encoding().get().kind()),
+            ),
+            (
+                DataSeries::ReadNames,
+                self.read_names_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::NextMateBitFlags,
+                self.next_mate_bit_flags_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::NextFragmentReferenceSequenceId,
+                self.next_fragment_reference_sequence_id_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::NextMateAlignmentStart,
+                self.next_mate_alignment_start_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::TemplateSize,
+                self.template_size_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::DistanceToNextFragment,
+                self.distance_to_next_fragment_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::TagIds,
+                Some(self.tag_ids_encoding().get().kind()),
+            ),
+            (
+                DataSeries::NumberOfReadFeatures,
+                self.number_of_read_features_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::ReadFeaturesCodes,
+                self.read_features_codes_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::InReadPositions,
+                self.in_read_positions_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::DeletionLengths,
+                self.deletion_lengths_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::StretchesOfBases,
+                self.stretches_of_bases_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::StretchesOfQualityScores,
+                self.stretches_of_quality_scores_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::BaseSubstitutionCodes,
+                self.base_substitution_codes_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::Insertion,
+                self.insertion_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::ReferenceSkipLength,
+                self.reference_skip_length_encoding()
+                    .map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::Padding,
+                self.padding_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::HardClip,
+                self.hard_clip_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::SoftClip,
+                self.soft_clip_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::MappingQualities,
+                self.mapping_qualities_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::Bases,
+                self.bases_encoding().map(|e| e.get().kind()),
+            ),
+            (
+                DataSeries::QualityScores,
+                self.quality_scores_encoding().map(|e| e.get().kind()),
+            ),
+        ]
+        .into_iter()
+        .filter_map(|(data_series, kind)| kind.map(|k| (data_series, k)))
+        .collect()
+    }
+
+    /// Returns the content IDs of the external blocks used by each data series.
+    pub fn block_content_ids(&self) -> Vec<(DataSeries, block::ContentId)> {
+        [
+            (
+                DataSeries::BamBitFlags,
+                Some(self.bam_bit_flags_encoding().get().block_content_ids()),
+            ),
+            (
+                DataSeries::CramBitFlags,
+                Some(self.cram_bit_flags_encoding().get().block_content_ids()),
+            ),
+            (
+                DataSeries::ReferenceId,
+                self.reference_id_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::ReadLengths,
+                Some(self.read_lengths_encoding().get().block_content_ids()),
+            ),
+            (
+                DataSeries::InSeqPositions,
+                Some(self.in_seq_positions_encoding().get().block_content_ids()),
+            ),
+            (
+                DataSeries::ReadGroups,
+                Some(self.read_groups_encoding().get().block_content_ids()),
+            ),
+            (
+                DataSeries::ReadNames,
+                self.read_names_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::NextMateBitFlags,
+                self.next_mate_bit_flags_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::NextFragmentReferenceSequenceId,
+                self.next_fragment_reference_sequence_id_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::NextMateAlignmentStart,
+                self.next_mate_alignment_start_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::TemplateSize,
+                self.template_size_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::DistanceToNextFragment,
+                self.distance_to_next_fragment_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::TagIds,
+                Some(self.tag_ids_encoding().get().block_content_ids()),
+            ),
+            (
+                DataSeries::NumberOfReadFeatures,
+                self.number_of_read_features_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::ReadFeaturesCodes,
+                self.read_features_codes_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::InReadPositions,
+                self.in_read_positions_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::DeletionLengths,
+                self.deletion_lengths_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::StretchesOfBases,
+                self.stretches_of_bases_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::StretchesOfQualityScores,
+                self.stretches_of_quality_scores_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::BaseSubstitutionCodes,
+                self.base_substitution_codes_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::Insertion,
+                self.insertion_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::ReferenceSkipLength,
+                self.reference_skip_length_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::Padding,
+                self.padding_encoding().map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::HardClip,
+                self.hard_clip_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::SoftClip,
+                self.soft_clip_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::MappingQualities,
+                self.mapping_qualities_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::Bases,
+                self.bases_encoding().map(|e| e.get().block_content_ids()),
+            ),
+            (
+                DataSeries::QualityScores,
+                self.quality_scores_encoding()
+                    .map(|e| e.get().block_content_ids()),
+            ),
+        ]
+        .into_iter()
+        .flat_map(|(data_series, block_content_ids)| {
+            block_content_ids
+                .into_iter()
+                .flatten()
+                .map(move |block_content_id| (data_series, block_content_id))
+        })
+        .collect()
+    }
+
     pub fn bam_bit_flags_encoding(&self) -> &Encoding<Integer> {
         &self.bam_bit_flags_encoding
     }
@@ -385,4 +662,76 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_block_content_ids() -> Result<(), builder::BuildError> {
+        let map = DataSeriesEncodingMap::builder()
+            .set_bam_bit_flags_encoding(Encoding::new(Integer::Huffman(vec![0], vec![0])))
+            .set_cram_bit_flags_encoding(Encoding::new(Integer::External(block::ContentId::from(
+                2,
+            ))))
+            .set_read_lengths_encoding(Encoding::new(Integer::Beta(0, 8)))
+            .set_in_seq_positions_encoding(Encoding::new(Integer::External(
+                block::ContentId::from(5),
+            )))
+            .set_read_groups_encoding(Encoding::new(Integer::External(block::ContentId::from(6))))
+            .set_tag_ids_encoding(Encoding::new(Integer::External(block::ContentId::from(13))))
+            .set_read_names_encoding(Encoding::new(ByteArray::ByteArrayLen(
+                Encoding::new(Integer::External(block::ContentId::from(8))),
+                Encoding::new(Byte::External(block::ContentId::from(7))),
+            )))
+            .set_quality_scores_encoding(Encoding::new(Byte::External(block::ContentId::from(12))))
+            .build()?;
+
+        assert_eq!(
+            map.block_content_ids(),
+            [
+                (DataSeries::CramBitFlags, block::ContentId::from(2)),
+                (DataSeries::InSeqPositions, block::ContentId::from(5)),
+                (DataSeries::ReadGroups, block::ContentId::from(6)),
+                (DataSeries::ReadNames, block::ContentId::from(8)),
+                (DataSeries::ReadNames, block::ContentId::from(7)),
+                (DataSeries::TagIds, block::ContentId::from(13)),
+                (DataSeries::QualityScores, block::ContentId::from(12)),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_encoding_kinds() -> Result<(), builder::BuildError> {
+        let map = DataSeriesEncodingMap::default();
+        let kinds = map.encoding_kinds();
+        assert_eq!(kinds.len(), map.len());
+        assert_eq!(kinds[0], (DataSeries::BamBitFlags, Kind::External));
+        assert_eq!(kinds[6], (DataSeries::ReadNames, Kind::ByteArrayStop));
+
+        let map = DataSeriesEncodingMap::builder()
+            .set_bam_bit_flags_encoding(Encoding::new(Integer::Huffman(vec![0], vec![0])))
+            .set_cram_bit_flags_encoding(Encoding::new(Integer::External(block::ContentId::from(
+                2,
+            ))))
+            .set_read_lengths_encoding(Encoding::new(Integer::Beta(0, 8)))
+            .set_in_seq_positions_encoding(Encoding::new(Integer::External(
+                block::ContentId::from(5),
+            )))
+            .set_read_groups_encoding(Encoding::new(Integer::External(block::ContentId::from(6))))
+            .set_tag_ids_encoding(Encoding::new(Integer::External(block::ContentId::from(13))))
+            .build()?;
+
+        assert_eq!(
+            map.encoding_kinds(),
+            [
+                (DataSeries::BamBitFlags, Kind::Huffman),
+                (DataSeries::CramBitFlags, Kind::External),
+                (DataSeries::ReadLengths, Kind::Beta),
+                (DataSeries::InSeqPositions, Kind::External),
+                (DataSeries::ReadGroups, Kind::External),
+                (DataSeries::TagIds, Kind::External),
+            ]
+        );
+
+        Ok(())
+    }
 }
@@ -5,7 +5,7 @@ use bytes::Buf;
 
 use crate::{
     container::block,
-    data_container::compression_header::encoding::{Decode, Encode},
+    data_container::compression_header::encoding::{Decode, Encode, Kind},
     huffman::CanonicalHuffmanDecoder,
     io::{reader::record::ExternalDataReaders, BitReader, BitWriter},
 };
@@ -19,9 +19,23 @@ pub enum Byte {
 }
 
 impl Byte {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::External(_) => Kind::External,
+            Self::Huffman(..) => Kind::Huffman,
+        }
+    }
+
+    pub fn block_content_ids(&self) -> Vec<block::ContentId> {
+        match self {
+            Self::External(block_content_id) => vec![*block_content_id],
+            Self::Huffman(..) => Vec::new(),
+        }
+    }
+
     pub fn decode_exact<R, S>(
         &self,
-        _core_data_reader: &mut BitReader<R>,
+        core_data_reader: &mut BitReader<R>,
         external_data_readers: &mut ExternalDataReaders<S>,
         dst: &mut [u8],
     ) -> io::Result<()>
@@ -46,7 +60,58 @@ impl Byte {
 
                 src.copy_to_slice(dst);
             }
-            Byte::Huffman(..) => todo!(),
+            Byte::Huffman(alphabet, bit_lens) => {
+                if alphabet.len() == 1 {
+                    dst.fill(alphabet[0] as u8);
+                } else {
+                    let decoder = CanonicalHuffmanDecoder::new(alphabet, bit_lens);
+
+                    for value in dst.iter_mut() {
+                        *value = decoder.decode(core_data_reader).map(|i| i as u8)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn skip_exact<R, S>(
+        &self,
+        core_data_reader: &mut BitReader<R>,
+        external_data_readers: &mut ExternalDataReaders<S>,
+        len: usize,
+    ) -> io::Result<()>
+    where
+        R: Buf,
+        S: Buf,
+    {
+        match self {
+            Byte::External(block_content_id) => {
+                let src = external_data_readers
+                    .get_mut(block_content_id)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("missing external block: {block_content_id}"),
+                        )
+                    })?;
+
+                if src.remaining() < len {
+                    return Err(io::Error::from(io::ErrorKind::UnexpectedEof));
+                }
+
+                src.advance(len);
+            }
+            Byte::Huffman(alphabet, bit_lens) => {
+                if alphabet.len() > 1 {
+                    let decoder = CanonicalHuffmanDecoder::new(alphabet, bit_lens);
+
+                    for _ in 0..len {
+                        decoder.decode(core_data_reader)?;
+                    }
+                }
+            }
         }
 
         Ok(())
@@ -145,6 +210,49 @@ mod tests {
 
         assert_eq!(dst, external_data);
 
+        let codec = Byte::Huffman(vec![0x2d], vec![0]);
+        let mut dst = vec![0; 4];
+        codec.decode_exact(&mut core_data_reader, &mut external_data_readers, &mut dst)?;
+        assert_eq!(dst, [0x2d; 4]);
+
+        let core_data = [0b01100000];
+        let mut core_data_reader = BitReader::new(&core_data[..]);
+        let codec = Byte::Huffman(vec![0x0d, 0x2d], vec![1, 1]);
+        let mut dst = vec![0; 4];
+        codec.decode_exact(&mut core_data_reader, &mut external_data_readers, &mut dst)?;
+        assert_eq!(dst, [0x0d, 0x2d, 0x2d, 0x0d]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_skip_exact() -> io::Result<()> {
+        let core_data = [0b01100000];
+        let mut core_data_reader = BitReader::new(&core_data[..]);
+
+        let external_data = b"ndls";
+        let mut external_data_readers = ExternalDataReaders::new();
+        external_data_readers.insert(block::ContentId::from(1), &external_data[..]);
+
+        let codec = Byte::External(block::ContentId::from(1));
+        codec.skip_exact(&mut core_data_reader, &mut external_data_readers, 3)?;
+        assert_eq!(
+            codec.decode(&mut core_data_reader, &mut external_data_readers)?,
+            b's'
+        );
+
+        assert!(matches!(
+            codec.skip_exact(&mut core_data_reader, &mut external_data_readers, 1),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let codec = Byte::Huffman(vec![0x0d, 0x2d], vec![1, 1]);
+        codec.skip_exact(&mut core_data_reader, &mut external_data_readers, 3)?;
+        assert_eq!(
+            codec.decode(&mut core_data_reader, &mut external_data_readers)?,
+            0x0d
+        );
+
         Ok(())
     }
 
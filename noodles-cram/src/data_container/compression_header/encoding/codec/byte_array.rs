@@ -11,7 +11,7 @@ use crate::{
     data_container::compression_header::{
         encoding::{
             codec::{Byte, Integer},
-            Decode, Encode,
+            Decode, Encode, Kind,
         },
         Encoding,
     },
@@ -26,6 +26,73 @@ pub enum ByteArray {
     ByteArrayStop(u8, block::ContentId),
 }
 
+impl ByteArray {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::ByteArrayLen(..) => Kind::ByteArrayLen,
+            Self::ByteArrayStop(..) => Kind::ByteArrayStop,
+        }
+    }
+
+    pub fn block_content_ids(&self) -> Vec<block::ContentId> {
+        match self {
+            Self::ByteArrayLen(len_encoding, value_encoding) => {
+                let mut block_content_ids = len_encoding.get().block_content_ids();
+                block_content_ids.extend(value_encoding.get().block_content_ids());
+                block_content_ids
+            }
+            Self::ByteArrayStop(_, block_content_id) => vec![*block_content_id],
+        }
+    }
+
+    pub fn skip<R, S>(
+        &self,
+        core_data_reader: &mut BitReader<R>,
+        external_data_readers: &mut ExternalDataReaders<S>,
+    ) -> io::Result<()>
+    where
+        R: Buf,
+        S: Buf,
+    {
+        match self {
+            ByteArray::ByteArrayLen(len_encoding, value_encoding) => {
+                let len = len_encoding
+                    .decode(core_data_reader, external_data_readers)
+                    .and_then(|n| {
+                        usize::try_from(n)
+                            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+                    })?;
+
+                value_encoding
+                    .get()
+                    .skip_exact(core_data_reader, external_data_readers, len)
+            }
+            ByteArray::ByteArrayStop(stop_byte, block_content_id) => {
+                let src = external_data_readers
+                    .get_mut(block_content_id)
+                    .ok_or_else(|| {
+                        io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("missing external block: {block_content_id}"),
+                        )
+                    })?;
+
+                let Some(len) = src.chunk().iter().position(|&b| b == *stop_byte) else {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "missing byte array stop byte",
+                    ));
+                };
+
+                // Skip the value and the stop byte.
+                src.advance(len + 1);
+
+                Ok(())
+            }
+        }
+    }
+}
+
 impl Decode for ByteArray {
     type Value = Vec<u8>;
 
@@ -173,6 +240,42 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_skip() -> io::Result<()> {
+        fn t(external_data: &[u8], codec: &ByteArray) -> io::Result<u8> {
+            let core_data = [];
+            let mut core_data_reader = BitReader::new(&core_data[..]);
+
+            let mut external_data_readers = ExternalDataReaders::new();
+            external_data_readers.insert(block::ContentId::from(1), external_data);
+
+            codec.skip(&mut core_data_reader, &mut external_data_readers)?;
+
+            Byte::External(block::ContentId::from(1))
+                .decode(&mut core_data_reader, &mut external_data_readers)
+        }
+
+        let len_encoding = Encoding::new(Integer::External(block::ContentId::from(1)));
+        let value_encoding = Encoding::new(Byte::External(block::ContentId::from(1)));
+        let codec = ByteArray::ByteArrayLen(len_encoding, value_encoding);
+        assert_eq!(t(&[0x03, 0x6e, 0x64, 0x6c, 0x73], &codec)?, b's');
+
+        assert!(matches!(
+            t(&[0x05, 0x6e, 0x64, 0x6c, 0x73], &codec),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+
+        let codec = ByteArray::ByteArrayStop(0x00, block::ContentId::from(1));
+        assert_eq!(t(&[0x6e, 0x64, 0x6c, 0x00, 0x73], &codec)?, b's');
+
+        assert!(matches!(
+            t(&[0x6e, 0x64, 0x6c, 0x73], &codec),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_encode() -> io::Result<()> {
         fn t(
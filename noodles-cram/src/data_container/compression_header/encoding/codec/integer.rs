@@ -7,7 +7,7 @@ use bytes::Buf;
 
 use crate::{
     container::block,
-    data_container::compression_header::encoding::{Decode, Encode},
+    data_container::compression_header::encoding::{Decode, Encode, Kind},
     huffman::CanonicalHuffmanDecoder,
     io::{
         reader::{num::get_itf8, record::ExternalDataReaders},
@@ -34,6 +34,27 @@ pub enum Integer {
     Gamma(i32),
 }
 
+impl Integer {
+    pub fn kind(&self) -> Kind {
+        match self {
+            Self::External(_) => Kind::External,
+            Self::Golomb(..) => Kind::Golomb,
+            Self::Huffman(..) => Kind::Huffman,
+            Self::Beta(..) => Kind::Beta,
+            Self::Subexp(..) => Kind::Subexp,
+            Self::GolombRice(..) => Kind::GolombRice,
+            Self::Gamma(_) => Kind::Gamma,
+        }
+    }
+
+    pub fn block_content_ids(&self) -> Vec<block::ContentId> {
+        match self {
+            Self::External(block_content_id) => vec![*block_content_id],
+            _ => Vec::new(),
+        }
+    }
+}
+
 impl Decode for Integer {
     type Value = i32;
 
@@ -1,7 +1,10 @@
+//! CRAM data container reference sequence context.
+
 use std::cmp;
 
 use noodles_core::Position;
 
+/// A reference sequence context for a single reference sequence.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub struct Context {
     reference_sequence_id: usize,
@@ -22,33 +25,43 @@ impl Context {
         }
     }
 
+    /// Returns the reference sequence ID.
     pub fn reference_sequence_id(&self) -> usize {
         self.reference_sequence_id
     }
 
+    /// Returns the alignment start.
     pub fn alignment_start(&self) -> Position {
         self.alignment_start
     }
 
+    /// Returns the alignment span.
     pub fn alignment_span(&self) -> usize {
         usize::from(self.alignment_end) - usize::from(self.alignment_start) + 1
     }
 
+    /// Returns the alignment end.
     pub fn alignment_end(&self) -> Position {
         self.alignment_end
     }
 }
 
+/// A CRAM reference sequence context.
+///
+/// This describes the reference sequence(s) a container or slice is associated with.
 #[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
 pub enum ReferenceSequenceContext {
+    /// Records are placed on a single reference sequence.
     Some(Context),
+    /// Records are unplaced.
     #[default]
     None,
+    /// Records are placed on multiple reference sequences.
     Many,
 }
 
 impl ReferenceSequenceContext {
-    pub fn some(
+    pub(crate) fn some(
         reference_sequence_id: usize,
         alignment_start: Position,
         alignment_end: Position,
@@ -60,11 +73,12 @@ impl ReferenceSequenceContext {
         ))
     }
 
+    /// Returns whether the context is associated with multiple reference sequences.
     pub fn is_many(&self) -> bool {
         matches!(self, Self::Many)
     }
 
-    pub fn update(
+    pub(crate) fn update(
         &mut self,
         reference_sequence_id: Option<usize>,
         alignment_start: Option<Position>,
@@ -10,10 +10,13 @@ use noodles_core::Position;
 use noodles_fasta as fasta;
 use noodles_sam as sam;
 
-use super::{CompressionHeader, ReferenceSequenceContext};
+use super::{
+    compression_header::{data_series_encoding_map::DataSeries, encoding::codec::Byte},
+    CompressionHeader, ReferenceSequenceContext,
+};
 use crate::{
-    container::Block,
-    io::BitReader,
+    container::{block, Block},
+    io::{reader::Options, BitReader},
     record::{resolve, Features},
     Record,
 };
@@ -46,11 +49,36 @@ impl Slice {
         &self.header
     }
 
-    pub(crate) fn core_data_block(&self) -> &Block {
+    /// Returns the number of records in the slice.
+    ///
+    /// This is read from the slice header and does not decode any records.
+    pub fn record_count(&self) -> usize {
+        self.header.record_count()
+    }
+
+    /// Returns the reference sequence context.
+    pub fn reference_sequence_context(&self) -> ReferenceSequenceContext {
+        self.header.reference_sequence_context()
+    }
+
+    /// Returns the alignment span of the slice.
+    ///
+    /// This is read from the slice header and does not decode any records. It is only set when the
+    /// slice is placed on a single reference sequence.
+    pub fn alignment_span(&self) -> Option<usize> {
+        match self.header.reference_sequence_context() {
+            ReferenceSequenceContext::Some(context) => Some(context.alignment_span()),
+            ReferenceSequenceContext::None | ReferenceSequenceContext::Many => None,
+        }
+    }
+
+    /// Returns the core data block.
+    pub fn core_data_block(&self) -> &Block {
         &self.core_data_block
     }
 
-    pub(crate) fn external_blocks(&self) -> &[Block] {
+    /// Returns the external blocks.
+    pub fn external_blocks(&self) -> &[Block] {
         &self.external_blocks
     }
 
@@ -75,6 +103,14 @@ impl Slice {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn records(&self, compression_header: &CompressionHeader) -> io::Result<Vec<Record>> {
+        self.records_with_options(compression_header, &Options::default())
+    }
+
+    pub(crate) fn records_with_options(
+        &self,
+        compression_header: &CompressionHeader,
+        options: &Options,
+    ) -> io::Result<Vec<Record>> {
         use crate::io::reader::record::ExternalDataReaders;
 
         let core_data_reader = self
@@ -82,9 +118,19 @@ impl Slice {
             .decompressed_data()
             .map(BitReader::new)?;
 
+        let skipped_block_content_id = if options.decode_quality_scores {
+            None
+        } else {
+            quality_scores_block_content_id(compression_header)
+        };
+
         let mut external_data_readers = ExternalDataReaders::new();
 
         for block in self.external_blocks() {
+            if Some(block.content_id()) == skipped_block_content_id {
+                continue;
+            }
+
             let reader = block.decompressed_data()?;
             external_data_readers.insert(block.content_id(), reader);
         }
@@ -96,6 +142,9 @@ impl Slice {
             self.header.reference_sequence_context(),
         );
 
+        record_reader.set_decode_quality_scores(options.decode_quality_scores);
+        record_reader.set_tag_filter(options.tag_filter.as_ref());
+
         let record_count = self.header().record_count();
 
         let mut records = vec![Record::default(); record_count];
@@ -121,6 +170,23 @@ impl Slice {
         header: &sam::Header,
         compression_header: &CompressionHeader,
         records: &mut [Record],
+    ) -> io::Result<()> {
+        self.resolve_records_with_options(
+            reference_sequence_repository,
+            header,
+            compression_header,
+            records,
+            &Options::default(),
+        )
+    }
+
+    pub(crate) fn resolve_records_with_options(
+        &self,
+        reference_sequence_repository: &fasta::Repository,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        records: &mut [Record],
+        options: &Options,
     ) -> io::Result<()> {
         resolve_mates(records)?;
 
@@ -132,12 +198,52 @@ impl Slice {
             records,
         )?;
 
-        resolve_quality_scores(records);
+        if options.decode_quality_scores {
+            self::resolve_quality_scores(records);
+        }
 
         Ok(())
     }
 }
 
+// Returns the content ID of the external block that only holds quality scores.
+//
+// This is the block of the quality scores data series when it is not shared with any other data
+// series or tag.
+fn quality_scores_block_content_id(
+    compression_header: &CompressionHeader,
+) -> Option<block::ContentId> {
+    let data_series_encoding_map = compression_header.data_series_encoding_map();
+
+    let Byte::External(block_content_id) =
+        data_series_encoding_map.quality_scores_encoding()?.get()
+    else {
+        return None;
+    };
+
+    let is_shared = data_series_encoding_map
+        .block_content_ids()
+        .into_iter()
+        .any(|(data_series, id)| {
+            data_series != DataSeries::QualityScores && id == *block_content_id
+        })
+        || compression_header
+            .tag_encoding_map()
+            .values()
+            .any(|encoding| {
+                encoding
+                    .get()
+                    .block_content_ids()
+                    .contains(block_content_id)
+            });
+
+    if is_shared {
+        None
+    } else {
+        Some(*block_content_id)
+    }
+}
+
 fn resolve_mates(records: &mut [Record]) -> io::Result<()> {
     let mut mate_indices: Vec<_> = records
         .iter()
@@ -311,12 +417,17 @@ fn resolve_bases(
                 .reference_sequences()
                 .get_index(context.reference_sequence_id())
                 .map(|(name, _)| name)
-                .expect("invalid slice reference sequence ID");
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        "invalid slice reference sequence ID",
+                    )
+                })?;
 
             let sequence = reference_sequence_repository
                 .get(reference_sequence_name)
                 .transpose()?
-                .expect("invalid slice reference sequence name");
+                .ok_or_else(|| missing_reference_sequence_error(reference_sequence_name))?;
 
             // § 11 "Reference sequences" (2021-11-15): "All CRAM reader implementations are
             // expected to check for reference MD5 checksums and report any missing or
@@ -383,12 +494,14 @@ fn resolve_bases(
                     .reference_sequence(header.reference_sequences())
                     .transpose()?
                     .map(|(name, _)| name)
-                    .expect("invalid reference sequence ID");
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+                    })?;
 
                 let sequence = reference_sequence_repository
                     .get(reference_sequence_name)
                     .transpose()?
-                    .expect("invalid reference sequence name");
+                    .ok_or_else(|| missing_reference_sequence_error(reference_sequence_name))?;
 
                 Some(sequence)
             }
@@ -416,6 +529,18 @@ fn resolve_bases(
     Ok(())
 }
 
+fn missing_reference_sequence_error(reference_sequence_name: &[u8]) -> io::Error {
+    use bstr::BStr;
+
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!(
+            "missing reference sequence in repository: {}",
+            BStr::new(reference_sequence_name)
+        ),
+    )
+}
+
 fn resolve_quality_scores(records: &mut [Record]) {
     for record in records {
         if !record.flags().is_unmapped()
@@ -437,6 +562,87 @@ mod tests {
     use super::*;
     use crate::record::Flags;
 
+    #[test]
+    fn test_records_with_options_does_not_decompress_quality_scores_block(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use bytes::Bytes;
+        use sam::alignment::{
+            io::Write,
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        use crate::{
+            container::block::{CompressionMethod, ContentType},
+            io::{writer, Reader},
+        };
+
+        let header = sam::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+            .build();
+
+        let mut writer = writer::Builder::default().build_with_writer(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let container = reader
+            .read_data_container()?
+            .ok_or("missing data container")?;
+
+        let compression_header = container.compression_header();
+        let slice = &container.slices()[0];
+
+        let block_content_id = quality_scores_block_content_id(compression_header)
+            .ok_or("missing quality scores block")?;
+
+        // Replace the quality scores block with one that fails to decompress.
+        let external_blocks = slice
+            .external_blocks()
+            .iter()
+            .map(|block| {
+                if block.content_id() == block_content_id {
+                    Block::builder()
+                        .set_compression_method(CompressionMethod::Gzip)
+                        .set_content_type(ContentType::ExternalData)
+                        .set_content_id(block_content_id)
+                        .set_uncompressed_len(4)
+                        .set_data(Bytes::from_static(b"noodles"))
+                        .build()
+                } else {
+                    block.clone()
+                }
+            })
+            .collect();
+
+        let slice = Slice::new(
+            slice.header().clone(),
+            slice.core_data_block().clone(),
+            external_blocks,
+        );
+
+        assert!(slice
+            .records_with_options(compression_header, &Options::default())
+            .is_err());
+
+        let options = Options {
+            decode_quality_scores: false,
+            ..Default::default()
+        };
+
+        let records = slice.records_with_options(compression_header, &options)?;
+        assert_eq!(records.len(), 1);
+        assert!(records[0].quality_scores().is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_mates() -> Result<(), Box<dyn std::error::Error>> {
         let mut records = vec![
@@ -577,6 +783,46 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_record_count_and_alignment_span() -> Result<(), noodles_core::position::TryFromIntError>
+    {
+        use crate::container::block::ContentType;
+
+        let core_data_block = Block::builder()
+            .set_content_type(ContentType::CoreData)
+            .build();
+
+        let slice = Slice::new(
+            Header::builder()
+                .set_reference_sequence_context(ReferenceSequenceContext::some(
+                    0,
+                    Position::try_from(8)?,
+                    Position::try_from(13)?,
+                ))
+                .set_record_count(5)
+                .build(),
+            core_data_block.clone(),
+            Vec::new(),
+        );
+
+        assert_eq!(slice.record_count(), 5);
+        assert_eq!(slice.alignment_span(), Some(6));
+
+        let slice = Slice::new(
+            Header::builder()
+                .set_reference_sequence_context(ReferenceSequenceContext::Many)
+                .set_record_count(3)
+                .build(),
+            core_data_block,
+            Vec::new(),
+        );
+
+        assert_eq!(slice.record_count(), 3);
+        assert!(slice.alignment_span().is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_resolve_bases() -> Result<(), Box<dyn std::error::Error>> {
         use std::num::NonZeroUsize;
@@ -21,7 +21,6 @@ use crate::{
 use super::{Header, Slice};
 
 const CORE_DATA_BLOCK_CONTENT_ID: i32 = 0;
-const MAX_RECORD_COUNT: usize = 10240;
 
 #[derive(Debug, Default)]
 pub struct Builder {
@@ -48,8 +47,12 @@ impl Builder {
     }
 
     #[allow(clippy::result_large_err)]
-    pub fn add_record(&mut self, record: Record) -> Result<&Record, AddRecordError> {
-        if self.records.len() >= MAX_RECORD_COUNT {
+    pub fn add_record(
+        &mut self,
+        max_record_count: usize,
+        record: Record,
+    ) -> Result<&Record, AddRecordError> {
+        if self.records.len() >= max_record_count {
             return Err(AddRecordError::SliceFull(record));
         }
 
@@ -302,6 +305,55 @@ pub(crate) fn calculate_normalized_sequence_digest(sequence: &[u8]) -> [u8; 16]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_add_record_updates_reference_sequence_context(
+    ) -> Result<(), noodles_core::position::TryFromIntError> {
+        use noodles_core::Position;
+
+        let mut builder = Builder::default();
+
+        // 8-13
+        let record = Record {
+            reference_sequence_id: Some(0),
+            alignment_start: Some(Position::try_from(8)?),
+            read_length: 6,
+            ..Default::default()
+        };
+
+        builder.add_record(usize::MAX, record).unwrap();
+
+        // 5-21
+        let record = Record {
+            reference_sequence_id: Some(0),
+            alignment_start: Some(Position::try_from(5)?),
+            read_length: 17,
+            ..Default::default()
+        };
+
+        builder.add_record(usize::MAX, record).unwrap();
+
+        let ReferenceSequenceContext::Some(context) = builder.reference_sequence_context() else {
+            panic!("expected a single reference sequence context");
+        };
+
+        assert_eq!(context.reference_sequence_id(), 0);
+        assert_eq!(context.alignment_start(), Position::try_from(5)?);
+        assert_eq!(context.alignment_end(), Position::try_from(21)?);
+        assert_eq!(context.alignment_span(), 17);
+
+        let record = Record {
+            reference_sequence_id: Some(1),
+            alignment_start: Some(Position::try_from(1)?),
+            read_length: 4,
+            ..Default::default()
+        };
+
+        builder.add_record(usize::MAX, record).unwrap();
+        assert!(builder.reference_sequence_context().is_many());
+
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_normalized_sequence_digest() {
         assert_eq!(
@@ -97,4 +97,12 @@ where
     ) -> io::Result<Query<'a, R>> {
         self.inner.query(header, &self.index, region)
     }
+
+    /// Returns an iterator of unmapped records after querying for the unmapped region.
+    pub fn query_unmapped<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+    ) -> io::Result<impl Iterator<Item = io::Result<crate::Record>> + 'a> {
+        self.inner.query_unmapped(header, &self.index)
+    }
 }
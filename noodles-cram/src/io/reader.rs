@@ -2,14 +2,20 @@
 
 mod builder;
 pub(crate) mod container;
+mod containers;
 pub(crate) mod data_container;
 pub(crate) mod header_container;
 pub(crate) mod num;
+mod options;
 mod query;
 pub(crate) mod record;
 mod records;
+mod tag_filter;
 
-pub use self::{builder::Builder, query::Query, records::Records};
+pub(crate) use self::options::Options;
+pub use self::{
+    builder::Builder, containers::Containers, query::Query, records::Records, tag_filter::TagFilter,
+};
 
 use std::io::{self, Read, Seek, SeekFrom};
 
@@ -19,7 +25,11 @@ use noodles_fasta as fasta;
 use noodles_sam as sam;
 
 use crate::{
-    crai, data_container::DataContainer, file_definition::Version, FileDefinition, MAGIC_NUMBER,
+    container::Container,
+    crai,
+    data_container::{CompressionHeader, DataContainer, Slice},
+    file_definition::Version,
+    FileDefinition, Record, MAGIC_NUMBER,
 };
 
 /// A CRAM reader.
@@ -47,6 +57,9 @@ use crate::{
 pub struct Reader<R> {
     inner: R,
     reference_sequence_repository: fasta::Repository,
+    require_eof: bool,
+    is_eof: bool,
+    options: Options,
     buf: BytesMut,
 }
 
@@ -114,6 +127,25 @@ where
         &self.reference_sequence_repository
     }
 
+    pub(crate) fn read_slice_records(
+        &self,
+        header: &sam::Header,
+        compression_header: &CompressionHeader,
+        slice: &Slice,
+    ) -> io::Result<Vec<Record>> {
+        let mut records = slice.records_with_options(compression_header, &self.options)?;
+
+        slice.resolve_records_with_options(
+            &self.reference_sequence_repository,
+            header,
+            compression_header,
+            &mut records,
+            &self.options,
+        )?;
+
+        Ok(records)
+    }
+
     /// Reads the CRAM file definition.
     ///
     /// The CRAM magic number is also checked.
@@ -183,15 +215,30 @@ where
 
     pub(crate) fn read_data_container_with_container_header(
         &mut self,
-    ) -> io::Result<Option<(crate::data_container::Header, DataContainer)>> {
+    ) -> io::Result<Option<(crate::container::Header, DataContainer)>> {
         use self::data_container::read_data_container_with_container_header;
-        read_data_container_with_container_header(&mut self.inner, &mut self.buf)
+
+        if self.is_eof {
+            return Ok(None);
+        }
+
+        let result = read_data_container_with_container_header(
+            &mut self.inner,
+            &mut self.buf,
+            self.require_eof,
+        );
+
+        self.check_eof(result)
     }
 
     /// Reads a data container.
     ///
     /// This returns `None` if the container header is the EOF container header, which signals the
-    /// end of the stream.
+    /// end of the stream. If the stream ends without an EOF container, this returns an error,
+    /// unless the reader was built with [`Builder::require_eof`] disabled.
+    ///
+    /// After an unexpected EOF error, the stream is considered to be at its end, and subsequent
+    /// reads return `None`.
     ///
     /// # Examples
     ///
@@ -210,7 +257,81 @@ where
     pub fn read_data_container(&mut self) -> io::Result<Option<DataContainer>> {
         use self::data_container::read_data_container;
 
-        read_data_container(&mut self.inner, &mut self.buf)
+        if self.is_eof {
+            return Ok(None);
+        }
+
+        let result = read_data_container(&mut self.inner, &mut self.buf, self.require_eof);
+        self.check_eof(result)
+    }
+
+    /// Reads a container.
+    ///
+    /// Unlike [`Self::read_data_container`], this does not decode the blocks in the container.
+    ///
+    /// This returns `None` if the container header is the EOF container header, which signals the
+    /// end of the stream. The stream ending without an EOF container is handled the same as in
+    /// [`Self::read_data_container`].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// while let Some(container) = reader.read_container()? {
+    ///     // ...
+    /// }
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn read_container(&mut self) -> io::Result<Option<Container>> {
+        use self::container::read_container;
+
+        if self.is_eof {
+            return Ok(None);
+        }
+
+        let result = read_container(&mut self.inner, &mut self.buf, self.require_eof);
+        self.check_eof(result)
+    }
+
+    // Marks the stream as ended after an unexpected EOF error so that the error is only reported
+    // once.
+    fn check_eof<T>(&mut self, result: io::Result<Option<T>>) -> io::Result<Option<T>> {
+        if let Err(e) = &result {
+            if e.kind() == io::ErrorKind::UnexpectedEof {
+                self.is_eof = true;
+            }
+        }
+
+        result
+    }
+
+    /// Returns an iterator over containers starting from the current stream position.
+    ///
+    /// The stream is expected to be at the start of a data container. The iterator stops at the
+    /// EOF container.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram as cram;
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    /// reader.read_header()?;
+    ///
+    /// for result in reader.containers() {
+    ///     let container = result?;
+    ///     println!("{}", container.header().record_count());
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn containers(&mut self) -> Containers<'_, R> {
+        Containers::new(self)
     }
 
     /// Returns a iterator over records starting from the current stream position.
@@ -258,6 +379,7 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.is_eof = false;
         self.inner.seek(pos)
     }
 
@@ -324,6 +446,56 @@ where
             region.interval(),
         ))
     }
+
+    /// Returns an iterator of unmapped records after querying for the unmapped region.
+    ///
+    /// This seeks to the first container in the index that holds unplaced records, i.e., index
+    /// records without a reference sequence ID, and reads the records without a reference
+    /// sequence ID from there to the end of the stream. Placed unmapped records are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use std::{fs::File, io};
+    /// use noodles_cram::{self as cram, crai};
+    ///
+    /// let mut reader = File::open("sample.cram").map(cram::io::Reader::new)?;
+    ///
+    /// let header = reader.read_header()?;
+    /// let index = crai::read("sample.cram.crai")?;
+    /// let query = reader.query_unmapped(&header, &index)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn query_unmapped<'a>(
+        &'a mut self,
+        header: &'a sam::Header,
+        index: &crai::Index,
+    ) -> io::Result<impl Iterator<Item = io::Result<crate::Record>> + 'a> {
+        let offset = index
+            .iter()
+            .find(|record| record.reference_sequence_id().is_none())
+            .map(|record| record.offset());
+
+        let records = match offset {
+            Some(pos) => {
+                self.seek(SeekFrom::Start(pos))?;
+                Some(self.records(header))
+            }
+            None => None,
+        };
+
+        Ok(records.into_iter().flatten().filter(|result| {
+            result
+                .as_ref()
+                .map(|record| record.reference_sequence_id().is_none())
+                .unwrap_or(true)
+        }))
+    }
 }
 
 impl<R> sam::alignment::io::Read<R> for Reader<R>
@@ -409,6 +581,435 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_containers() -> io::Result<()> {
+        use crate::io::Writer;
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        for _ in 0..3 {
+            writer.write_record(&header, crate::Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+
+        let containers: Vec<_> = reader.containers().collect::<io::Result<_>>()?;
+        assert_eq!(containers.len(), 1);
+
+        let container = &containers[0];
+        assert_eq!(container.header().record_count(), 3);
+        assert_eq!(container.blocks().len(), container.header().block_count());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_missing_eof_container() -> io::Result<()> {
+        use crate::io::{writer::container::EOF, Writer};
+
+        let header = sam::Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, crate::Record::default())?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        assert_eq!(reader.records(&header).count(), 1);
+
+        let data = &data[..data.len() - EOF.len()];
+
+        let mut reader = Reader::new(data);
+        reader.read_header()?;
+        let results: Vec<_> = reader.records(&header).collect();
+        assert!(matches!(
+            &results[..],
+            [Ok(_), Err(e)] if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+        assert!(reader.read_data_container()?.is_none());
+
+        let mut reader = Reader::new(data);
+        reader.read_header()?;
+        let results: Vec<_> = reader.containers().collect();
+        assert!(matches!(
+            &results[..],
+            [Ok(_), Err(e)] if e.kind() == io::ErrorKind::UnexpectedEof
+        ));
+        assert!(reader.read_container()?.is_none());
+
+        let mut reader = Builder::default()
+            .require_eof(false)
+            .build_from_reader(data);
+        reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(records.len(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_without_decoding_quality_scores() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::writer;
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect::<Cigar>())
+                .set_sequence(Sequence::from(b"TTGA"))
+                .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+                .build(),
+        ];
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+        let mut expected: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(expected.len(), 2);
+        assert!(expected
+            .iter()
+            .all(|record| !record.quality_scores().is_empty()));
+
+        for record in &mut expected {
+            record.quality_scores = QualityScores::default();
+        }
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .decode_quality_scores(false)
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_reference_sequence_repository_adapter(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::{collections::HashMap, num::NonZeroUsize};
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::writer;
+
+        struct InMemoryAdapter(HashMap<Vec<u8>, fasta::record::Sequence>);
+
+        impl fasta::repository::Adapter for InMemoryAdapter {
+            fn get(&mut self, name: &[u8]) -> Option<io::Result<fasta::Record>> {
+                self.0.get(name).map(|sequence| {
+                    let definition = fasta::record::Definition::new(name, None);
+                    Ok(fasta::Record::new(definition, sequence.clone()))
+                })
+            }
+        }
+
+        let repository = || {
+            fasta::Repository::new(InMemoryAdapter(
+                [(
+                    b"sq0".to_vec(),
+                    fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+                )]
+                .into_iter()
+                .collect(),
+            ))
+        };
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_flags(Flags::empty())
+            .set_reference_sequence_id(0)
+            .set_alignment_start(Position::MIN)
+            .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect::<Cigar>())
+            .set_sequence(Sequence::from(b"TTGA"))
+            .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+            .build();
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository())
+            .build_from_reader(&data[..]);
+        reader.read_header()?;
+        let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].sequence(), &Sequence::from(b"TTGA"));
+
+        let mut reader = Reader::new(&data[..]);
+        reader.read_header()?;
+        assert!(matches!(
+            reader.records(&header).next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_records_with_tag_filter() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write,
+            record::data::field::Tag,
+            record_buf::{data::field::Value, QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        let header = sam::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_name("r0")
+            .set_sequence(Sequence::from(b"ACGT"))
+            .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+            .set_data(
+                [
+                    (Tag::EDIT_DISTANCE, Value::from(1)),
+                    (Tag::ALIGNMENT_HIT_COUNT, Value::from(2)),
+                    (Tag::ALIGNMENT_SCORE, Value::from(34)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .build();
+
+        let mut writer = crate::io::Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+        writer.write_alignment_record(&header, &record)?;
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let read_tags = |tag_filter| -> io::Result<Vec<Tag>> {
+            let mut reader = Builder::default()
+                .set_tag_filter(tag_filter)
+                .build_from_reader(&data[..]);
+
+            reader.read_header()?;
+
+            let records: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+            assert_eq!(records.len(), 1);
+
+            let record = &records[0];
+            assert_eq!(record.name(), Some(b"r0".as_ref().into()));
+            assert_eq!(record.bases().as_ref(), b"ACGT");
+
+            Ok(record.data().keys().collect())
+        };
+
+        let tags = [Tag::EDIT_DISTANCE].into_iter().collect();
+        assert_eq!(read_tags(TagFilter::Keep(tags))?, [Tag::EDIT_DISTANCE]);
+
+        let tags = [Tag::EDIT_DISTANCE].into_iter().collect();
+        assert_eq!(
+            read_tags(TagFilter::Drop(tags))?,
+            [Tag::ALIGNMENT_HIT_COUNT, Tag::ALIGNMENT_SCORE]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{cigar::op::Kind, Flags},
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::io::writer;
+
+        const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+            Some(length) => length,
+            None => unreachable!(),
+        };
+
+        const RECORDS_PER_SLICE: NonZeroUsize = match NonZeroUsize::new(1) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        let repository = fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+        )]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_name("r0")
+                .set_flags(Flags::empty())
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_cigar(Cigar::from(vec![sam::alignment::record::cigar::Op::new(
+                    Kind::Match,
+                    4,
+                )]))
+                .set_sequence(Sequence::from(b"TTCA"))
+                .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                .build(),
+            // placed unmapped
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::MIN)
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+                .build(),
+            RecordBuf::builder()
+                .set_name("r1")
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+                .build(),
+        ];
+
+        let mut writer = writer::Builder::default()
+            .set_reference_sequence_repository(repository.clone())
+            .set_records_per_slice(RECORDS_PER_SLICE)
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let data = writer.get_ref();
+
+        let mut reader = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .build_from_reader(io::Cursor::new(&data[..]));
+        reader.read_header()?;
+
+        let mapped_offset = reader.position()?;
+        reader.read_data_container()?;
+        let unmapped_offset = reader.position()?;
+
+        let index = vec![
+            crai::Record::new(Some(0), Some(Position::MIN), 4, mapped_offset, 0, 0),
+            crai::Record::new(None, None, 0, unmapped_offset, 0, 0),
+        ];
+
+        let actual: Vec<_> = reader
+            .query_unmapped(&header, &index)?
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(actual.len(), 1);
+        assert_eq!(actual[0].name(), Some(b"r1".as_ref().into()));
+        assert!(actual[0].flags().is_unmapped());
+
+        let actual: Vec<_> = reader
+            .query_unmapped(&header, &index[..1].to_vec())?
+            .collect::<io::Result<_>>()?;
+
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_magic_number() {
         let data = b"CRAM";
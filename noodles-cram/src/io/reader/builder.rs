@@ -7,12 +7,14 @@ use std::{
 use bytes::BytesMut;
 use noodles_fasta as fasta;
 
-use super::Reader;
+use super::{Options, Reader, TagFilter};
 
 /// A CRAM reader builder.
-#[derive(Debug, Default)]
+#[derive(Debug)]
 pub struct Builder {
     reference_sequence_repository: fasta::Repository,
+    require_eof: bool,
+    options: Options,
 }
 
 impl Builder {
@@ -37,6 +39,59 @@ impl Builder {
         self
     }
 
+    /// Sets whether the stream is required to end with an EOF container.
+    ///
+    /// When enabled, reaching the end of the stream without reading an EOF container is an
+    /// error. This typically indicates a truncated file. By default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::Builder;
+    /// let builder = Builder::default().require_eof(false);
+    /// ```
+    pub fn require_eof(mut self, value: bool) -> Self {
+        self.require_eof = value;
+        self
+    }
+
+    /// Sets whether quality scores are decoded.
+    ///
+    /// When disabled, the quality scores data series is skipped, and records are read with empty
+    /// quality scores. All other fields are read as usual. This is useful when quality scores are
+    /// not needed, e.g., when calculating coverage. By default, this is enabled.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::Builder;
+    /// let builder = Builder::default().decode_quality_scores(false);
+    /// ```
+    pub fn decode_quality_scores(mut self, value: bool) -> Self {
+        self.options.decode_quality_scores = value;
+        self
+    }
+
+    /// Sets a filter of the data fields (tags) to decode.
+    ///
+    /// Fields are either kept or dropped by tag, so a filter cannot both keep and drop tags. Fields
+    /// that are filtered out are skipped when decoding records. By default, all fields are
+    /// decoded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::reader::{Builder, TagFilter};
+    /// use noodles_sam::alignment::record::data::field::Tag;
+    ///
+    /// let tag_filter = TagFilter::Keep([Tag::EDIT_DISTANCE].into_iter().collect());
+    /// let builder = Builder::default().set_tag_filter(tag_filter);
+    /// ```
+    pub fn set_tag_filter(mut self, tag_filter: TagFilter) -> Self {
+        self.options.tag_filter = Some(tag_filter);
+        self
+    }
+
     /// Builds a CRAM reader from a path.
     ///
     /// # Examples
@@ -70,7 +125,20 @@ impl Builder {
         Reader {
             inner: reader,
             reference_sequence_repository: self.reference_sequence_repository,
+            require_eof: self.require_eof,
+            is_eof: false,
+            options: self.options,
             buf: BytesMut::new(),
         }
     }
 }
+
+impl Default for Builder {
+    fn default() -> Self {
+        Self {
+            reference_sequence_repository: fasta::Repository::default(),
+            require_eof: true,
+            options: Options::default(),
+        }
+    }
+}
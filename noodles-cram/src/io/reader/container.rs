@@ -1,3 +1,33 @@
 mod block;
 
 pub use self::block::read_block;
+
+use std::io::{self, Read};
+
+use bytes::BytesMut;
+
+use super::data_container::header::read_header;
+use crate::container::Container;
+
+pub fn read_container<R>(
+    reader: &mut R,
+    buf: &mut BytesMut,
+    require_eof: bool,
+) -> io::Result<Option<Container>>
+where
+    R: Read,
+{
+    let Some(header) = read_header(reader, require_eof)? else {
+        return Ok(None);
+    };
+
+    buf.resize(header.len(), 0);
+    reader.read_exact(buf)?;
+    let mut buf = buf.split().freeze();
+
+    let blocks = (0..header.block_count())
+        .map(|_| read_block(&mut buf))
+        .collect::<io::Result<_>>()?;
+
+    Ok(Some(Container::new(header, blocks)))
+}
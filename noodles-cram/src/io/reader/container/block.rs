@@ -4,7 +4,7 @@ use bytes::{Buf, Bytes};
 
 use crate::{
     container::{
-        block::{CompressionMethod, ContentId, ContentType},
+        block::{ChecksumMismatchError, CompressionMethod, ContentId, ContentType},
         Block,
     },
     io::reader::num::get_itf8,
@@ -41,12 +41,13 @@ pub fn read_block(src: &mut Bytes) -> io::Result<Block> {
     let expected_crc32 = src.get_u32_le();
 
     if actual_crc32 != expected_crc32 {
-        return Err(io::Error::new(
-            io::ErrorKind::InvalidData,
-            format!(
-                "container block checksum mismatch: expected {expected_crc32:08x}, got {actual_crc32:08x}"
-            ),
-        ));
+        return Err(ChecksumMismatchError::new(
+            block_content_type,
+            block_content_id,
+            expected_crc32,
+            actual_crc32,
+        )
+        .into());
     }
 
     let mut builder = Block::builder()
@@ -150,6 +151,32 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_read_block_with_checksum_mismatch() {
+        let mut data = Bytes::from_static(&[
+            0x00, // compression method = none (0)
+            0x04, // content type = external data (4)
+            0x01, // block content ID = 1
+            0x04, // size in bytes = 4 bytes
+            0x04, // raw size in bytes = 4 bytes
+            0x6e, 0x64, 0x6c, 0x74, // data = b"ndlt" (corrupted from b"ndls"),
+            0xd7, 0x12, 0x46, 0x3e, // CRC32 = 3e4612d7
+        ]);
+
+        let e = read_block(&mut data).unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let source = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<ChecksumMismatchError>())
+            .expect("expected checksum mismatch error");
+
+        assert_eq!(source.content_type(), ContentType::ExternalData);
+        assert_eq!(source.content_id(), ContentId::from(1));
+        assert_eq!(source.expected(), 0x3e4612d7);
+        assert_ne!(source.actual(), source.expected());
+    }
+
     #[test]
     fn test_read_block_with_empty_block() -> io::Result<()> {
         let mut data = Bytes::from_static(&[
@@ -0,0 +1,31 @@
+use std::io::{self, Read};
+
+use super::Reader;
+use crate::container::Container;
+
+/// An iterator over containers of a CRAM reader.
+///
+/// This is created by calling [`Reader::containers`].
+pub struct Containers<'a, R> {
+    reader: &'a mut Reader<R>,
+}
+
+impl<'a, R> Containers<'a, R>
+where
+    R: Read,
+{
+    pub(crate) fn new(reader: &'a mut Reader<R>) -> Self {
+        Self { reader }
+    }
+}
+
+impl<'a, R> Iterator for Containers<'a, R>
+where
+    R: Read,
+{
+    type Item = io::Result<Container>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.reader.read_container().transpose()
+    }
+}
@@ -14,11 +14,12 @@ use crate::{container::block::ContentType, data_container::CompressionHeader, Da
 pub fn read_data_container<R>(
     reader: &mut R,
     buf: &mut BytesMut,
+    require_eof: bool,
 ) -> io::Result<Option<DataContainer>>
 where
     R: Read,
 {
-    let Some(header) = read_header(reader)? else {
+    let Some(header) = read_header(reader, require_eof)? else {
         return Ok(None);
     };
 
@@ -42,11 +43,12 @@ where
 pub fn read_data_container_with_container_header<R>(
     reader: &mut R,
     buf: &mut BytesMut,
-) -> io::Result<Option<(crate::data_container::Header, DataContainer)>>
+    require_eof: bool,
+) -> io::Result<Option<(crate::container::Header, DataContainer)>>
 where
     R: Read,
 {
-    let Some(header) = read_header(reader)? else {
+    let Some(header) = read_header(reader, require_eof)? else {
         return Ok(None);
     };
 
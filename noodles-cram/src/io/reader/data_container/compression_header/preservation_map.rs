@@ -128,13 +128,29 @@ fn get_tag_ids_dictionary(src: &mut Bytes) -> io::Result<TagIdsDictionary> {
 
     let mut dictionary = Vec::new();
 
-    while let Some(i) = buf.iter().position(|&b| b == NUL) {
+    while !buf.is_empty() {
+        let i = buf.iter().position(|&b| b == NUL).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                "unterminated tag IDs dictionary line",
+            )
+        })?;
+
         let keys_buf = buf.split_to(i);
         buf.advance(1); // Discard the NUL terminator.
 
-        let mut line = Vec::new();
+        let chunks = keys_buf.chunks_exact(3);
+
+        if !chunks.remainder().is_empty() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "invalid tag IDs dictionary line length",
+            ));
+        }
+
+        let mut line = Vec::with_capacity(chunks.len());
 
-        for chunk in keys_buf.chunks_exact(3) {
+        for chunk in chunks {
             let (t0, t1, ty) = (chunk[0], chunk[1], chunk[2]);
 
             let tag = Tag::new(t0, t1);
@@ -238,6 +254,54 @@ mod tests {
         ));
     }
 
+    #[test]
+    fn test_get_tag_ids_dictionary() -> io::Result<()> {
+        let mut data = Bytes::from_static(&[
+            0x0b, // data.len = 11
+            0x4e, 0x4d, 0x43, 0x4d, 0x44, 0x5a, 0x00, // [NM:C, MD:Z]
+            0x5a, 0x42, 0x42, // ZB:B
+            0x00, // NUL
+        ]);
+
+        let actual = get_tag_ids_dictionary(&mut data)?;
+
+        let expected = TagIdsDictionary::from(vec![
+            vec![
+                tag_ids_dictionary::Key::new(Tag::EDIT_DISTANCE, Type::UInt8),
+                tag_ids_dictionary::Key::new(Tag::MISMATCHED_POSITIONS, Type::String),
+            ],
+            vec![tag_ids_dictionary::Key::new(
+                Tag::new(b'Z', b'B'),
+                Type::Array,
+            )],
+        ]);
+
+        assert_eq!(actual, expected);
+
+        let mut data = Bytes::from_static(&[
+            0x04, // data.len = 4
+            0x4e, 0x4d, 0x43, 0x4d, // NM:C, M
+        ]);
+
+        assert!(matches!(
+            get_tag_ids_dictionary(&mut data),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData,
+        ));
+
+        let mut data = Bytes::from_static(&[
+            0x05, // data.len = 5
+            0x4e, 0x4d, 0x43, 0x4d, // NM:C, M
+            0x00, // NUL
+        ]);
+
+        assert!(matches!(
+            get_tag_ids_dictionary(&mut data),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData,
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_get_bool() -> io::Result<()> {
         let data = [0x00];
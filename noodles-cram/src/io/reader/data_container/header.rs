@@ -8,7 +8,8 @@ use flate2::CrcReader;
 use noodles_core::Position;
 
 use crate::{
-    data_container::{Header, ReferenceSequenceContext},
+    container::Header,
+    data_container::ReferenceSequenceContext,
     io::reader::num::{read_itf8, read_ltf8},
 };
 
@@ -19,15 +20,22 @@ const EOF_ALIGNMENT_START: i32 = 4_542_278;
 const EOF_BLOCK_COUNT: usize = 1;
 const EOF_CRC32: u32 = 0x4f_d9_bd_05;
 
-pub fn read_header<R>(reader: &mut R) -> io::Result<Option<Header>>
+pub fn read_header<R>(reader: &mut R, require_eof: bool) -> io::Result<Option<Header>>
 where
     R: Read,
 {
     let mut crc_reader = CrcReader::new(reader);
 
-    let length = crc_reader.read_i32::<LittleEndian>().and_then(|n| {
-        usize::try_from(n).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
-    })?;
+    let Some(length) = read_length(&mut crc_reader)? else {
+        if require_eof {
+            return Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "missing EOF container",
+            ));
+        } else {
+            return Ok(None);
+        }
+    };
 
     let reference_sequence_id = read_itf8(&mut crc_reader)?;
     let alignment_start = read_itf8(&mut crc_reader)?;
@@ -89,6 +97,32 @@ where
     Ok(Some(header))
 }
 
+// Returns `None` if the stream is at its end.
+fn read_length<R>(reader: &mut R) -> io::Result<Option<usize>>
+where
+    R: Read,
+{
+    let mut buf = [0; 4];
+
+    let n = loop {
+        match reader.read(&mut buf) {
+            Ok(n) => break n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    };
+
+    if n == 0 {
+        return Ok(None);
+    }
+
+    reader.read_exact(&mut buf[n..])?;
+
+    usize::try_from(i32::from_le_bytes(buf))
+        .map(Some)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+}
+
 fn read_landmarks<R>(reader: &mut R) -> io::Result<Vec<usize>>
 where
     R: Read,
@@ -181,7 +215,7 @@ mod tests {
             0x21, 0xf7, 0x9c, 0xed, // CRC32
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, true)?;
 
         let expected = Header::builder()
             .set_length(144)
@@ -217,13 +251,29 @@ mod tests {
             0x05, 0xbd, 0xd9, 0x4f, // CRC32
         ];
         let mut reader = &data[..];
-        let actual = read_header(&mut reader)?;
+        let actual = read_header(&mut reader, true)?;
 
         assert!(actual.is_none());
 
         Ok(())
     }
 
+    #[test]
+    fn test_read_header_at_end_of_stream() -> io::Result<()> {
+        let data = [];
+
+        let mut reader = &data[..];
+        assert!(matches!(
+            read_header(&mut reader, true),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof,
+        ));
+
+        let mut reader = &data[..];
+        assert!(read_header(&mut reader, false)?.is_none());
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_header_with_a_checksum_mismatch() {
         // EOF container header
@@ -242,7 +292,7 @@ mod tests {
         let mut reader = &data[..];
 
         assert!(matches!(
-            read_header(&mut reader),
+            read_header(&mut reader, true),
             Err(e) if e.kind() == io::ErrorKind::InvalidData,
         ));
     }
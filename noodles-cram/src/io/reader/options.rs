@@ -0,0 +1,16 @@
+use super::TagFilter;
+
+#[derive(Clone, Debug)]
+pub struct Options {
+    pub decode_quality_scores: bool,
+    pub tag_filter: Option<TagFilter>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            decode_quality_scores: true,
+            tag_filter: None,
+        }
+    }
+}
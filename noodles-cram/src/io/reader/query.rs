@@ -76,16 +76,8 @@ where
             .map(|slice| {
                 let compression_header = container.compression_header();
 
-                slice.records(compression_header).and_then(|mut records| {
-                    slice.resolve_records(
-                        self.reader.reference_sequence_repository(),
-                        self.header,
-                        compression_header,
-                        &mut records,
-                    )?;
-
-                    Ok(records)
-                })
+                self.reader
+                    .read_slice_records(self.header, compression_header, slice)
             })
             .collect::<Result<Vec<_>, _>>();
 
@@ -14,11 +14,12 @@ use crate::{
     container::block,
     data_container::{
         compression_header::{
-            data_series_encoding_map::DataSeries, preservation_map::tag_ids_dictionary,
+            data_series_encoding_map::DataSeries, encoding::codec::Byte,
+            preservation_map::tag_ids_dictionary, Encoding,
         },
         CompressionHeader, ReferenceSequenceContext,
     },
-    io::BitReader,
+    io::{reader::TagFilter, BitReader},
     record::{
         feature::{self, substitution},
         Feature, Flags, NextMateFlags,
@@ -26,6 +27,8 @@ use crate::{
     Record,
 };
 
+const MISSING_QUALITY_SCORE: u8 = 0xff;
+
 #[allow(clippy::enum_variant_names)]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ReadRecordError {
@@ -56,6 +59,8 @@ where
     external_data_readers: ExternalDataReaders<EDR>,
     reference_sequence_context: ReferenceSequenceContext,
     prev_alignment_start: Option<Position>,
+    decode_quality_scores: bool,
+    tag_filter: Option<&'a TagFilter>,
 }
 
 impl<'a, CDR, EDR> Reader<'a, CDR, EDR>
@@ -80,9 +85,25 @@ where
             external_data_readers,
             reference_sequence_context,
             prev_alignment_start: initial_alignment_start,
+            decode_quality_scores: true,
+            tag_filter: None,
         }
     }
 
+    /// Sets whether quality scores stored as arrays are decoded.
+    ///
+    /// When disabled, the quality scores of those records are skipped and left empty.
+    pub fn set_decode_quality_scores(&mut self, decode_quality_scores: bool) {
+        self.decode_quality_scores = decode_quality_scores;
+    }
+
+    /// Sets the filter of data fields to decode.
+    ///
+    /// Fields that are filtered out are skipped. By default, all fields are decoded.
+    pub fn set_tag_filter(&mut self, tag_filter: Option<&'a TagFilter>) {
+        self.tag_filter = tag_filter;
+    }
+
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<()> {
         let bam_bit_flags = self.read_bam_bit_flags()?;
         record.bam_bit_flags = bam_bit_flags;
@@ -406,6 +427,16 @@ where
                 )
             })?;
 
+            if let Some(tag_filter) = self.tag_filter {
+                if !tag_filter.matches(&key.tag()) {
+                    encoding
+                        .get()
+                        .skip(&mut self.core_data_reader, &mut self.external_data_readers)?;
+
+                    continue;
+                }
+            }
+
             let data =
                 encoding.decode(&mut self.core_data_reader, &mut self.external_data_readers)?;
 
@@ -608,7 +639,8 @@ where
     }
 
     fn read_quality_score(&mut self) -> io::Result<u8> {
-        self.compression_header
+        let encoding = self
+            .compression_header
             .data_series_encoding_map()
             .quality_scores_encoding()
             .ok_or_else(|| {
@@ -616,8 +648,13 @@ where
                     io::ErrorKind::InvalidData,
                     ReadRecordError::MissingDataSeriesEncoding(DataSeries::QualityScores),
                 )
-            })?
-            .decode(&mut self.core_data_reader, &mut self.external_data_readers)
+            })?;
+
+        if self.is_quality_scores_block_skipped(encoding) {
+            return Ok(MISSING_QUALITY_SCORE);
+        }
+
+        encoding.decode(&mut self.core_data_reader, &mut self.external_data_readers)
     }
 
     fn read_base_substitution_code(&mut self) -> io::Result<substitution::Value> {
@@ -767,8 +804,6 @@ where
         &mut self,
         read_length: usize,
     ) -> io::Result<QualityScores> {
-        const MISSING: u8 = 0xff;
-
         let encoding = self
             .compression_header
             .data_series_encoding_map()
@@ -780,6 +815,20 @@ where
                 )
             })?;
 
+        if self.is_quality_scores_block_skipped(encoding) {
+            return Ok(QualityScores::default());
+        }
+
+        if !self.decode_quality_scores {
+            encoding.get().skip_exact(
+                &mut self.core_data_reader,
+                &mut self.external_data_readers,
+                read_length,
+            )?;
+
+            return Ok(QualityScores::default());
+        }
+
         let mut buf = vec![0; read_length];
 
         encoding.get().decode_exact(
@@ -788,10 +837,90 @@ where
             &mut buf,
         )?;
 
-        if buf.iter().all(|&n| n == MISSING) {
+        if buf.iter().all(|&n| n == MISSING_QUALITY_SCORE) {
             buf.clear();
         }
 
         Ok(QualityScores::from(buf))
     }
+
+    // The external block of the quality scores data series is not decompressed when quality
+    // scores are not decoded (see `Slice::records_with_options`).
+    fn is_quality_scores_block_skipped(&self, encoding: &Encoding<Byte>) -> bool {
+        match encoding.get() {
+            Byte::External(block_content_id) => {
+                !self.decode_quality_scores
+                    && !self.external_data_readers.contains_key(block_content_id)
+            }
+            Byte::Huffman(..) => false,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data_container::compression_header::{
+        encoding::codec::{Byte, Integer},
+        DataSeriesEncodingMap, Encoding,
+    };
+
+    fn build_compression_header(quality_scores_encoding: Encoding<Byte>) -> CompressionHeader {
+        let default_compression_header = CompressionHeader::builder().build();
+
+        let integer_encoding = || Encoding::new(Integer::External(block::ContentId::from(1)));
+
+        let data_series_encoding_map = DataSeriesEncodingMap::builder()
+            .set_bam_bit_flags_encoding(integer_encoding())
+            .set_cram_bit_flags_encoding(integer_encoding())
+            .set_read_lengths_encoding(integer_encoding())
+            .set_in_seq_positions_encoding(integer_encoding())
+            .set_read_groups_encoding(integer_encoding())
+            .set_tag_ids_encoding(integer_encoding())
+            .set_quality_scores_encoding(quality_scores_encoding)
+            .build()
+            .unwrap();
+
+        CompressionHeader::new(
+            default_compression_header.preservation_map().clone(),
+            data_series_encoding_map,
+            default_compression_header.tag_encoding_map().clone(),
+        )
+    }
+
+    #[test]
+    fn test_read_quality_scores_stored_as_array_with_constant_huffman_encoding() -> io::Result<()> {
+        let compression_header =
+            build_compression_header(Encoding::new(Byte::Huffman(vec![0x2d], vec![0])));
+
+        let mut reader = Reader::new(
+            &compression_header,
+            BitReader::new(&[][..]),
+            ExternalDataReaders::<&[u8]>::new(),
+            ReferenceSequenceContext::None,
+        );
+
+        let actual = reader.read_quality_scores_stored_as_array(4)?;
+        assert_eq!(actual, QualityScores::from(vec![0x2d; 4]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_quality_scores_stored_as_array_with_missing_quality_scores() -> io::Result<()> {
+        let compression_header =
+            build_compression_header(Encoding::new(Byte::Huffman(vec![0xff], vec![0])));
+
+        let mut reader = Reader::new(
+            &compression_header,
+            BitReader::new(&[][..]),
+            ExternalDataReaders::<&[u8]>::new(),
+            ReferenceSequenceContext::None,
+        );
+
+        let actual = reader.read_quality_scores_stored_as_array(4)?;
+        assert!(actual.is_empty());
+
+        Ok(())
+    }
 }
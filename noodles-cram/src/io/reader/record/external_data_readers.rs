@@ -31,6 +31,13 @@ where
         }
     }
 
+    pub fn contains_key(&self, id: &block::ContentId) -> bool {
+        match i32::from(*id) {
+            i @ 0..=63 => self.low_readers[i as usize].is_some(),
+            _ => self.high_readers.contains_key(id),
+        }
+    }
+
     pub fn get_mut(&mut self, id: &block::ContentId) -> Option<&mut B> {
         match i32::from(*id) {
             i @ 0..=63 => self.low_readers[i as usize].as_mut(),
@@ -0,0 +1,41 @@
+use std::collections::HashSet;
+
+use noodles_sam::alignment::record::data::field::Tag;
+
+/// A filter of the data fields (tags) decoded from records.
+///
+/// Fields that are filtered out are skipped when decoding and are not added to the record data.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TagFilter {
+    /// Decodes only the fields with the given tags.
+    Keep(HashSet<Tag>),
+    /// Decodes all fields except those with the given tags.
+    Drop(HashSet<Tag>),
+}
+
+impl TagFilter {
+    pub(crate) fn matches(&self, tag: &Tag) -> bool {
+        match self {
+            Self::Keep(tags) => tags.contains(tag),
+            Self::Drop(tags) => !tags.contains(tag),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        let tags: HashSet<_> = [Tag::EDIT_DISTANCE].into_iter().collect();
+
+        let filter = TagFilter::Keep(tags.clone());
+        assert!(filter.matches(&Tag::EDIT_DISTANCE));
+        assert!(!filter.matches(&Tag::ALIGNMENT_HIT_COUNT));
+
+        let filter = TagFilter::Drop(tags);
+        assert!(!filter.matches(&Tag::EDIT_DISTANCE));
+        assert!(filter.matches(&Tag::ALIGNMENT_HIT_COUNT));
+    }
+}
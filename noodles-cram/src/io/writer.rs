@@ -6,20 +6,26 @@ pub(crate) mod data_container;
 pub(crate) mod header_container;
 pub(crate) mod num;
 mod options;
+mod position_writer;
 pub(crate) mod record;
 
 pub use self::builder::Builder;
 pub(crate) use self::options::Options;
+use self::position_writer::PositionWriter;
 
 use std::{
     io::{self, Write},
     mem,
+    ops::Range,
 };
 
 use noodles_fasta as fasta;
 use noodles_sam::{self as sam, header::ReferenceSequences};
 
-use crate::{file_definition::Version, DataContainer, FileDefinition, Record, MAGIC_NUMBER};
+use crate::{
+    container::Header as ContainerHeader, file_definition::Version, DataContainer, FileDefinition,
+    Record, MAGIC_NUMBER,
+};
 
 /// A CRAM writer.
 ///
@@ -53,6 +59,8 @@ where
     options: Options,
     data_container_builder: crate::data_container::Builder,
     record_counter: u64,
+    position: u64,
+    containers: Vec<(Range<u64>, ContainerHeader)>,
 }
 
 impl<W> Writer<W>
@@ -84,6 +92,46 @@ where
         &self.inner
     }
 
+    /// Returns the byte ranges and headers of the data containers written so far.
+    ///
+    /// Byte ranges are relative to the start of the output stream and include the container
+    /// header. The slice positions in the container are given by its header's
+    /// [`landmarks`](ContainerHeader::landmarks), which are byte offsets from the end of the
+    /// container header.
+    ///
+    /// This can be used to build an index, e.g., a CRAM index (CRAI), without rereading the
+    /// output.
+    ///
+    /// Containers are only recorded when enabled using [`Builder::record_containers`].
+    /// Otherwise, this is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_cram as cram;
+    /// use noodles_sam as sam;
+    ///
+    /// let mut writer = cram::io::writer::Builder::default()
+    ///     .record_containers(true)
+    ///     .build_with_writer(Vec::new());
+    ///
+    /// let header = sam::Header::default();
+    /// writer.write_header(&header)?;
+    /// writer.write_record(&header, cram::Record::default())?;
+    /// writer.try_finish(&header)?;
+    ///
+    /// let containers = writer.containers();
+    /// assert_eq!(containers.len(), 1);
+    ///
+    /// let (range, container_header) = &containers[0];
+    /// assert_eq!(container_header.landmarks().len(), 1);
+    /// # Ok::<(), io::Error>(())
+    /// ```
+    pub fn containers(&self) -> &[(Range<u64>, ContainerHeader)] {
+        &self.containers
+    }
+
     /// Attempts to finish the output stream by writing any pending containers and a final EOF
     /// container.
     ///
@@ -104,8 +152,10 @@ where
     /// ```
     pub fn try_finish(&mut self, header: &sam::Header) -> io::Result<()> {
         use self::container::write_eof_container;
+
         self.flush(header)?;
-        write_eof_container(&mut self.inner)
+
+        write_eof_container(&mut self.position_writer())
     }
 
     /// Writes a CRAM file definition.
@@ -134,7 +184,8 @@ where
     /// ```
     pub fn write_file_definition(&mut self) -> io::Result<()> {
         let file_definition = FileDefinition::new(self.options.version, Default::default());
-        write_file_definition(&mut self.inner, &file_definition)
+
+        write_file_definition(&mut self.position_writer(), &file_definition)
     }
 
     /// Writes a CRAM file header container.
@@ -170,7 +221,7 @@ where
             header.reference_sequences_mut(),
         )?;
 
-        write_header_container(&mut self.inner, &header)
+        write_header_container(&mut self.position_writer(), &header)
     }
 
     /// Writes a SAM header.
@@ -218,13 +269,17 @@ where
         use crate::data_container::builder::AddRecordError;
 
         loop {
-            match self.data_container_builder.add_record(record) {
+            match self
+                .data_container_builder
+                .add_record(&self.options, record)
+            {
                 Ok(_) => {
                     self.record_counter += 1;
                     return Ok(());
                 }
                 Err(e) => match e {
-                    AddRecordError::ContainerFull(r) => {
+                    AddRecordError::ContainerFull(r)
+                    | AddRecordError::ReferenceSequenceChanged(r) => {
                         record = r;
                         self.flush(header)?;
                     }
@@ -257,7 +312,20 @@ where
             header,
         )?;
 
-        write_data_container(&mut self.inner, &data_container, base_count)
+        let start = self.position;
+        let container_header =
+            write_data_container(&mut self.position_writer(), &data_container, base_count)?;
+        let end = self.position;
+
+        if self.options.record_containers {
+            self.containers.push((start..end, container_header));
+        }
+
+        Ok(())
+    }
+
+    fn position_writer(&mut self) -> PositionWriter<'_, W> {
+        PositionWriter::new(&mut self.inner, &mut self.position)
     }
 }
 
@@ -335,6 +403,547 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn test_write_record_with_records_per_slice_and_slices_per_container(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::io::Reader;
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .set_records_per_slice(NonZeroUsize::try_from(2)?)
+            .set_slices_per_container(NonZeroUsize::try_from(2)?)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for _ in 0..5 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let mut slice_record_counts = Vec::new();
+
+        while let Some(data_container) = reader.read_data_container()? {
+            let counts: Vec<_> = data_container
+                .slices()
+                .iter()
+                .map(|slice| slice.record_count())
+                .collect();
+
+            slice_record_counts.push(counts);
+        }
+
+        assert_eq!(slice_record_counts, [vec![2, 2], vec![1]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_containers() -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use crate::io::Reader;
+
+        let header = sam::Header::default();
+
+        let mut writer = Builder::default()
+            .set_records_per_slice(NonZeroUsize::try_from(2)?)
+            .set_slices_per_container(NonZeroUsize::try_from(2)?)
+            .record_containers(true)
+            .build_with_writer(Vec::new());
+
+        writer.write_header(&header)?;
+
+        for _ in 0..5 {
+            writer.write_record(&header, Record::default())?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let containers = writer.containers();
+        assert_eq!(containers.len(), 2);
+
+        let src = writer.get_ref();
+
+        for (range, expected) in containers {
+            let start = usize::try_from(range.start)?;
+            let end = usize::try_from(range.end)?;
+
+            let mut reader = Reader::new(io::Cursor::new(&src[start..end]));
+            let container = reader.read_container()?.expect("missing container");
+            assert_eq!(container.header(), expected);
+            assert_eq!(reader.position()?, range.end - range.start);
+        }
+
+        assert_eq!(containers[0].1.landmarks().len(), 2);
+        assert_eq!(containers[1].1.landmarks().len(), 1);
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.write_record(&header, Record::default())?;
+        writer.try_finish(&header)?;
+        assert!(writer.containers().is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_data() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write,
+            record::data::field::Tag,
+            record_buf::{
+                data::field::{value::Array, Value},
+                Data, Sequence,
+            },
+            RecordBuf,
+        };
+
+        use crate::io::Reader;
+
+        fn build_record(data: Data) -> RecordBuf {
+            RecordBuf::builder()
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_data(data)
+                .build()
+        }
+
+        let header = sam::Header::default();
+
+        let expected = [
+            build_record(
+                [
+                    (Tag::EDIT_DISTANCE, Value::Int32(1)),
+                    (Tag::MISMATCHED_POSITIONS, Value::from("2C1")),
+                    (
+                        Tag::new(b'Z', b'B'),
+                        Value::Array(Array::Int8(vec![-1, 0, 1])),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            build_record(
+                [
+                    (Tag::EDIT_DISTANCE, Value::UInt8(3)),
+                    (Tag::new(b'Z', b'A'), Value::Character(b'n')),
+                    (Tag::new(b'Z', b'H'), Value::Hex("CAFE".into())),
+                    (
+                        Tag::new(b'Z', b'B'),
+                        Value::Array(Array::Float(vec![0.0, 1.5])),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            build_record(Data::default()),
+        ];
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_alignment_header(&header)?;
+
+        for record in &expected {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), expected.len());
+
+        for (a, e) in actual.iter().zip(&expected) {
+            assert_eq!(a.data(), e.data());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_cram_3_1_codecs() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write,
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        use crate::{
+            codecs::{rans_nx16, Encoder},
+            data_container::{
+                compression_header::data_series_encoding_map::DataSeries, BlockContentEncoderMap,
+            },
+            io::Reader,
+        };
+
+        fn t(data_series: DataSeries, encoder: Encoder) -> Result<(), Box<dyn std::error::Error>> {
+            let header = sam::Header::default();
+
+            let expected: Vec<_> = [
+                ("r0", b"ACGT", [45, 35, 43, 50]),
+                ("r1", b"TGCA", [8, 13, 21, 34]),
+            ]
+            .into_iter()
+            .map(|(name, sequence, quality_scores)| {
+                RecordBuf::builder()
+                    .set_name(name)
+                    .set_sequence(Sequence::from(sequence))
+                    .set_quality_scores(QualityScores::from(quality_scores.to_vec()))
+                    .build()
+            })
+            .collect();
+
+            let block_content_encoder_map = BlockContentEncoderMap::builder()
+                .set_data_series_encoder(data_series, Some(encoder))
+                .build();
+
+            let mut writer = Builder::default()
+                .set_block_content_encoder_map(block_content_encoder_map)
+                .build_with_writer(Vec::new());
+
+            writer.write_alignment_header(&header)?;
+
+            for record in &expected {
+                writer.write_alignment_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            let mut reader = Reader::new(&writer.get_ref()[..]);
+
+            let file_definition = reader.read_file_definition()?;
+            assert_eq!(file_definition.version(), Version::new(3, 1));
+
+            reader.read_file_header()?;
+
+            let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+            assert_eq!(actual.len(), expected.len());
+
+            for (a, e) in actual.iter().zip(&expected) {
+                assert_eq!(a.name(), e.name());
+                assert_eq!(a.sequence(), e.sequence());
+                assert_eq!(a.quality_scores(), e.quality_scores());
+            }
+
+            Ok(())
+        }
+
+        t(
+            DataSeries::Bases,
+            Encoder::RansNx16(rans_nx16::Flags::ORDER),
+        )?;
+        t(DataSeries::ReadNames, Encoder::NameTokenizer)?;
+        t(DataSeries::QualityScores, Encoder::Fqzcomp)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_tokenized_read_names(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use sam::alignment::{
+            io::Write,
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        };
+
+        use crate::io::Reader;
+
+        fn write(
+            header: &sam::Header,
+            records: &[RecordBuf],
+            tokenize_read_names: bool,
+        ) -> io::Result<Vec<u8>> {
+            let mut writer = Builder::default()
+                .tokenize_read_names(tokenize_read_names)
+                .build_with_writer(Vec::new());
+
+            writer.write_alignment_header(header)?;
+
+            for record in records {
+                writer.write_alignment_record(header, record)?;
+            }
+
+            writer.try_finish(header)?;
+
+            Ok(writer.get_ref().clone())
+        }
+
+        let header = sam::Header::default();
+
+        let expected: Vec<_> = (0..256)
+            .map(|i| {
+                let tile = 1101 + (i / 64);
+                let x = 1000 + (i * 37) % 30000;
+                let y = 1000 + (i * 113) % 30000;
+
+                RecordBuf::builder()
+                    .set_name(format!("A00123:8:H5KJ2DSXY:1:{tile}:{x}:{y}"))
+                    .set_sequence(Sequence::from(b"ACGT"))
+                    .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                    .build()
+            })
+            .collect();
+
+        let src = write(&header, &expected, true)?;
+
+        let mut reader = Reader::new(&src[..]);
+
+        let file_definition = reader.read_file_definition()?;
+        assert_eq!(file_definition.version(), Version::new(3, 1));
+
+        reader.read_file_header()?;
+
+        let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+        assert_eq!(actual.len(), expected.len());
+
+        for (a, e) in actual.iter().zip(&expected) {
+            assert_eq!(a.name(), e.name());
+        }
+
+        let untokenized_src = write(&header, &expected, false)?;
+        assert!(src.len() < untokenized_src.len());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_multiple_reference_sequences(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::Definition;
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::{
+            data_container::ReferenceSequenceContext,
+            io::{reader, Reader},
+        };
+
+        fn t(
+            records_per_slice: usize,
+            expected_slice_reference_sequence_contexts: &[ReferenceSequenceContext],
+        ) -> Result<(), Box<dyn std::error::Error>> {
+            let repository = fasta::Repository::new(vec![
+                fasta::Record::new(
+                    Definition::new("sq0", None),
+                    fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+                ),
+                fasta::Record::new(
+                    Definition::new("sq1", None),
+                    fasta::record::Sequence::from(b"GATCTTACTTTTT".to_vec()),
+                ),
+            ]);
+
+            let header = sam::Header::builder()
+                .add_reference_sequence(
+                    "sq0",
+                    Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+                )
+                .add_reference_sequence(
+                    "sq1",
+                    Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+                )
+                .build();
+
+            let expected: Vec<_> = [(0, 2, b"CACC"), (1, 5, b"TTAC")]
+                .into_iter()
+                .map(|(reference_sequence_id, alignment_start, sequence)| {
+                    Ok(RecordBuf::builder()
+                        .set_flags(Flags::empty())
+                        .set_reference_sequence_id(reference_sequence_id)
+                        .set_alignment_start(Position::try_from(alignment_start)?)
+                        .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect::<Cigar>())
+                        .set_sequence(Sequence::from(sequence))
+                        .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                        .build())
+                })
+                .collect::<Result<_, noodles_core::position::TryFromIntError>>()?;
+
+            let mut writer = Builder::default()
+                .set_reference_sequence_repository(repository.clone())
+                .set_records_per_slice(NonZeroUsize::try_from(records_per_slice)?)
+                .set_slices_per_container(NonZeroUsize::try_from(2)?)
+                .build_with_writer(Vec::new());
+
+            writer.write_alignment_header(&header)?;
+
+            for record in &expected {
+                writer.write_alignment_record(&header, record)?;
+            }
+
+            writer.try_finish(&header)?;
+
+            let mut reader = Reader::new(&writer.get_ref()[..]);
+            reader.read_header()?;
+
+            let mut actual_slice_reference_sequence_contexts = Vec::new();
+
+            while let Some(data_container) = reader.read_data_container()? {
+                for slice in data_container.slices() {
+                    actual_slice_reference_sequence_contexts
+                        .push(slice.header().reference_sequence_context());
+                }
+            }
+
+            assert_eq!(
+                actual_slice_reference_sequence_contexts,
+                expected_slice_reference_sequence_contexts
+            );
+
+            let mut reader = reader::Builder::default()
+                .set_reference_sequence_repository(repository)
+                .build_from_reader(&writer.get_ref()[..]);
+            reader.read_header()?;
+
+            let actual: Vec<_> = reader.records(&header).collect::<io::Result<_>>()?;
+            assert_eq!(actual.len(), expected.len());
+
+            for (a, e) in actual.iter().zip(&expected) {
+                assert_eq!(a.reference_sequence_id(), e.reference_sequence_id());
+                assert_eq!(a.alignment_start(), e.alignment_start());
+                assert_eq!(a.sequence(), e.sequence());
+            }
+
+            Ok(())
+        }
+
+        t(2, &[ReferenceSequenceContext::Many])?;
+
+        t(
+            1,
+            &[
+                ReferenceSequenceContext::some(0, Position::try_from(2)?, Position::try_from(5)?),
+                ReferenceSequenceContext::some(1, Position::try_from(5)?, Position::try_from(8)?),
+            ],
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_alignment_record_with_single_reference_per_container(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use std::num::NonZeroUsize;
+
+        use fasta::record::Definition;
+        use noodles_core::Position;
+        use sam::{
+            alignment::{
+                io::Write,
+                record::{
+                    cigar::{op::Kind, Op},
+                    Flags,
+                },
+                record_buf::{Cigar, QualityScores, Sequence},
+                RecordBuf,
+            },
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        use crate::{data_container::ReferenceSequenceContext, io::Reader};
+
+        let repository = fasta::Repository::new(vec![
+            fasta::Record::new(
+                Definition::new("sq0", None),
+                fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+            ),
+            fasta::Record::new(
+                Definition::new("sq1", None),
+                fasta::record::Sequence::from(b"GATCTTACTTTTT".to_vec()),
+            ),
+        ]);
+
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .build();
+
+        let records: Vec<_> = [(0, 2, b"CACC"), (0, 3, b"ACCC"), (1, 5, b"TTAC")]
+            .into_iter()
+            .map(|(reference_sequence_id, alignment_start, sequence)| {
+                Ok(RecordBuf::builder()
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(reference_sequence_id)
+                    .set_alignment_start(Position::try_from(alignment_start)?)
+                    .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect::<Cigar>())
+                    .set_sequence(Sequence::from(sequence))
+                    .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                    .build())
+            })
+            .collect::<Result<_, noodles_core::position::TryFromIntError>>()?;
+
+        let mut writer = Builder::default()
+            .set_reference_sequence_repository(repository)
+            .single_reference_per_container(true)
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(&header)?;
+
+        for record in &records {
+            writer.write_alignment_record(&header, record)?;
+        }
+
+        writer.try_finish(&header)?;
+
+        let mut reader = Reader::new(&writer.get_ref()[..]);
+        reader.read_header()?;
+
+        let mut actual = Vec::new();
+
+        while let Some(container) = reader.read_container()? {
+            let container_header = container.header();
+
+            if container_header.record_count() > 0 {
+                actual.push((
+                    container_header.reference_sequence_context(),
+                    container_header.record_count(),
+                ));
+            }
+        }
+
+        let expected = [
+            (
+                ReferenceSequenceContext::some(0, Position::try_from(2)?, Position::try_from(6)?),
+                2,
+            ),
+            (
+                ReferenceSequenceContext::some(1, Position::try_from(5)?, Position::try_from(8)?),
+                1,
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
     #[test]
     fn test_add_missing_reference_sequence_checksums() -> Result<(), Box<dyn std::error::Error>> {
         use std::num::NonZeroUsize;
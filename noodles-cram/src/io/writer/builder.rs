@@ -1,6 +1,7 @@
 use std::{
     fs::File,
     io::{self, Write},
+    num::NonZeroUsize,
     path::Path,
 };
 
@@ -8,7 +9,11 @@ use noodles_fasta as fasta;
 
 use super::{Options, Writer};
 use crate::{
-    codecs::Encoder, data_container::BlockContentEncoderMap, file_definition::Version,
+    codecs::Encoder,
+    data_container::{
+        compression_header::data_series_encoding_map::DataSeries, BlockContentEncoderMap,
+    },
+    file_definition::Version,
     DataContainer,
 };
 
@@ -57,6 +62,65 @@ impl Builder {
         self
     }
 
+    /// Sets whether to encode read names using the name tokenizer.
+    ///
+    /// Read names from an instrument typically share most of their tokens, e.g., Illumina names
+    /// that only differ by tile and coordinates. When enabled, read names are compressed using
+    /// the CRAM 3.1 name tokenizer codec, which encodes each name as a delta of its tokens to a
+    /// previous name. This overrides the read names encoder in the block content-encoder map and
+    /// sets the output version to CRAM 3.1.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().tokenize_read_names(true);
+    /// ```
+    pub fn tokenize_read_names(mut self, value: bool) -> Self {
+        self.options.tokenize_read_names = value;
+        self
+    }
+
+    /// Sets whether each data container only holds records from a single reference sequence.
+    ///
+    /// If `true`, the current data container is written when a record has a different reference
+    /// sequence ID than the previous record. For coordinate-sorted input, each container then has
+    /// a single reference sequence context rather than multiple reference sequences, which allows
+    /// for more precise indexing. Unmapped records are grouped together.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().single_reference_per_container(true);
+    /// ```
+    pub fn single_reference_per_container(mut self, value: bool) -> Self {
+        self.options.single_reference_per_container = value;
+        self
+    }
+
+    /// Sets whether to record the byte ranges and headers of written data containers.
+    ///
+    /// If `true`, these are available using [`Writer::containers`], e.g., to build an index
+    /// without rereading the output.
+    ///
+    /// The default is `false`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().record_containers(true);
+    /// ```
+    pub fn record_containers(mut self, value: bool) -> Self {
+        self.options.record_containers = value;
+        self
+    }
+
     /// Sets whether to encode alignment start positions as deltas.
     ///
     /// If `false`, record alignment start positions are written with their actual values.
@@ -91,6 +155,41 @@ impl Builder {
         self
     }
 
+    /// Sets the maximum number of records per slice.
+    ///
+    /// Smaller slices allow finer-grained queries, while larger slices typically compress better.
+    ///
+    /// The default is 10000.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_records_per_slice(NonZeroUsize::try_from(1024)?);
+    /// # Ok::<_, std::num::TryFromIntError>(())
+    /// ```
+    pub fn set_records_per_slice(mut self, records_per_slice: NonZeroUsize) -> Self {
+        self.options.records_per_slice = records_per_slice;
+        self
+    }
+
+    /// Sets the maximum number of slices per container.
+    ///
+    /// The default is 1.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    /// use noodles_cram::io::writer::Builder;
+    /// let builder = Builder::default().set_slices_per_container(NonZeroUsize::MIN);
+    /// ```
+    pub fn set_slices_per_container(mut self, slices_per_container: NonZeroUsize) -> Self {
+        self.options.slices_per_container = slices_per_container;
+        self
+    }
+
     /// Builds a CRAM writer from a path.
     ///
     /// # Examples
@@ -119,6 +218,12 @@ impl Builder {
     where
         W: Write,
     {
+        if self.options.tokenize_read_names {
+            self.options
+                .block_content_encoder_map
+                .set_data_series_encoder(DataSeries::ReadNames, Some(Encoder::NameTokenizer));
+        }
+
         if uses_cram_3_1_codecs(&self.options.block_content_encoder_map) {
             self.options.version = Version::new(3, 1);
         }
@@ -129,6 +234,8 @@ impl Builder {
             options: self.options,
             data_container_builder: DataContainer::builder(0),
             record_counter: 0,
+            position: 0,
+            containers: Vec::new(),
         }
     }
 }
@@ -137,7 +244,10 @@ pub fn uses_cram_3_1_codecs(block_content_encoder_map: &BlockContentEncoderMap)
     fn is_cram_3_1_codec(encoder: &Encoder) -> bool {
         matches!(
             encoder,
-            Encoder::RansNx16(_) | Encoder::AdaptiveArithmeticCoding(_) | Encoder::NameTokenizer
+            Encoder::RansNx16(_)
+                | Encoder::AdaptiveArithmeticCoding(_)
+                | Encoder::NameTokenizer
+                | Encoder::Fqzcomp
         )
     }
 
@@ -161,7 +271,10 @@ mod tests {
 
     #[test]
     fn test_uses_cram_3_1_codecs() {
-        use crate::codecs::rans_nx16::Flags;
+        use crate::{
+            codecs::rans_nx16::Flags,
+            data_container::compression_header::data_series_encoding_map::DataSeries,
+        };
 
         let block_content_encoder_map = BlockContentEncoderMap::default();
         assert!(!uses_cram_3_1_codecs(&block_content_encoder_map));
@@ -170,5 +283,10 @@ mod tests {
             .set_core_data_encoder(Some(Encoder::RansNx16(Flags::empty())))
             .build();
         assert!(uses_cram_3_1_codecs(&block_content_encoder_map));
+
+        let block_content_encoder_map = BlockContentEncoderMap::builder()
+            .set_data_series_encoder(DataSeries::QualityScores, Some(Encoder::Fqzcomp))
+            .build();
+        assert!(uses_cram_3_1_codecs(&block_content_encoder_map));
     }
 }
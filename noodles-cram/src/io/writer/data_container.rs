@@ -8,8 +8,8 @@ use std::{
 
 use self::compression_header::write_compression_header;
 use crate::{
-    container::Block,
-    data_container::{Header, ReferenceSequenceContext, Slice},
+    container::{Block, Header},
+    data_container::{ReferenceSequenceContext, Slice},
     DataContainer,
 };
 
@@ -17,7 +17,7 @@ pub fn write_data_container<W>(
     writer: &mut W,
     data_container: &DataContainer,
     base_count: u64,
-) -> io::Result<()>
+) -> io::Result<Header>
 where
     W: Write,
 {
@@ -31,7 +31,7 @@ where
         write_block(writer, &block)?;
     }
 
-    Ok(())
+    Ok(header)
 }
 
 fn build_container(
@@ -53,7 +53,7 @@ fn build_container(
     let mut landmarks = Vec::new();
 
     let container_reference_sequence_context =
-        build_container_reference_sequence_context(data_container.slices())?;
+        build_container_reference_sequence_context(data_container.slices());
 
     let mut container_record_count = 0;
     let container_record_counter = data_container
@@ -109,9 +109,7 @@ fn build_container(
     Ok((header, blocks))
 }
 
-fn build_container_reference_sequence_context(
-    slices: &[Slice],
-) -> io::Result<ReferenceSequenceContext> {
+fn build_container_reference_sequence_context(slices: &[Slice]) -> ReferenceSequenceContext {
     assert!(!slices.is_empty());
 
     let first_slice = slices.first().expect("slices cannot be empty");
@@ -148,17 +146,89 @@ fn build_container_reference_sequence_context(
                 );
             }
             (ReferenceSequenceContext::None, ReferenceSequenceContext::None) => {}
-            (ReferenceSequenceContext::Many, ReferenceSequenceContext::Many) => {}
-            _ => {
-                return Err(io::Error::new(
-                    io::ErrorKind::InvalidInput,
-                    format!(
-                        "invalid slice reference sequence context: expected {container_reference_sequence_context:?}, got {slice_reference_sequence_context:?}"
-                    ),
-                ));
-            }
+            // Slices that reference different reference sequences, or that mix mapped and
+            // unmapped records, are in a multi-reference container.
+            _ => container_reference_sequence_context = ReferenceSequenceContext::Many,
         }
     }
 
-    Ok(container_reference_sequence_context)
+    container_reference_sequence_context
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+
+    use super::*;
+    use crate::{container::block::ContentType, data_container::slice};
+
+    #[test]
+    fn test_build_container_reference_sequence_context(
+    ) -> Result<(), noodles_core::position::TryFromIntError> {
+        fn build_slice(reference_sequence_context: ReferenceSequenceContext) -> Slice {
+            let core_data_block = Block::builder()
+                .set_content_type(ContentType::CoreData)
+                .build();
+
+            Slice::new(
+                slice::Header::builder()
+                    .set_reference_sequence_context(reference_sequence_context)
+                    .build(),
+                core_data_block,
+                Vec::new(),
+            )
+        }
+
+        let slices = [
+            build_slice(ReferenceSequenceContext::some(
+                0,
+                Position::try_from(8)?,
+                Position::try_from(13)?,
+            )),
+            build_slice(ReferenceSequenceContext::some(
+                0,
+                Position::try_from(5)?,
+                Position::try_from(21)?,
+            )),
+        ];
+
+        assert_eq!(
+            build_container_reference_sequence_context(&slices),
+            ReferenceSequenceContext::some(0, Position::try_from(5)?, Position::try_from(21)?)
+        );
+
+        let slices = [
+            build_slice(ReferenceSequenceContext::some(
+                0,
+                Position::try_from(8)?,
+                Position::try_from(13)?,
+            )),
+            build_slice(ReferenceSequenceContext::some(
+                1,
+                Position::try_from(5)?,
+                Position::try_from(21)?,
+            )),
+        ];
+
+        assert_eq!(
+            build_container_reference_sequence_context(&slices),
+            ReferenceSequenceContext::Many
+        );
+
+        let slices = [
+            build_slice(ReferenceSequenceContext::None),
+            build_slice(ReferenceSequenceContext::some(
+                0,
+                Position::try_from(8)?,
+                Position::try_from(13)?,
+            )),
+        ];
+
+        assert_eq!(
+            build_container_reference_sequence_context(&slices),
+            ReferenceSequenceContext::Many
+        );
+
+        Ok(())
+    }
 }
@@ -1,20 +1,39 @@
+use std::num::NonZeroUsize;
+
 use crate::{data_container::BlockContentEncoderMap, file_definition::Version};
 
+const DEFAULT_RECORDS_PER_SLICE: NonZeroUsize = match NonZeroUsize::new(10000) {
+    Some(n) => n,
+    None => unreachable!(),
+};
+
+const DEFAULT_SLICES_PER_CONTAINER: NonZeroUsize = NonZeroUsize::MIN;
+
 #[derive(Clone, Debug)]
 pub struct Options {
     pub preserve_read_names: bool,
+    pub tokenize_read_names: bool,
     pub encode_alignment_start_positions_as_deltas: bool,
     pub version: Version,
     pub block_content_encoder_map: BlockContentEncoderMap,
+    pub records_per_slice: NonZeroUsize,
+    pub slices_per_container: NonZeroUsize,
+    pub single_reference_per_container: bool,
+    pub record_containers: bool,
 }
 
 impl Default for Options {
     fn default() -> Self {
         Self {
             preserve_read_names: true,
+            tokenize_read_names: false,
             encode_alignment_start_positions_as_deltas: true,
             version: Version::default(),
             block_content_encoder_map: BlockContentEncoderMap::default(),
+            records_per_slice: DEFAULT_RECORDS_PER_SLICE,
+            slices_per_container: DEFAULT_SLICES_PER_CONTAINER,
+            single_reference_per_container: false,
+            record_containers: false,
         }
     }
 }
@@ -0,0 +1,48 @@
+use std::io::{self, Write};
+
+/// A writer that counts the number of bytes written to the inner writer.
+pub(super) struct PositionWriter<'a, W> {
+    inner: &'a mut W,
+    position: &'a mut u64,
+}
+
+impl<'a, W> PositionWriter<'a, W> {
+    pub(super) fn new(inner: &'a mut W, position: &'a mut u64) -> Self {
+        Self { inner, position }
+    }
+}
+
+impl<W> Write for PositionWriter<'_, W>
+where
+    W: Write,
+{
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let n = self.inner.write(buf)?;
+        *self.position += n as u64;
+        Ok(n)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write() -> io::Result<()> {
+        let mut buf = Vec::new();
+        let mut position = 0;
+
+        let mut writer = PositionWriter::new(&mut buf, &mut position);
+        writer.write_all(b"nd")?;
+        writer.write_all(b"ls")?;
+
+        assert_eq!(position, 4);
+        assert_eq!(buf, b"ndls");
+
+        Ok(())
+    }
+}
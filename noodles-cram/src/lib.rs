@@ -6,7 +6,7 @@
 pub mod r#async;
 
 pub mod codecs;
-pub(crate) mod container;
+pub mod container;
 pub mod crai;
 pub mod data_container;
 pub mod file_definition;
@@ -4,7 +4,7 @@ pub mod index;
 mod indexer;
 mod reference_sequence;
 
-use std::io;
+use std::{fmt, io};
 
 use noodles_bgzf as bgzf;
 use noodles_core::region::Interval;
@@ -39,6 +39,20 @@ pub trait BinningIndex {
     fn last_first_record_start_position(&self) -> Option<bgzf::VirtualPosition>;
 }
 
+impl<'a> fmt::Debug for dyn BinningIndex + 'a {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BinningIndex")
+            .field("min_shift", &self.min_shift())
+            .field("depth", &self.depth())
+            .field("header", &self.header())
+            .field(
+                "unplaced_unmapped_record_count",
+                &self.unplaced_unmapped_record_count(),
+            )
+            .finish_non_exhaustive()
+    }
+}
+
 impl<I> BinningIndex for Box<I>
 where
     I: BinningIndex + ?Sized,
@@ -8,6 +8,7 @@ pub use self::{builder::Builder, header::Header, reference_sequence::ReferenceSe
 
 use std::io;
 
+use indexmap::IndexMap;
 use noodles_bgzf as bgzf;
 use noodles_core::{region::Interval, Position};
 
@@ -51,6 +52,33 @@ where
     pub fn reference_sequences(&self) -> &[ReferenceSequence<I>] {
         &self.reference_sequences
     }
+
+    /// Returns the number of mapped records for each reference sequence.
+    ///
+    /// The counts are read from the metadata pseudo-bin of each reference sequence, keyed by
+    /// reference sequence ID. Reference sequences without metadata are not included. No records
+    /// are read.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_csi as csi;
+    /// let index = csi::Index::default();
+    /// assert!(index.record_counts().is_empty());
+    /// ```
+    pub fn record_counts(&self) -> IndexMap<usize, u64> {
+        use super::ReferenceSequence as _;
+
+        self.reference_sequences
+            .iter()
+            .enumerate()
+            .filter_map(|(i, reference_sequence)| {
+                reference_sequence
+                    .metadata()
+                    .map(|metadata| (i, metadata.mapped_record_count()))
+            })
+            .collect()
+    }
 }
 
 impl<I> Default for Index<I>
@@ -166,6 +194,43 @@ fn max_position(min_shift: u8, depth: u8) -> io::Result<Position> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_record_counts() {
+        use self::reference_sequence::{index::LinearIndex, Metadata};
+
+        let reference_sequences = vec![
+            ReferenceSequence::new(
+                IndexMap::new(),
+                LinearIndex::default(),
+                Some(Metadata::new(
+                    bgzf::VirtualPosition::from(610),
+                    bgzf::VirtualPosition::from(1597),
+                    55,
+                    0,
+                )),
+            ),
+            ReferenceSequence::new(IndexMap::new(), LinearIndex::default(), None),
+            ReferenceSequence::new(
+                IndexMap::new(),
+                LinearIndex::default(),
+                Some(Metadata::new(
+                    bgzf::VirtualPosition::from(1597),
+                    bgzf::VirtualPosition::from(2584),
+                    8,
+                    13,
+                )),
+            ),
+        ];
+
+        let index = Index::builder()
+            .set_reference_sequences(reference_sequences)
+            .build();
+
+        let actual = index.record_counts();
+        let expected: IndexMap<_, _> = [(0, 55), (2, 8)].into_iter().collect();
+        assert_eq!(actual, expected);
+    }
+
     #[test]
     fn test_max_position() -> Result<(), Box<dyn std::error::Error>> {
         const MIN_SHIFT: u8 = 14;
@@ -18,7 +18,10 @@ pub use self::{
 };
 use crate::{
     header::{
-        record::value::{map::ReferenceSequence, Map},
+        record::value::{
+            map::{ReadGroup, ReferenceSequence},
+            Map,
+        },
         ReferenceSequences,
     },
     Header,
@@ -96,6 +99,45 @@ pub trait Record {
         get_reference_sequence(header.reference_sequences(), mate_reference_sequence_id)
     }
 
+    /// Returns the associated read group.
+    ///
+    /// This looks up the read group (`RG`) data field in the read groups of the header. If the
+    /// record has no read group, this returns `None`. If the read group is not declared in the
+    /// header, this returns an [`io::ErrorKind::InvalidData`] error, which can be used to check
+    /// that records only reference declared read groups.
+    fn read_group<'h>(
+        &self,
+        header: &'h Header,
+    ) -> Option<io::Result<(&'h BStr, &'h Map<ReadGroup>)>> {
+        use self::data::field::{Tag, Value};
+
+        let data = self.data();
+
+        let id = match data.get(&Tag::READ_GROUP)? {
+            Ok(Value::String(id)) => id,
+            Ok(_) => {
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "invalid read group value type",
+                )))
+            }
+            Err(e) => return Some(Err(e)),
+        };
+
+        let result = header
+            .read_groups()
+            .get_key_value(id)
+            .map(|(id, read_group)| (id.as_ref(), read_group))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("undeclared read group: {id}"),
+                )
+            });
+
+        Some(result)
+    }
+
     /// Returns the alignment span.
     fn alignment_span(&self) -> io::Result<Option<usize>> {
         self.cigar().alignment_span().map(|span| match span {
@@ -216,4 +258,45 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_read_group() -> io::Result<()> {
+        use crate::alignment::{
+            record::data::field::Tag,
+            record_buf::{data::field::Value, RecordBuf},
+        };
+
+        let header = Header::builder()
+            .add_read_group("rg0", Map::<ReadGroup>::default())
+            .build();
+
+        let record = RecordBuf::default();
+        assert!(Record::read_group(&record, &header).is_none());
+
+        let record = RecordBuf::builder()
+            .set_data(
+                [(Tag::READ_GROUP, Value::from("rg0"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+        let (id, _) = Record::read_group(&record, &header)
+            .transpose()?
+            .expect("missing read group");
+        assert_eq!(id, "rg0");
+
+        let record = RecordBuf::builder()
+            .set_data(
+                [(Tag::READ_GROUP, Value::from("rg1"))]
+                    .into_iter()
+                    .collect(),
+            )
+            .build();
+        assert!(matches!(
+            Record::read_group(&record, &header),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
 }
@@ -203,6 +203,174 @@ impl Flags {
     pub fn is_supplementary(self) -> bool {
         self.contains(Self::SUPPLEMENTARY)
     }
+
+    /// Returns a copy of these flags with the `SEGMENTED` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_segmented(true), Flags::SEGMENTED);
+    /// assert!(Flags::SEGMENTED.with_segmented(false).is_empty());
+    /// ```
+    pub fn with_segmented(mut self, value: bool) -> Self {
+        self.set(Self::SEGMENTED, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `PROPERLY_SEGMENTED` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_properly_segmented(true), Flags::PROPERLY_SEGMENTED);
+    /// assert!(Flags::PROPERLY_SEGMENTED.with_properly_segmented(false).is_empty());
+    /// ```
+    pub fn with_properly_segmented(mut self, value: bool) -> Self {
+        self.set(Self::PROPERLY_SEGMENTED, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `UNMAPPED` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_unmapped(true), Flags::UNMAPPED);
+    /// assert!(Flags::UNMAPPED.with_unmapped(false).is_empty());
+    /// ```
+    pub fn with_unmapped(mut self, value: bool) -> Self {
+        self.set(Self::UNMAPPED, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `MATE_UNMAPPED` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_mate_unmapped(true), Flags::MATE_UNMAPPED);
+    /// assert!(Flags::MATE_UNMAPPED.with_mate_unmapped(false).is_empty());
+    /// ```
+    pub fn with_mate_unmapped(mut self, value: bool) -> Self {
+        self.set(Self::MATE_UNMAPPED, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `REVERSE_COMPLEMENTED` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_reverse_complemented(true), Flags::REVERSE_COMPLEMENTED);
+    /// assert!(Flags::REVERSE_COMPLEMENTED.with_reverse_complemented(false).is_empty());
+    /// ```
+    pub fn with_reverse_complemented(mut self, value: bool) -> Self {
+        self.set(Self::REVERSE_COMPLEMENTED, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `MATE_REVERSE_COMPLEMENTED` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_mate_reverse_complemented(true), Flags::MATE_REVERSE_COMPLEMENTED);
+    /// assert!(Flags::MATE_REVERSE_COMPLEMENTED.with_mate_reverse_complemented(false).is_empty());
+    /// ```
+    pub fn with_mate_reverse_complemented(mut self, value: bool) -> Self {
+        self.set(Self::MATE_REVERSE_COMPLEMENTED, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `FIRST_SEGMENT` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_first_segment(true), Flags::FIRST_SEGMENT);
+    /// assert!(Flags::FIRST_SEGMENT.with_first_segment(false).is_empty());
+    /// ```
+    pub fn with_first_segment(mut self, value: bool) -> Self {
+        self.set(Self::FIRST_SEGMENT, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `LAST_SEGMENT` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_last_segment(true), Flags::LAST_SEGMENT);
+    /// assert!(Flags::LAST_SEGMENT.with_last_segment(false).is_empty());
+    /// ```
+    pub fn with_last_segment(mut self, value: bool) -> Self {
+        self.set(Self::LAST_SEGMENT, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `SECONDARY` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_secondary(true), Flags::SECONDARY);
+    /// assert!(Flags::SECONDARY.with_secondary(false).is_empty());
+    /// ```
+    pub fn with_secondary(mut self, value: bool) -> Self {
+        self.set(Self::SECONDARY, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `QC_FAIL` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_qc_fail(true), Flags::QC_FAIL);
+    /// assert!(Flags::QC_FAIL.with_qc_fail(false).is_empty());
+    /// ```
+    pub fn with_qc_fail(mut self, value: bool) -> Self {
+        self.set(Self::QC_FAIL, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `DUPLICATE` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_duplicate(true), Flags::DUPLICATE);
+    /// assert!(Flags::DUPLICATE.with_duplicate(false).is_empty());
+    /// ```
+    pub fn with_duplicate(mut self, value: bool) -> Self {
+        self.set(Self::DUPLICATE, value);
+        self
+    }
+
+    /// Returns a copy of these flags with the `SUPPLEMENTARY` flag set or cleared.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record::Flags;
+    /// assert_eq!(Flags::default().with_supplementary(true), Flags::SUPPLEMENTARY);
+    /// assert!(Flags::SUPPLEMENTARY.with_supplementary(false).is_empty());
+    /// ```
+    pub fn with_supplementary(mut self, value: bool) -> Self {
+        self.set(Self::SUPPLEMENTARY, value);
+        self
+    }
 }
 
 impl From<u16> for Flags {
@@ -259,6 +427,46 @@ mod tests {
         assert!(Flags::SUPPLEMENTARY.is_supplementary());
     }
 
+    #[test]
+    fn test_with() {
+        let flags = Flags::default()
+            .with_segmented(true)
+            .with_properly_segmented(true)
+            .with_mate_reverse_complemented(true)
+            .with_first_segment(true);
+
+        assert_eq!(
+            u16::from(flags),
+            u16::from(
+                Flags::SEGMENTED
+                    | Flags::PROPERLY_SEGMENTED
+                    | Flags::MATE_REVERSE_COMPLEMENTED
+                    | Flags::FIRST_SEGMENT
+            )
+        );
+        assert_eq!(u16::from(flags), 0x63);
+
+        let flags = flags.with_first_segment(false).with_last_segment(true);
+        assert_eq!(
+            flags,
+            Flags::SEGMENTED
+                | Flags::PROPERLY_SEGMENTED
+                | Flags::MATE_REVERSE_COMPLEMENTED
+                | Flags::LAST_SEGMENT
+        );
+
+        let flags = Flags::default()
+            .with_unmapped(true)
+            .with_mate_unmapped(true)
+            .with_reverse_complemented(true)
+            .with_secondary(true)
+            .with_qc_fail(true)
+            .with_duplicate(true)
+            .with_supplementary(true);
+
+        assert_eq!(u16::from(flags), 0x0f1c);
+    }
+
     #[test]
     fn test_from_u16_for_flags() {
         assert_eq!(Flags::from(0x04), Flags::UNMAPPED);
@@ -5,7 +5,7 @@ mod cigar;
 mod convert;
 pub mod data;
 mod quality_scores;
-mod sequence;
+pub mod sequence;
 
 use std::io;
 
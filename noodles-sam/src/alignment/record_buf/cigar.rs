@@ -2,6 +2,8 @@
 
 use std::io;
 
+use noodles_core::Position;
+
 use crate::alignment::record::cigar::Op;
 
 /// An alignment record CIGAR operations buffer.
@@ -70,6 +72,87 @@ impl Cigar {
             .filter_map(|op| op.kind().consumes_read().then_some(op.len()))
             .sum()
     }
+
+    /// Returns an iterator over the CIGAR operations with their reference and read positions.
+    ///
+    /// Each item is the operation, the reference position at the start of the operation, and the
+    /// 1-based read position at the start of the operation. The given start is the alignment
+    /// start, i.e., the reference position of the first operation.
+    ///
+    /// Operations that do not consume the reference (e.g., `I`, `S`) or the read (e.g., `D`, `N`)
+    /// do not advance the respective position for the following operation. If a position
+    /// overflows, an error is returned for the next operation, and iteration stops.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::io;
+    ///
+    /// use noodles_core::Position;
+    /// use noodles_sam::alignment::{
+    ///     record::cigar::{op::Kind, Op},
+    ///     record_buf::Cigar,
+    /// };
+    ///
+    /// let cigar: Cigar = [
+    ///     Op::new(Kind::SoftClip, 2),
+    ///     Op::new(Kind::Match, 4),
+    ///     Op::new(Kind::Deletion, 1),
+    ///     Op::new(Kind::Match, 3),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let start = Position::try_from(8)?;
+    /// let positions: Vec<_> = cigar
+    ///     .iter_with_positions(start)
+    ///     .map(|result| {
+    ///         result.map(|(_, reference_position, read_position)| {
+    ///             (usize::from(reference_position), usize::from(read_position))
+    ///         })
+    ///     })
+    ///     .collect::<io::Result<_>>()?;
+    ///
+    /// assert_eq!(positions, [(8, 1), (8, 3), (12, 7), (13, 7)]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn iter_with_positions(
+        &self,
+        start: Position,
+    ) -> impl Iterator<Item = io::Result<(Op, Position, Position)>> + '_ {
+        let mut reference_position = Some(start);
+        let mut read_position = Some(Position::MIN);
+        let mut is_done = false;
+
+        self.0.iter().copied().map_while(move |op| {
+            if is_done {
+                return None;
+            }
+
+            let (Some(current_reference_position), Some(current_read_position)) =
+                (reference_position, read_position)
+            else {
+                is_done = true;
+
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "position overflow",
+                )));
+            };
+
+            let kind = op.kind();
+
+            if kind.consumes_reference() {
+                reference_position = current_reference_position.checked_add(op.len());
+            }
+
+            if kind.consumes_read() {
+                read_position = current_read_position.checked_add(op.len());
+            }
+
+            Some(Ok((op, current_reference_position, current_read_position)))
+        })
+    }
 }
 
 impl crate::alignment::record::Cigar for Cigar {
@@ -137,3 +220,82 @@ impl From<Cigar> for Vec<Op> {
         cigar.0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::alignment::record::cigar::op::Kind;
+
+    #[test]
+    fn test_iter_with_positions() -> Result<(), Box<dyn std::error::Error>> {
+        let cigar: Cigar = [
+            Op::new(Kind::SoftClip, 5),
+            Op::new(Kind::Match, 10),
+            Op::new(Kind::Insertion, 2),
+            Op::new(Kind::Match, 10),
+        ]
+        .into_iter()
+        .collect();
+
+        let start = Position::try_from(100)?;
+        let actual: Vec<_> = cigar
+            .iter_with_positions(start)
+            .collect::<io::Result<_>>()?;
+
+        let expected = [
+            (
+                Op::new(Kind::SoftClip, 5),
+                Position::try_from(100)?,
+                Position::try_from(1)?,
+            ),
+            (
+                Op::new(Kind::Match, 10),
+                Position::try_from(100)?,
+                Position::try_from(6)?,
+            ),
+            (
+                Op::new(Kind::Insertion, 2),
+                Position::try_from(110)?,
+                Position::try_from(16)?,
+            ),
+            (
+                Op::new(Kind::Match, 10),
+                Position::try_from(110)?,
+                Position::try_from(18)?,
+            ),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_iter_with_positions_with_overflow(
+    ) -> Result<(), noodles_core::position::TryFromIntError> {
+        let cigar: Cigar = [
+            Op::new(Kind::Match, 1),
+            Op::new(Kind::Match, 1),
+            Op::new(Kind::Match, 1),
+        ]
+        .into_iter()
+        .collect();
+
+        let start = Position::try_from(usize::MAX)?;
+        let mut iter = cigar.iter_with_positions(start);
+
+        assert!(matches!(
+            iter.next(),
+            Some(Ok((_, reference_position, _))) if reference_position == start
+        ));
+
+        assert!(matches!(
+            iter.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(iter.next().is_none());
+
+        Ok(())
+    }
+}
@@ -103,6 +103,139 @@ impl Value {
                 | Self::UInt32(_)
         )
     }
+
+    /// Returns the values if the value is an 8-bit integer array (`B:c`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::Int8(vec![-1i8, 0, 1]));
+    /// assert_eq!(value.as_int8_array(), Some(&[-1, 0, 1][..]));
+    ///
+    /// assert!(Value::from(vec![0.0f32]).as_int8_array().is_none());
+    /// ```
+    pub fn as_int8_array(&self) -> Option<&[i8]> {
+        match self {
+            Self::Array(Array::Int8(values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is an 8-bit unsigned integer array (`B:C`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::UInt8(vec![0u8, 1]));
+    /// assert_eq!(value.as_uint8_array(), Some(&[0, 1][..]));
+    ///
+    /// assert!(Value::from(vec![0.0f32]).as_uint8_array().is_none());
+    /// ```
+    pub fn as_uint8_array(&self) -> Option<&[u8]> {
+        match self {
+            Self::Array(Array::UInt8(values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a 16-bit integer array (`B:s`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::Int16(vec![-1i16, 0, 1]));
+    /// assert_eq!(value.as_int16_array(), Some(&[-1, 0, 1][..]));
+    ///
+    /// assert!(Value::from(vec![0.0f32]).as_int16_array().is_none());
+    /// ```
+    pub fn as_int16_array(&self) -> Option<&[i16]> {
+        match self {
+            Self::Array(Array::Int16(values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a 16-bit unsigned integer array (`B:S`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::UInt16(vec![0u16, 1]));
+    /// assert_eq!(value.as_uint16_array(), Some(&[0, 1][..]));
+    ///
+    /// assert!(Value::from(vec![0.0f32]).as_uint16_array().is_none());
+    /// ```
+    pub fn as_uint16_array(&self) -> Option<&[u16]> {
+        match self {
+            Self::Array(Array::UInt16(values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a 32-bit integer array (`B:i`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::Int32(vec![-1i32, 0, 1]));
+    /// assert_eq!(value.as_int32_array(), Some(&[-1, 0, 1][..]));
+    ///
+    /// assert!(Value::from(vec![0.0f32]).as_int32_array().is_none());
+    /// ```
+    pub fn as_int32_array(&self) -> Option<&[i32]> {
+        match self {
+            Self::Array(Array::Int32(values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a 32-bit unsigned integer array (`B:I`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::UInt32(vec![0u32, 1]));
+    /// assert_eq!(value.as_uint32_array(), Some(&[0, 1][..]));
+    ///
+    /// assert!(Value::from(vec![0.0f32]).as_uint32_array().is_none());
+    /// ```
+    pub fn as_uint32_array(&self) -> Option<&[u32]> {
+        match self {
+            Self::Array(Array::UInt32(values)) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the values if the value is a single-precision floating-point array (`B:f`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::data::field::{value::Array, Value};
+    ///
+    /// let value = Value::Array(Array::Float(vec![0.0f32, 1.5]));
+    /// assert_eq!(value.as_float_array(), Some(&[0.0, 1.5][..]));
+    ///
+    /// assert!(Value::from(vec![0i32]).as_float_array().is_none());
+    /// ```
+    pub fn as_float_array(&self) -> Option<&[f32]> {
+        match self {
+            Self::Array(Array::Float(values)) => Some(values),
+            _ => None,
+        }
+    }
 }
 
 impl From<i8> for Value {
@@ -284,6 +417,22 @@ mod tests {
         assert_eq!(Value::Array(Array::UInt8(vec![0])).ty(), Type::Array);
     }
 
+    #[test]
+    fn test_as_array() {
+        let value = Value::Array(Array::Int32(vec![-8, 0, 13]));
+        assert_eq!(value.as_int32_array(), Some(&[-8, 0, 13][..]));
+        assert!(value.as_uint32_array().is_none());
+        assert!(value.as_int8_array().is_none());
+        assert!(value.as_float_array().is_none());
+
+        let value = Value::Array(Array::Float(vec![0.0, 1.5]));
+        assert_eq!(value.as_float_array(), Some(&[0.0, 1.5][..]));
+        assert!(value.as_int32_array().is_none());
+
+        assert!(Value::Int32(8).as_int32_array().is_none());
+        assert!(Value::from("noodles").as_uint8_array().is_none());
+    }
+
     #[test]
     fn test_from_i8_for_value() {
         assert_eq!(Value::from(i8::MIN), Value::Int8(i8::MIN));
@@ -11,6 +11,100 @@ impl QualityScores {
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
     }
+
+    /// Returns the mean score.
+    ///
+    /// This returns `None` if there are no scores, e.g., when the quality scores are missing
+    /// (`*`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::QualityScores;
+    ///
+    /// let quality_scores = QualityScores::from(vec![45, 35, 43, 50]);
+    /// assert_eq!(quality_scores.mean(), Some(43.25));
+    ///
+    /// assert!(QualityScores::default().mean().is_none());
+    /// ```
+    pub fn mean(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let sum: u64 = self.0.iter().copied().map(u64::from).sum();
+        Some(sum as f64 / self.0.len() as f64)
+    }
+
+    /// Returns the median score.
+    ///
+    /// For an even number of scores, this is the mean of the two middle scores. This returns
+    /// `None` if there are no scores, e.g., when the quality scores are missing (`*`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::QualityScores;
+    ///
+    /// let quality_scores = QualityScores::from(vec![45, 35, 43, 50]);
+    /// assert_eq!(quality_scores.median(), Some(44.0));
+    ///
+    /// assert!(QualityScores::default().median().is_none());
+    /// ```
+    pub fn median(&self) -> Option<f64> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let mut scores = self.0.clone();
+        scores.sort_unstable();
+
+        let mid = scores.len() / 2;
+
+        if scores.len() % 2 == 0 {
+            Some((f64::from(scores[mid - 1]) + f64::from(scores[mid])) / 2.0)
+        } else {
+            Some(f64::from(scores[mid]))
+        }
+    }
+
+    /// Returns the minimum score.
+    ///
+    /// This returns `None` if there are no scores, e.g., when the quality scores are missing
+    /// (`*`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::QualityScores;
+    ///
+    /// let quality_scores = QualityScores::from(vec![45, 35, 43, 50]);
+    /// assert_eq!(quality_scores.min(), Some(35));
+    ///
+    /// assert!(QualityScores::default().min().is_none());
+    /// ```
+    pub fn min(&self) -> Option<u8> {
+        self.0.iter().copied().min()
+    }
+
+    /// Returns the maximum score.
+    ///
+    /// This returns `None` if there are no scores, e.g., when the quality scores are missing
+    /// (`*`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::QualityScores;
+    ///
+    /// let quality_scores = QualityScores::from(vec![45, 35, 43, 50]);
+    /// assert_eq!(quality_scores.max(), Some(50));
+    ///
+    /// assert!(QualityScores::default().max().is_none());
+    /// ```
+    pub fn max(&self) -> Option<u8> {
+        self.0.iter().copied().max()
+    }
 }
 
 impl AsRef<[u8]> for QualityScores {
@@ -70,3 +164,27 @@ impl crate::alignment::record::QualityScores for &QualityScores {
         Box::new(self.0.iter().copied())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary_statistics() {
+        let quality_scores = QualityScores::from(vec![45, 35, 43, 50, 30]);
+        assert_eq!(quality_scores.mean(), Some(40.6));
+        assert_eq!(quality_scores.median(), Some(43.0));
+        assert_eq!(quality_scores.min(), Some(30));
+        assert_eq!(quality_scores.max(), Some(50));
+
+        let quality_scores = QualityScores::from(vec![45, 35, 43, 50]);
+        assert_eq!(quality_scores.mean(), Some(43.25));
+        assert_eq!(quality_scores.median(), Some(44.0));
+
+        let quality_scores = QualityScores::default();
+        assert!(quality_scores.mean().is_none());
+        assert!(quality_scores.median().is_none());
+        assert!(quality_scores.min().is_none());
+        assert!(quality_scores.max().is_none());
+    }
+}
@@ -1,12 +1,72 @@
-use std::ops::{Index, IndexMut};
+//! Alignment record sequence buffer.
+
+use std::{
+    error, fmt,
+    ops::{Index, IndexMut},
+};
 
 use noodles_core::position::SequenceIndex;
 
+/// An error returned when raw bases fail to convert to a sequence.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FromBasesError {
+    /// A base is invalid.
+    InvalidBase {
+        /// The index of the invalid base.
+        index: usize,
+        /// The invalid base.
+        base: u8,
+    },
+}
+
+impl error::Error for FromBasesError {}
+
+impl fmt::Display for FromBasesError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidBase { index, base } => {
+                write!(f, "invalid base at index {index}: {:?}", char::from(*base))
+            }
+        }
+    }
+}
+
 /// An alignment record sequence buffer.
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Sequence(Vec<u8>);
 
 impl Sequence {
+    /// Creates a sequence from raw bases.
+    ///
+    /// Each base must be an IUPAC nucleotide code (`ACGTURYSWKMBDHVN`, in either case), `=`, `.`,
+    /// or `*`.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned with the first invalid base.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::alignment::record_buf::{sequence::FromBasesError, Sequence};
+    ///
+    /// let sequence = Sequence::from_bases(b"ACGTN")?;
+    /// assert_eq!(sequence.as_ref(), b"ACGTN");
+    ///
+    /// assert_eq!(
+    ///     Sequence::from_bases(b"AC!T"),
+    ///     Err(FromBasesError::InvalidBase { index: 2, base: b'!' })
+    /// );
+    /// # Ok::<_, FromBasesError>(())
+    /// ```
+    pub fn from_bases(bases: &[u8]) -> Result<Self, FromBasesError> {
+        if let Some((index, &base)) = bases.iter().enumerate().find(|(_, &b)| !is_valid_base(b)) {
+            return Err(FromBasesError::InvalidBase { index, base });
+        }
+
+        Ok(Self(bases.to_vec()))
+    }
+
     /// Returns whether there are any bases.
     pub fn is_empty(&self) -> bool {
         self.0.is_empty()
@@ -114,3 +174,60 @@ impl crate::alignment::record::Sequence for &Sequence {
         Box::new(self.0.iter().copied())
     }
 }
+
+fn is_valid_base(b: u8) -> bool {
+    matches!(
+        b.to_ascii_uppercase(),
+        b'A' | b'C'
+            | b'G'
+            | b'T'
+            | b'U'
+            | b'R'
+            | b'Y'
+            | b'S'
+            | b'W'
+            | b'K'
+            | b'M'
+            | b'B'
+            | b'D'
+            | b'H'
+            | b'V'
+            | b'N'
+            | b'='
+            | b'.'
+            | b'*'
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_bases() {
+        assert_eq!(Sequence::from_bases(b"ACGTN"), Ok(Sequence::from(b"ACGTN")));
+
+        assert_eq!(
+            Sequence::from_bases(b"acgtn=.*"),
+            Ok(Sequence::from(b"acgtn=.*"))
+        );
+
+        assert_eq!(Sequence::from_bases(b""), Ok(Sequence::default()));
+
+        assert_eq!(
+            Sequence::from_bases(b"ACXGT"),
+            Err(FromBasesError::InvalidBase {
+                index: 2,
+                base: b'X'
+            })
+        );
+
+        assert_eq!(
+            Sequence::from_bases(&[b'A', 0x00]),
+            Err(FromBasesError::InvalidBase {
+                index: 1,
+                base: 0x00
+            })
+        );
+    }
+}
@@ -225,6 +225,71 @@ impl Header {
         &mut self.reference_sequences
     }
 
+    /// Returns the length of the reference sequence with the given name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?)
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(header.reference_sequence_len(b"sq0"), Some(13));
+    /// assert!(header.reference_sequence_len(b"sq1").is_none());
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn reference_sequence_len(&self, name: &[u8]) -> Option<usize> {
+        self.reference_sequences
+            .get(name)
+            .map(|reference_sequence| reference_sequence.length().get())
+    }
+
+    /// Returns the sum of the lengths of all reference sequences.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::num::NonZeroUsize;
+    ///
+    /// use noodles_sam::{
+    ///     self as sam,
+    ///     header::record::value::{map::ReferenceSequence, Map},
+    /// };
+    ///
+    /// let header = sam::Header::default();
+    /// assert_eq!(header.total_length(), 0);
+    ///
+    /// let header = sam::Header::builder()
+    ///     .add_reference_sequence(
+    ///         "sq0",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?)
+    ///     )
+    ///     .add_reference_sequence(
+    ///         "sq1",
+    ///         Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?)
+    ///     )
+    ///     .build();
+    ///
+    /// assert_eq!(header.total_length(), 21);
+    /// # Ok::<(), Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn total_length(&self) -> usize {
+        self.reference_sequences
+            .values()
+            .map(|reference_sequence| reference_sequence.length().get())
+            .sum()
+    }
+
     /// Returns the SAM header read groups.
     ///
     /// # Examples
@@ -434,3 +499,38 @@ impl FromStr for Header {
         parser::parse(s)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use super::*;
+    use crate::header::record::value::{map::ReferenceSequence, Map};
+
+    #[test]
+    fn test_reference_sequence_len_and_total_length() -> Result<(), Box<dyn std::error::Error>> {
+        let header = Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(8)?),
+            )
+            .add_reference_sequence(
+                "sq1",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(13)?),
+            )
+            .add_reference_sequence(
+                "sq2",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(21)?),
+            )
+            .build();
+
+        assert_eq!(header.reference_sequence_len(b"sq0"), Some(8));
+        assert_eq!(header.reference_sequence_len(b"sq1"), Some(13));
+        assert_eq!(header.reference_sequence_len(b"sq2"), Some(21));
+        assert!(header.reference_sequence_len(b"sq3").is_none());
+
+        assert_eq!(header.total_length(), 42);
+
+        Ok(())
+    }
+}
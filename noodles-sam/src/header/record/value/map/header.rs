@@ -6,7 +6,7 @@ pub mod sort_order;
 pub mod tag;
 pub mod version;
 
-pub use self::{tag::Tag, version::Version};
+pub use self::{group_order::GroupOrder, sort_order::SortOrder, tag::Tag, version::Version};
 
 use self::builder::Builder;
 use super::{Inner, Map, OtherFields};
@@ -74,6 +74,70 @@ impl Map<Header> {
     pub fn version_mut(&mut self) -> &mut Version {
         &mut self.inner.version
     }
+
+    /// Returns the sort order (`SO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{self, header::SortOrder}, Map};
+    /// let mut header = Map::<map::Header>::default();
+    /// assert!(header.sort_order().is_none());
+    /// header.set_sort_order(SortOrder::Coordinate);
+    /// assert_eq!(header.sort_order(), Some(SortOrder::Coordinate));
+    /// ```
+    pub fn sort_order(&self) -> Option<SortOrder> {
+        self.other_fields
+            .get(&tag::SORT_ORDER)
+            .map(|value| SortOrder::from(value.as_ref()))
+    }
+
+    /// Sets the sort order (`SO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{self, header::SortOrder}, Map};
+    /// let mut header = Map::<map::Header>::default();
+    /// header.set_sort_order(SortOrder::QueryName);
+    /// assert_eq!(header.sort_order(), Some(SortOrder::QueryName));
+    /// ```
+    pub fn set_sort_order(&mut self, sort_order: SortOrder) {
+        self.other_fields
+            .insert(tag::SORT_ORDER, sort_order.as_ref().into());
+    }
+
+    /// Returns the group order (`GO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{self, header::GroupOrder}, Map};
+    /// let mut header = Map::<map::Header>::default();
+    /// assert!(header.group_order().is_none());
+    /// header.set_group_order(GroupOrder::Query);
+    /// assert_eq!(header.group_order(), Some(GroupOrder::Query));
+    /// ```
+    pub fn group_order(&self) -> Option<GroupOrder> {
+        self.other_fields
+            .get(&tag::GROUP_ORDER)
+            .map(|value| GroupOrder::from(value.as_ref()))
+    }
+
+    /// Sets the group order (`GO`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::header::record::value::{map::{self, header::GroupOrder}, Map};
+    /// let mut header = Map::<map::Header>::default();
+    /// header.set_group_order(GroupOrder::Reference);
+    /// assert_eq!(header.group_order(), Some(GroupOrder::Reference));
+    /// ```
+    pub fn set_group_order(&mut self, group_order: GroupOrder) {
+        self.other_fields
+            .insert(tag::GROUP_ORDER, group_order.as_ref().into());
+    }
 }
 
 #[cfg(test)]
@@ -85,4 +149,33 @@ mod tests {
         let header = Map::<Header>::default();
         assert_eq!(header.version(), Version::default());
     }
+
+    #[test]
+    fn test_sort_order_and_group_order_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::Writer;
+
+        let src = "@HD\tVN:1.6\tSO:coordinate\n";
+        let header: crate::Header = src.parse()?;
+
+        let hd = header.header().ok_or("missing @HD")?;
+        assert_eq!(hd.sort_order(), Some(SortOrder::Coordinate));
+        assert!(hd.group_order().is_none());
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        assert_eq!(writer.get_ref(), src.as_bytes());
+
+        let src = "@HD\tVN:1.6\tSO:nd\tGO:query\n";
+        let header: crate::Header = src.parse()?;
+
+        let hd = header.header().ok_or("missing @HD")?;
+        assert_eq!(hd.sort_order(), Some(SortOrder::Other(String::from("nd"))));
+        assert_eq!(hd.group_order(), Some(GroupOrder::Query));
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        assert_eq!(writer.get_ref(), src.as_bytes());
+
+        Ok(())
+    }
 }
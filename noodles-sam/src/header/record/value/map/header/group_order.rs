@@ -8,3 +8,62 @@ pub const QUERY: &[u8] = b"query";
 
 /// Alignments are grouped by reference sequence and position (`reference`).
 pub const REFERENCE: &[u8] = b"reference";
+
+/// A SAM header header group order (`GO`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum GroupOrder {
+    /// Records are not grouped (`none`).
+    None,
+    /// Records are grouped by name (`query`).
+    Query,
+    /// Alignments are grouped by reference sequence and position (`reference`).
+    Reference,
+    /// Any other group order.
+    Other(String),
+}
+
+impl AsRef<[u8]> for GroupOrder {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::None => NONE,
+            Self::Query => QUERY,
+            Self::Reference => REFERENCE,
+            Self::Other(s) => s.as_bytes(),
+        }
+    }
+}
+
+impl From<&[u8]> for GroupOrder {
+    fn from(src: &[u8]) -> Self {
+        match src {
+            NONE => Self::None,
+            QUERY => Self::Query,
+            REFERENCE => Self::Reference,
+            _ => Self::Other(String::from_utf8_lossy(src).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_u8_slice() {
+        assert_eq!(GroupOrder::None.as_ref(), b"none");
+        assert_eq!(GroupOrder::Query.as_ref(), b"query");
+        assert_eq!(GroupOrder::Reference.as_ref(), b"reference");
+        assert_eq!(GroupOrder::Other(String::from("nd")).as_ref(), b"nd");
+    }
+
+    #[test]
+    fn test_from_u8_slice() {
+        assert_eq!(GroupOrder::from(&b"none"[..]), GroupOrder::None);
+        assert_eq!(GroupOrder::from(&b"query"[..]), GroupOrder::Query);
+        assert_eq!(GroupOrder::from(&b"reference"[..]), GroupOrder::Reference);
+        assert_eq!(
+            GroupOrder::from(&b"nd"[..]),
+            GroupOrder::Other(String::from("nd"))
+        );
+    }
+}
@@ -11,3 +11,68 @@ pub const QUERY_NAME: &[u8] = b"queryname";
 
 /// Records are sorted by reference sequence and position (`coordinate`).
 pub const COORDINATE: &[u8] = b"coordinate";
+
+/// A SAM header header sort order (`SO`).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub enum SortOrder {
+    /// The record order is unknown (`unknown`).
+    Unknown,
+    /// Records are not sorted (`unsorted`).
+    Unsorted,
+    /// Records are sorted by name (`queryname`).
+    QueryName,
+    /// Records are sorted by reference sequence and position (`coordinate`).
+    Coordinate,
+    /// Any other sort order.
+    Other(String),
+}
+
+impl AsRef<[u8]> for SortOrder {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::Unknown => UNKNOWN,
+            Self::Unsorted => UNSORTED,
+            Self::QueryName => QUERY_NAME,
+            Self::Coordinate => COORDINATE,
+            Self::Other(s) => s.as_bytes(),
+        }
+    }
+}
+
+impl From<&[u8]> for SortOrder {
+    fn from(src: &[u8]) -> Self {
+        match src {
+            UNKNOWN => Self::Unknown,
+            UNSORTED => Self::Unsorted,
+            QUERY_NAME => Self::QueryName,
+            COORDINATE => Self::Coordinate,
+            _ => Self::Other(String::from_utf8_lossy(src).into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_as_ref_u8_slice() {
+        assert_eq!(SortOrder::Unknown.as_ref(), b"unknown");
+        assert_eq!(SortOrder::Unsorted.as_ref(), b"unsorted");
+        assert_eq!(SortOrder::QueryName.as_ref(), b"queryname");
+        assert_eq!(SortOrder::Coordinate.as_ref(), b"coordinate");
+        assert_eq!(SortOrder::Other(String::from("nd")).as_ref(), b"nd");
+    }
+
+    #[test]
+    fn test_from_u8_slice() {
+        assert_eq!(SortOrder::from(&b"unknown"[..]), SortOrder::Unknown);
+        assert_eq!(SortOrder::from(&b"unsorted"[..]), SortOrder::Unsorted);
+        assert_eq!(SortOrder::from(&b"queryname"[..]), SortOrder::QueryName);
+        assert_eq!(SortOrder::from(&b"coordinate"[..]), SortOrder::Coordinate);
+        assert_eq!(
+            SortOrder::from(&b"nd"[..]),
+            SortOrder::Other(String::from("nd"))
+        );
+    }
+}
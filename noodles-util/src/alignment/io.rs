@@ -1,5 +1,6 @@
 //! Alignment format I/O.
 
+mod bed;
 mod compression_method;
 mod format;
 pub mod indexed_reader;
@@ -7,6 +8,6 @@ pub mod reader;
 pub mod writer;
 
 pub use self::{
-    compression_method::CompressionMethod, format::Format, indexed_reader::IndexedReader,
-    reader::Reader, writer::Writer,
+    bed::write_bed, compression_method::CompressionMethod, format::Format,
+    indexed_reader::IndexedReader, reader::Reader, writer::Writer,
 };
@@ -0,0 +1,101 @@
+use std::io::{self, Write};
+
+use noodles_sam::{self as sam, alignment::Record};
+
+/// Writes the intervals of alignment records as BED3 lines.
+///
+/// Each record is written as `<chrom>\t<start>\t<end>`, where the interval is converted from the
+/// 1-based, inclusive alignment start and end to 0-based, half-open coordinates.
+///
+/// Records without a reference sequence or alignment start (e.g., unmapped records) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_sam as sam;
+/// use noodles_util::alignment;
+///
+/// let header = sam::Header::default();
+/// let records = Vec::new();
+///
+/// let mut buf = Vec::new();
+/// alignment::io::write_bed(&mut buf, &header, records)?;
+/// assert!(buf.is_empty());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_bed<W, I>(writer: &mut W, header: &sam::Header, records: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = io::Result<Box<dyn Record>>>,
+{
+    use crate::bed::write_interval;
+
+    for result in records {
+        let record = result?;
+
+        let Some((reference_sequence_name, _)) = record.reference_sequence(header).transpose()?
+        else {
+            continue;
+        };
+
+        let (Some(start), Some(end)) = (
+            record.alignment_start().transpose()?,
+            record.alignment_end().transpose()?,
+        ) else {
+            continue;
+        };
+
+        write_interval(writer, reference_sequence_name, start, end)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::num::NonZeroUsize;
+
+    use noodles_core::Position;
+    use sam::{
+        alignment::{
+            record::cigar::{op::Kind, Op},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    #[test]
+    fn test_write_bed() -> Result<(), Box<dyn std::error::Error>> {
+        let header = sam::Header::builder()
+            .add_reference_sequence(
+                "sq0",
+                Map::<ReferenceSequence>::new(NonZeroUsize::try_from(1000)?),
+            )
+            .build();
+
+        let records = [
+            RecordBuf::builder()
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(100)?)
+                .set_cigar([Op::new(Kind::Match, 3)].into_iter().collect())
+                .build(),
+            RecordBuf::default(),
+        ];
+
+        let mut buf = Vec::new();
+        write_bed(
+            &mut buf,
+            &header,
+            records
+                .into_iter()
+                .map(|record| Ok(Box::new(record) as Box<dyn Record>)),
+        )?;
+
+        assert_eq!(buf, b"sq0\t99\t102\n");
+
+        Ok(())
+    }
+}
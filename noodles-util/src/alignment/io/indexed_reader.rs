@@ -1,15 +1,21 @@
 //! Indexed alignment reader.
 
 mod builder;
+mod index;
+mod par_query;
 
-pub use self::builder::Builder;
+pub use self::{builder::Builder, index::Index, par_query::par_query};
 
-use std::io::{self, Read, Seek};
+use std::{
+    io::{self, Read, Seek, SeekFrom},
+    iter,
+};
 
 use noodles_bam as bam;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
 use noodles_cram as cram;
+use noodles_csi as csi;
 use noodles_sam::{self as sam, alignment::Record};
 
 /// An indexed alignment reader.
@@ -47,6 +53,34 @@ where
         }
     }
 
+    /// Returns the associated index.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment::{self, io::indexed_reader::Index};
+    ///
+    /// let reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.bam")?;
+    ///
+    /// match reader.index() {
+    ///     Index::Csi(index) => {
+    ///         let _reference_sequence_count = index.reference_sequences().count();
+    ///     }
+    ///     Index::Crai(index) => {
+    ///         let _record_count = index.len();
+    ///     }
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn index(&self) -> Index<'_> {
+        match self {
+            Self::Sam(reader) => Index::Csi(reader.index()),
+            Self::Bam(reader) => Index::Csi(reader.index()),
+            Self::Cram(reader) => Index::Crai(reader.index()),
+        }
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     ///
     /// # Examples
@@ -93,6 +127,53 @@ where
 
         records
     }
+
+    /// Returns an iterator over owned records starting from the current stream position.
+    ///
+    /// Unlike [`Self::records`], each record is fully decoded into an alignment record buffer
+    /// ([`sam::alignment::RecordBuf`]). The records do not borrow the reader and can be sent
+    /// across threads.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::{sync::mpsc, thread};
+    ///
+    /// use noodles_util::alignment;
+    ///
+    /// let mut reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.bam")?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let (tx, rx) = mpsc::channel();
+    ///
+    /// let handle = thread::spawn(move || {
+    ///     for _record in rx {
+    ///         // ...
+    ///     }
+    /// });
+    ///
+    /// for result in reader.records_owned(&header) {
+    ///     let record = result?;
+    ///     tx.send(record).unwrap();
+    /// }
+    ///
+    /// drop(tx);
+    /// handle.join().unwrap();
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn records_owned<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+    ) -> impl Iterator<Item = io::Result<Box<dyn Record + Send>>> + 'r {
+        self.records(header).map(move |result| {
+            result.and_then(|record| {
+                sam::alignment::RecordBuf::try_from_alignment_record(header, &record)
+                    .map(|record_buf| Box::new(record_buf) as Box<dyn Record + Send>)
+            })
+        })
+    }
 }
 
 impl<R> IndexedReader<R>
@@ -157,4 +238,655 @@ where
 
         Ok(records)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Overlapping and adjacent regions are first merged, and each merged region is queried in
+    /// reference sequence and coordinate order. A record that intersects more than one merged
+    /// region is only returned once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment;
+    ///
+    /// let mut reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.bam")?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?];
+    /// let query = reader.query_regions(&header, &regions)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+        regions: &[Region],
+    ) -> io::Result<impl Iterator<Item = io::Result<Box<dyn Record>>> + 'r> {
+        let reference_sequence_id = |region: &Region| {
+            header
+                .reference_sequences()
+                .get_index_of(region.name())
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        "invalid reference sequence name",
+                    )
+                })
+        };
+
+        let regions = crate::regions::merge(regions, |name| {
+            header.reference_sequences().get_index_of(name)
+        })?;
+
+        let records: Box<dyn Iterator<Item = io::Result<Box<dyn Record>>>> = match self {
+            Self::Sam(reader) => {
+                let chunks =
+                    crate::regions::query_chunks(reader.index(), &regions, reference_sequence_id)?;
+
+                let mut reader =
+                    sam::io::Reader::new(csi::io::Query::new(reader.get_mut(), chunks));
+
+                Box::new(iter::from_fn(move || {
+                    let mut record = sam::Record::default();
+
+                    match reader.read_record(&mut record) {
+                        Ok(0) => None,
+                        Ok(_) => Some(Ok(Box::new(record) as Box<dyn Record>)),
+                        Err(e) => Some(Err(e)),
+                    }
+                }))
+            }
+            Self::Bam(reader) => {
+                let chunks =
+                    crate::regions::query_chunks(reader.index(), &regions, reference_sequence_id)?;
+
+                let mut reader =
+                    bam::io::Reader::from(csi::io::Query::new(reader.get_mut(), chunks));
+
+                Box::new(iter::from_fn(move || {
+                    let mut record = bam::Record::default();
+
+                    match reader.read_record(&mut record) {
+                        Ok(0) => None,
+                        Ok(_) => Some(Ok(Box::new(record) as Box<dyn Record>)),
+                        Err(e) => Some(Err(e)),
+                    }
+                }))
+            }
+            Self::Cram(reader) => {
+                let regions = regions
+                    .iter()
+                    .map(|region| reference_sequence_id(region).map(|i| (i, region.interval())))
+                    .collect::<io::Result<Vec<_>>>()?;
+
+                let mut offsets: Vec<_> = reader
+                    .index()
+                    .iter()
+                    .filter(|index_record| {
+                        let (Some(i), Some(start)) = (
+                            index_record.reference_sequence_id(),
+                            index_record.alignment_start(),
+                        ) else {
+                            return false;
+                        };
+
+                        let end = start
+                            .checked_add(index_record.alignment_span().saturating_sub(1))
+                            .unwrap_or(start);
+
+                        regions.iter().any(|(region_i, interval)| {
+                            *region_i == i && interval.intersects((start..=end).into())
+                        })
+                    })
+                    .map(|index_record| index_record.offset())
+                    .collect();
+
+                offsets.sort_unstable();
+                offsets.dedup();
+
+                let mut offsets = offsets.into_iter();
+                let mut records = Vec::new().into_iter();
+
+                Box::new(iter::from_fn(move || loop {
+                    if let Some(record) = records.next() {
+                        return Some(
+                            cram::Record::try_into_alignment_record(record, header)
+                                .map(|record| Box::new(record) as Box<dyn Record>),
+                        );
+                    }
+
+                    let offset = offsets.next()?;
+
+                    let result = reader
+                        .get_mut()
+                        .seek(SeekFrom::Start(offset))
+                        .and_then(|_| reader.read_data_container())
+                        .and_then(|container| match container {
+                            Some(container) => {
+                                container.records(reader.reference_sequence_repository(), header)
+                            }
+                            None => Ok(Vec::new()),
+                        });
+
+                    match result {
+                        Ok(rs) => records = rs.into_iter(),
+                        Err(e) => return Some(Err(e)),
+                    }
+                }))
+            }
+        };
+
+        Ok(crate::regions::filter(records, move |record| {
+            let (Some(id), Some(start), Some(end)) = (
+                record.reference_sequence_id(header).transpose()?,
+                record.alignment_start().transpose()?,
+                record.alignment_end().transpose()?,
+            ) else {
+                return Ok(false);
+            };
+
+            let name = header
+                .reference_sequences()
+                .get_index(id)
+                .map(|(name, _)| name.as_ref())
+                .ok_or_else(|| {
+                    io::Error::new(io::ErrorKind::InvalidData, "invalid reference sequence ID")
+                })?;
+
+            Ok(crate::regions::intersects(
+                &regions,
+                name,
+                (start..=end).into(),
+            ))
+        }))
+    }
+
+    /// Returns an iterator of unmapped records after querying for the unmapped region.
+    ///
+    /// This is equivalent to querying for the `*` region, i.e., the unplaced, unmapped records at
+    /// the end of the file. Only records without a reference sequence ID are returned; placed
+    /// unmapped records are not included.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::alignment;
+    ///
+    /// let mut reader = alignment::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.bam")?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// for result in reader.query_unmapped(&header)? {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn query_unmapped<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h sam::Header,
+    ) -> io::Result<impl Iterator<Item = io::Result<Box<dyn Record>>> + 'r> {
+        let records: Box<dyn Iterator<Item = io::Result<Box<dyn Record>>>> = match self {
+            Self::Sam(reader) => {
+                let query = reader.query_unmapped()?;
+
+                Box::new(
+                    query.map(|result| result.map(|record| Box::new(record) as Box<dyn Record>)),
+                )
+            }
+            Self::Bam(reader) => {
+                let query = reader.query_unmapped()?;
+
+                Box::new(
+                    query.map(|result| result.map(|record| Box::new(record) as Box<dyn Record>)),
+                )
+            }
+            Self::Cram(reader) => {
+                let query = reader.query_unmapped(header)?;
+
+                Box::new(query.map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .try_into_alignment_record(header)
+                            .map(|alignment_record| {
+                                Box::new(alignment_record) as Box<dyn sam::alignment::Record>
+                            })
+                    })
+                }))
+            }
+        };
+
+        Ok(records.filter(move |result| {
+            result
+                .as_ref()
+                .map(|record| record.reference_sequence_id(header).is_none())
+                .unwrap_or(true)
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{io::Cursor, num::NonZeroUsize};
+
+    use noodles_core::Position;
+    use noodles_cram::crai;
+    use noodles_csi::binning_index::{index::reference_sequence::bin::Chunk, Indexer};
+    use noodles_fasta as fasta;
+    use sam::{
+        alignment::{
+            io::Write,
+            record::{
+                cigar::{op::Kind, Op},
+                Flags,
+            },
+            record_buf::{QualityScores, Sequence},
+            RecordBuf,
+        },
+        header::record::value::{map::ReferenceSequence, Map},
+    };
+
+    use super::*;
+
+    const SQ0_LN: NonZeroUsize = match NonZeroUsize::new(8) {
+        Some(length) => length,
+        None => unreachable!(),
+    };
+
+    fn build_reference_sequence_repository() -> fasta::Repository {
+        fasta::Repository::new(vec![fasta::Record::new(
+            fasta::record::Definition::new("sq0", None),
+            fasta::record::Sequence::from(b"TTCACCCA".to_vec()),
+        )])
+    }
+
+    fn build_records() -> Result<Vec<RecordBuf>, Box<dyn std::error::Error>> {
+        [("r0", 1, b"TTCA"), ("r1", 5, b"CCCA")]
+            .into_iter()
+            .map(|(name, start, sequence)| {
+                Ok(RecordBuf::builder()
+                    .set_name(name)
+                    .set_flags(Flags::empty())
+                    .set_reference_sequence_id(0)
+                    .set_alignment_start(Position::try_from(start)?)
+                    .set_cigar([Op::new(Kind::Match, 4)].into_iter().collect())
+                    .set_sequence(Sequence::from(sequence))
+                    .set_quality_scores(QualityScores::from(vec![45, 35, 43, 50]))
+                    .build())
+            })
+            .collect()
+    }
+
+    fn write_bam(
+        header: &sam::Header,
+        records: &[RecordBuf],
+    ) -> io::Result<(Vec<u8>, bam::bai::Index)> {
+        let mut writer = bam::io::Writer::new(Vec::new());
+        writer.write_header(header)?;
+
+        let mut indexer = Indexer::default();
+
+        for record in records {
+            let chunk_start = writer.get_ref().virtual_position();
+            writer.write_alignment_record(header, record)?;
+            let chunk_end = writer.get_ref().virtual_position();
+
+            let alignment_context = match (
+                record.reference_sequence_id(),
+                record.alignment_start(),
+                record.alignment_end(),
+            ) {
+                (Some(id), Some(start), Some(end)) => {
+                    Some((id, start, end, !record.flags().is_unmapped()))
+                }
+                _ => None,
+            };
+
+            indexer.add_record(alignment_context, Chunk::new(chunk_start, chunk_end))?;
+        }
+
+        let data = writer.into_inner().finish()?;
+        let index = indexer.build(header.reference_sequences().len());
+
+        Ok((data, index))
+    }
+
+    fn write_cram(
+        header: &sam::Header,
+        records: &[RecordBuf],
+    ) -> io::Result<(Vec<u8>, crai::Index)> {
+        let mut writer = cram::io::writer::Builder::default()
+            .set_reference_sequence_repository(build_reference_sequence_repository())
+            .build_with_writer(Vec::new());
+
+        writer.write_alignment_header(header)?;
+
+        for record in records {
+            writer.write_alignment_record(header, record)?;
+        }
+
+        writer.try_finish(header)?;
+
+        let data = writer.get_ref().clone();
+
+        let mut reader = cram::io::Reader::new(Cursor::new(&data[..]));
+        reader.read_header()?;
+        let offset = reader.position()?;
+
+        let index = vec![crai::Record::new(
+            Some(0),
+            Some(Position::MIN),
+            SQ0_LN.get(),
+            offset,
+            0,
+            0,
+        )];
+
+        Ok((data, index))
+    }
+
+    #[test]
+    fn test_records_owned() -> Result<(), Box<dyn std::error::Error>> {
+        use std::{sync::mpsc, thread};
+
+        use crate::alignment::io::indexed_reader::Builder;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let records = build_records()?;
+
+        let (data, index) = write_bam(&header, &records)?;
+        let mut reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        let header = reader.read_header()?;
+
+        let (tx, rx) = mpsc::channel();
+
+        let handle = thread::spawn(move || {
+            rx.into_iter()
+                .map(|record: Box<dyn Record + Send>| record.name().map(|name| name.to_vec()))
+                .collect::<Vec<_>>()
+        });
+
+        for result in reader.records_owned(&header) {
+            let record = result?;
+            tx.send(record)?;
+        }
+
+        drop(tx);
+
+        let names = handle.join().expect("thread panicked");
+        assert_eq!(names, [Some(b"r0".to_vec()), Some(b"r1".to_vec())]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::io::indexed_reader::Builder;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let records = build_records()?;
+        let region = "sq0:5-8".parse()?;
+
+        let (data, index) = write_bam(&header, &records)?;
+        let bam_reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        let (data, index) = write_cram(&header, &records)?;
+        let cram_reader = Builder::default()
+            .set_reference_sequence_repository(build_reference_sequence_repository())
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        for mut reader in [bam_reader, cram_reader] {
+            let header = reader.read_header()?;
+
+            let names: Vec<_> = reader
+                .query(&header, &region)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .name()
+                            .map(|name| name.to_vec())
+                            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(names, [b"r1".to_vec()]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_regions() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::io::indexed_reader::Builder;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let records = build_records()?;
+
+        // sq0:1-1 and sq0:1-2 overlap and are merged. r0 (sq0:1-4) intersects both sq0:1-2 and
+        // sq0:4-6.
+        let regions = ["sq0:4-6".parse()?, "sq0:1-1".parse()?, "sq0:1-2".parse()?];
+
+        let (data, index) = write_bam(&header, &records)?;
+        let bam_reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        let (data, index) = write_cram(&header, &records)?;
+        let cram_reader = Builder::default()
+            .set_reference_sequence_repository(build_reference_sequence_repository())
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        for mut reader in [bam_reader, cram_reader] {
+            let header = reader.read_header()?;
+
+            let names: Vec<_> = reader
+                .query_regions(&header, &regions)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .name()
+                            .map(|name| name.to_vec())
+                            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(names, [b"r0".to_vec(), b"r1".to_vec()]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_query_unmapped() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::io::indexed_reader::Builder;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let mut records = build_records()?;
+
+        // placed unmapped
+        records.push(
+            RecordBuf::builder()
+                .set_name("r2")
+                .set_flags(Flags::UNMAPPED)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(Position::try_from(5)?)
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+                .build(),
+        );
+
+        // unplaced unmapped
+        records.push(
+            RecordBuf::builder()
+                .set_name("r3")
+                .set_flags(Flags::UNMAPPED)
+                .set_sequence(Sequence::from(b"ACGT"))
+                .set_quality_scores(QualityScores::from(vec![8, 13, 21, 34]))
+                .build(),
+        );
+
+        let (data, index) = write_bam(&header, &records)?;
+        let bam_reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        let (data, mut index) = write_cram(&header, &records)?;
+        let offset = index[0].offset();
+        index.push(crai::Record::new(None, None, 0, offset, 0, 0));
+        let cram_reader = Builder::default()
+            .set_reference_sequence_repository(build_reference_sequence_repository())
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        for mut reader in [bam_reader, cram_reader] {
+            let header = reader.read_header()?;
+
+            let names: Vec<_> = reader
+                .query_unmapped(&header)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .name()
+                            .map(|name| name.to_vec())
+                            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            assert_eq!(names, [b"r3".to_vec()]);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_index() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::io::indexed_reader::Builder;
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let records = build_records()?;
+
+        let (data, index) = write_bam(&header, &records)?;
+        let reader = Builder::default()
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        assert!(matches!(
+            reader.index(),
+            Index::Csi(index) if index.reference_sequences().count() == 1
+        ));
+
+        let (data, index) = write_cram(&header, &records)?;
+        let reader = Builder::default()
+            .set_reference_sequence_repository(build_reference_sequence_repository())
+            .set_index(index)
+            .build_from_reader(Cursor::new(data))?;
+
+        assert!(matches!(
+            reader.index(),
+            Index::Crai(index) if index.len() == 1
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_par_query() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::alignment::io::indexed_reader::Builder;
+
+        const WORKER_COUNT: NonZeroUsize = match NonZeroUsize::new(2) {
+            Some(n) => n,
+            None => unreachable!(),
+        };
+
+        fn names(
+            records: &mut dyn Iterator<Item = io::Result<Box<dyn Record>>>,
+        ) -> io::Result<Vec<Vec<u8>>> {
+            records
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .name()
+                            .map(|name| name.to_vec())
+                            .ok_or_else(|| io::Error::from(io::ErrorKind::InvalidData))
+                    })
+                })
+                .collect()
+        }
+
+        let header = sam::Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(SQ0_LN))
+            .build();
+
+        let records = build_records()?;
+        let (data, index) = write_bam(&header, &records)?;
+
+        let build_reader = || {
+            Builder::default()
+                .set_index(index.clone())
+                .build_from_reader(Cursor::new(data.clone()))
+        };
+
+        let regions = ["sq0:5-8".parse()?, "sq0:1-4".parse()?, "sq0:3-6".parse()?];
+
+        let actual = par_query(WORKER_COUNT, &regions, build_reader, |_, records| {
+            names(records)
+        })?;
+
+        let mut reader = build_reader()?;
+        let header = reader.read_header()?;
+
+        let expected = regions
+            .iter()
+            .map(|region| {
+                reader
+                    .query(&header, region)
+                    .and_then(|mut q| names(&mut q))
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+
+        assert_eq!(actual, expected);
+        assert_eq!(
+            actual,
+            [
+                vec![b"r1".to_vec()],
+                vec![b"r0".to_vec()],
+                vec![b"r0".to_vec(), b"r1".to_vec()],
+            ]
+        );
+
+        Ok(())
+    }
 }
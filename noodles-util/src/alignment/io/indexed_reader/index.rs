@@ -0,0 +1,11 @@
+use noodles_cram::crai;
+use noodles_csi::BinningIndex;
+
+/// An alignment index of an indexed reader.
+#[derive(Debug)]
+pub enum Index<'a> {
+    /// A binning index (BAI or CSI).
+    Csi(&'a dyn BinningIndex),
+    /// CRAI.
+    Crai(&'a crai::Index),
+}
@@ -0,0 +1,96 @@
+use std::{
+    io::{self, Read, Seek},
+    num::NonZeroUsize,
+    panic,
+    sync::atomic::{AtomicUsize, Ordering},
+    thread,
+};
+
+use noodles_core::Region;
+use noodles_sam::{self as sam, alignment::Record};
+
+use super::IndexedReader;
+
+/// Queries a list of regions in parallel.
+///
+/// Regions are distributed across `worker_count` threads. Each thread builds its own indexed
+/// reader using `build_reader`, e.g., by reopening the file, and reads its header. For each region,
+/// `f` is called with the header and an iterator over the records that intersect the region.
+///
+/// The returned list has one result per region, in the same order as the input regions. If any
+/// query fails, the error of the first failing region is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::num::NonZeroUsize;
+/// use noodles_util::alignment::io::indexed_reader::{self, par_query};
+///
+/// let regions = ["sq0".parse()?, "sq1".parse()?, "sq2".parse()?];
+///
+/// let record_counts = par_query(
+///     NonZeroUsize::try_from(2)?,
+///     &regions,
+///     || indexed_reader::Builder::default().build_from_path("sample.bam"),
+///     |_, records| Ok(records.count()),
+/// )?;
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+pub fn par_query<R, F, G, T>(
+    worker_count: NonZeroUsize,
+    regions: &[Region],
+    build_reader: F,
+    f: G,
+) -> io::Result<Vec<T>>
+where
+    R: Read + Seek,
+    F: Fn() -> io::Result<IndexedReader<R>> + Sync,
+    G: Fn(&sam::Header, &mut dyn Iterator<Item = io::Result<Box<dyn Record>>>) -> io::Result<T>
+        + Sync,
+    T: Send,
+{
+    let worker_count = worker_count.get().min(regions.len());
+    let next_region_index = AtomicUsize::new(0);
+
+    let mut results = thread::scope(|scope| {
+        let handles: Vec<_> = (0..worker_count)
+            .map(|_| {
+                scope.spawn(|| {
+                    let mut reader = build_reader()?;
+                    let header = reader.read_header()?;
+
+                    let mut results = Vec::new();
+
+                    loop {
+                        let i = next_region_index.fetch_add(1, Ordering::Relaxed);
+
+                        let Some(region) = regions.get(i) else {
+                            break;
+                        };
+
+                        let result = reader
+                            .query(&header, region)
+                            .and_then(|mut query| f(&header, &mut query));
+
+                        results.push((i, result));
+                    }
+
+                    Ok::<_, io::Error>(results)
+                })
+            })
+            .collect();
+
+        let mut results = Vec::with_capacity(regions.len());
+
+        for handle in handles {
+            let worker_results = handle.join().unwrap_or_else(|e| panic::resume_unwind(e))?;
+
+            results.extend(worker_results);
+        }
+
+        Ok::<_, io::Error>(results)
+    })?;
+
+    results.sort_unstable_by_key(|(i, _)| *i);
+    results.into_iter().map(|(_, result)| result).collect()
+}
@@ -1,5 +1,6 @@
 //! Composable iterators for alignment records.
 
+mod downsample;
 mod pileup;
 
-pub use self::pileup::Pileup as Depth;
+pub use self::{downsample::Downsample, pileup::Pileup as Depth};
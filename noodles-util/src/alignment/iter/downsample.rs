@@ -0,0 +1,106 @@
+use std::io;
+
+use noodles_sam::alignment::Record;
+
+use crate::downsample::Sampler;
+
+/// A deterministic downsampling iterator.
+///
+/// This takes an iterator of records and keeps a fraction of them, chosen by a seeded hash of
+/// their read names. The same seed always keeps the same subset, and records that share a read
+/// name, e.g., mates, are either all kept or all discarded.
+///
+/// Records without a read name share an empty key.
+pub struct Downsample<I> {
+    records: I,
+    sampler: Sampler,
+}
+
+impl<I> Downsample<I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    /// Creates a downsampling iterator.
+    ///
+    /// `fraction` is the expected fraction of read names to keep and must be in `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::alignment::iter::Downsample;
+    /// let records = Vec::new().into_iter();
+    /// let downsample = Downsample::new(records, 0.1, 8)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(records: I, fraction: f64, seed: u64) -> io::Result<Self> {
+        Sampler::new(fraction, seed).map(|sampler| Self { records, sampler })
+    }
+}
+
+impl<I> Iterator for Downsample<I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    type Item = io::Result<Box<dyn Record>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for result in &mut self.records {
+            let record = match result {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            let name = record.name().map(|name| name.as_ref()).unwrap_or_default();
+
+            if self.sampler.keep(name) {
+                return Some(Ok(record));
+            }
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_sam::alignment::RecordBuf;
+
+    use super::*;
+
+    #[test]
+    fn test_next() -> io::Result<()> {
+        const N: usize = 5000;
+
+        // Each read name is given to a pair of records.
+        let names: Vec<_> = (0..N).map(|i| format!("r{i}")).collect();
+
+        let records = || {
+            names.iter().flat_map(|name| {
+                [0, 1].map(|_| {
+                    let record = RecordBuf::builder().set_name(name.as_str()).build();
+                    Ok(Box::new(record) as Box<dyn Record>)
+                })
+            })
+        };
+
+        let downsample = |seed| -> io::Result<Vec<Vec<u8>>> {
+            Downsample::new(records(), 0.25, seed)?
+                .map(|result| result.map(|record| record.name().unwrap_or_default().to_vec()))
+                .collect()
+        };
+
+        let actual = downsample(5)?;
+        assert_eq!(downsample(5)?, actual);
+        assert_ne!(downsample(21)?, actual);
+
+        // Mates are kept together.
+        assert!(actual.len() % 2 == 0);
+        assert!(actual.chunks(2).all(|pair| pair[0] == pair[1]));
+
+        let fraction = (actual.len() / 2) as f64 / N as f64;
+        assert!((fraction - 0.25).abs() < 0.02, "fraction = {fraction}");
+
+        Ok(())
+    }
+}
@@ -9,6 +9,11 @@ use noodles_sam::{
 
 type ActiveWindowRange = (Position, Position);
 
+const DEFAULT_EXCLUDED_FLAGS: Flags = Flags::UNMAPPED
+    .union(Flags::SECONDARY)
+    .union(Flags::QC_FAIL)
+    .union(Flags::DUPLICATE);
+
 #[derive(Debug)]
 enum State {
     Empty,
@@ -21,10 +26,15 @@ enum State {
 /// A pileup iterator.
 ///
 /// This takes an iterator of coordinate-sorted records and emits reference sequence column
-/// statistics.
+/// statistics, i.e., the read depth at each position.
+///
+/// Depth is accumulated by sweeping the CIGAR operations of each record. Alignment matches (`M`,
+/// `=`, `X`) are counted, and deletions (`D`) and skipped regions (`N`) advance the reference
+/// position without being counted.
 pub struct Pileup<'h, I> {
     header: &'h Header,
     records: I,
+    excluded_flags: Flags,
     state: State,
     position: Position,
     window: VecDeque<u64>,
@@ -37,11 +47,41 @@ where
 {
     /// Creates a pileup iterator.
     ///
-    /// The given iterator must be coordinate-sorted on a single reference sequence.
+    /// The given iterator must be coordinate-sorted on a single reference sequence, e.g., the
+    /// results of a region query.
+    ///
+    /// Unmapped, secondary, QC fail, and duplicate records are excluded. See
+    /// [`Self::with_excluded_flags`] to change which records are filtered.
     pub fn new(header: &'h Header, records: I) -> Self {
+        Self::with_excluded_flags(DEFAULT_EXCLUDED_FLAGS, header, records)
+    }
+
+    /// Creates a pileup iterator that excludes records with any of the given flags set.
+    ///
+    /// Unmapped records are always excluded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_sam::{self as sam, alignment::record::Flags};
+    /// use noodles_util::alignment::iter::Depth;
+    ///
+    /// let header = sam::Header::default();
+    /// let records = Vec::new().into_iter();
+    ///
+    /// let mut depth = Depth::with_excluded_flags(
+    ///     Flags::SECONDARY | Flags::DUPLICATE,
+    ///     &header,
+    ///     records,
+    /// );
+    ///
+    /// assert!(depth.next().is_none());
+    /// ```
+    pub fn with_excluded_flags(excluded_flags: Flags, header: &'h Header, records: I) -> Self {
         Self {
             header,
             records,
+            excluded_flags: excluded_flags | Flags::UNMAPPED,
             state: State::Empty,
             position: Position::MIN,
             window: VecDeque::new(),
@@ -55,7 +95,7 @@ where
                 let record = result?;
                 let flags = record.flags()?;
 
-                if filter(flags) {
+                if flags.intersects(self.excluded_flags) {
                     continue;
                 }
 
@@ -90,7 +130,7 @@ where
         while let Some(record) = self.records.next().transpose()? {
             let flags = record.flags()?;
 
-            if filter(flags) {
+            if flags.intersects(self.excluded_flags) {
                 continue;
             }
 
@@ -180,10 +220,6 @@ where
     }
 }
 
-fn filter(flags: Flags) -> bool {
-    flags.is_unmapped() || flags.is_secondary() || flags.is_qc_fail() || flags.is_duplicate()
-}
-
 fn pile_record<R>(
     window: &mut VecDeque<u64>,
     start: Position,
@@ -325,4 +361,98 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_next_with_excluded_flags() -> Result<(), Box<dyn std::error::Error>> {
+        use sam::{
+            alignment::record::cigar::{op::Kind, Op},
+            header::record::value::{map::ReferenceSequence, Map},
+        };
+
+        // 1 2 3 4 5 6 7 8
+        // [ - - ]             1M2D2M, secondary
+        //   [       ]         5M
+        //       [ ] - [ ]     2M1N2M, duplicate
+        let records: Vec<RecordBuf> = [
+            (
+                Flags::SECONDARY,
+                Position::try_from(1)?,
+                [
+                    Op::new(Kind::Match, 1),
+                    Op::new(Kind::Deletion, 2),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+            (
+                Flags::empty(),
+                Position::try_from(2)?,
+                [Op::new(Kind::Match, 5)].into_iter().collect(),
+            ),
+            (
+                Flags::DUPLICATE,
+                Position::try_from(4)?,
+                [
+                    Op::new(Kind::Match, 2),
+                    Op::new(Kind::Skip, 1),
+                    Op::new(Kind::Match, 2),
+                ]
+                .into_iter()
+                .collect(),
+            ),
+        ]
+        .into_iter()
+        .map(|(flags, position, cigar)| {
+            RecordBuf::builder()
+                .set_flags(flags)
+                .set_reference_sequence_id(0)
+                .set_alignment_start(position)
+                .set_cigar(cigar)
+                .build()
+        })
+        .collect();
+
+        let boxed_records = || {
+            records
+                .iter()
+                .cloned()
+                .map(|record| Ok(Box::new(record) as Box<dyn Record>))
+        };
+
+        let header = Header::builder()
+            .add_reference_sequence("sq0", Map::<ReferenceSequence>::new(NonZeroUsize::MAX))
+            .build();
+
+        let pileup = Pileup::with_excluded_flags(Flags::empty(), &header, boxed_records());
+        let actual: Vec<_> = pileup.collect::<Result<_, _>>()?;
+
+        let expected = [
+            (Position::try_from(1)?, 1),
+            (Position::try_from(2)?, 1),
+            (Position::try_from(3)?, 1),
+            (Position::try_from(4)?, 3),
+            (Position::try_from(5)?, 3),
+            (Position::try_from(6)?, 1),
+            (Position::try_from(7)?, 1),
+            (Position::try_from(8)?, 1),
+        ];
+
+        assert_eq!(actual, expected);
+
+        let pileup = Pileup::new(&header, boxed_records());
+        let actual: Vec<_> = pileup.collect::<Result<_, _>>()?;
+
+        let expected = [
+            (Position::try_from(2)?, 1),
+            (Position::try_from(3)?, 1),
+            (Position::try_from(4)?, 1),
+            (Position::try_from(5)?, 1),
+            (Position::try_from(6)?, 1),
+        ];
+
+        assert_eq!(actual, expected);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,55 @@
+//! BED interval writing.
+
+use std::io::{self, Write};
+
+use noodles_core::Position;
+
+/// Writes an interval as a BED3 line.
+///
+/// The given interval is 1-based and inclusive, i.e., `[start, end]`. It is written 0-based and
+/// half-open, i.e., `[start - 1, end)`.
+pub(crate) fn write_interval<W>(
+    writer: &mut W,
+    name: &[u8],
+    start: Position,
+    end: Position,
+) -> io::Result<()>
+where
+    W: Write,
+{
+    const DELIMITER: u8 = b'\t';
+
+    let bed_start = usize::from(start) - 1;
+    let bed_end = usize::from(end);
+
+    writer.write_all(name)?;
+    writer.write_all(&[DELIMITER])?;
+    write!(writer, "{bed_start}")?;
+    writer.write_all(&[DELIMITER])?;
+    writeln!(writer, "{bed_end}")?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_interval() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+        write_interval(
+            &mut buf,
+            b"sq0",
+            Position::try_from(100)?,
+            Position::try_from(102)?,
+        )?;
+        assert_eq!(buf, b"sq0\t99\t102\n");
+
+        buf.clear();
+        write_interval(&mut buf, b"sq0", Position::MIN, Position::MIN)?;
+        assert_eq!(buf, b"sq0\t0\t1\n");
+
+        Ok(())
+    }
+}
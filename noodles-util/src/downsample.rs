@@ -0,0 +1,114 @@
+//! Deterministic record downsampling.
+
+use std::io;
+
+/// A deterministic sampler that keeps a fraction of keys.
+///
+/// A key is kept when its seeded hash falls below `fraction` of the hash space. The same seed and
+/// key always give the same decision, regardless of the order or process in which keys are seen.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Sampler {
+    seed: u64,
+    // `None` keeps all keys.
+    threshold: Option<u64>,
+}
+
+impl Sampler {
+    pub(crate) fn new(fraction: f64, seed: u64) -> io::Result<Self> {
+        if !(0.0..=1.0).contains(&fraction) {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid fraction: expected a value in [0.0, 1.0]",
+            ));
+        }
+
+        let threshold = if fraction == 1.0 {
+            None
+        } else {
+            // 2^64
+            const HASH_SPACE: f64 = 18_446_744_073_709_551_616.0;
+            Some((fraction * HASH_SPACE) as u64)
+        };
+
+        Ok(Self { seed, threshold })
+    }
+
+    pub(crate) fn keep(&self, key: &[u8]) -> bool {
+        match self.threshold {
+            Some(threshold) => hash(self.seed, key) < threshold,
+            None => true,
+        }
+    }
+}
+
+// A seeded 64-bit FNV-1a hash with a final avalanche step.
+//
+// This is used rather than `std::hash::DefaultHasher`, whose output is not guaranteed to be
+// stable across Rust releases.
+fn hash(seed: u64, key: &[u8]) -> u64 {
+    const OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+    const PRIME: u64 = 0x0100000001b3;
+
+    let mut h = OFFSET_BASIS ^ mix(seed);
+
+    for &b in key {
+        h ^= u64::from(b);
+        h = h.wrapping_mul(PRIME);
+    }
+
+    mix(h)
+}
+
+// The SplitMix64 finalizer.
+fn mix(mut z: u64) -> u64 {
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        assert!(Sampler::new(0.0, 0).is_ok());
+        assert!(Sampler::new(1.0, 0).is_ok());
+
+        for fraction in [-0.1, 1.1, f64::NAN] {
+            assert!(matches!(
+                Sampler::new(fraction, 0),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+    }
+
+    #[test]
+    fn test_keep() -> io::Result<()> {
+        const N: usize = 10000;
+
+        let keys: Vec<_> = (0..N).map(|i| format!("r{i}")).collect();
+
+        let kept = |sampler: Sampler| -> Vec<bool> {
+            keys.iter()
+                .map(|key| sampler.keep(key.as_bytes()))
+                .collect()
+        };
+
+        let a = kept(Sampler::new(0.3, 8)?);
+        let b = kept(Sampler::new(0.3, 8)?);
+        assert_eq!(a, b);
+
+        let n = a.iter().filter(|&&keep| keep).count();
+        let actual = n as f64 / N as f64;
+        assert!((actual - 0.3).abs() < 0.02, "actual = {actual}");
+
+        let c = kept(Sampler::new(0.3, 13)?);
+        assert_ne!(a, c);
+
+        assert!(kept(Sampler::new(0.0, 8)?).iter().all(|&keep| !keep));
+        assert!(kept(Sampler::new(1.0, 8)?).iter().all(|&keep| keep));
+
+        Ok(())
+    }
+}
@@ -6,5 +6,14 @@
 #[cfg(feature = "alignment")]
 pub mod alignment;
 
+#[cfg(any(feature = "alignment", feature = "variant"))]
+mod bed;
+
+#[cfg(any(feature = "alignment", feature = "variant"))]
+mod downsample;
+
+#[cfg(any(feature = "alignment", feature = "variant"))]
+mod regions;
+
 #[cfg(feature = "variant")]
 pub mod variant;
@@ -0,0 +1,206 @@
+//! Multi-region querying.
+
+use std::io;
+
+use noodles_core::{region::Interval, Position, Region};
+use noodles_csi::{
+    binning_index::{index::reference_sequence::bin::Chunk, merge_chunks},
+    BinningIndex,
+};
+
+/// Sorts and merges overlapping or adjacent regions.
+///
+/// Regions are ordered by their reference sequence indices, as given by
+/// `reference_sequence_index`, and then by their start positions. The resulting regions are
+/// disjoint and nonadjacent.
+pub(crate) fn merge<F>(regions: &[Region], reference_sequence_index: F) -> io::Result<Vec<Region>>
+where
+    F: Fn(&[u8]) -> Option<usize>,
+{
+    let mut intervals = Vec::with_capacity(regions.len());
+
+    for region in regions {
+        let i = reference_sequence_index(region.name()).ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "invalid reference sequence name",
+            )
+        })?;
+
+        let interval = region.interval();
+        let start = interval.start().unwrap_or(Position::MIN);
+
+        intervals.push((i, region, start, interval.end()));
+    }
+
+    intervals.sort_by_key(|(i, _, start, _)| (*i, *start));
+
+    let mut merged_intervals: Vec<(usize, &Region, Position, Option<Position>)> =
+        Vec::with_capacity(intervals.len());
+
+    for (i, region, start, end) in intervals {
+        if let Some((prev_i, _, _, prev_end)) = merged_intervals.last_mut() {
+            if *prev_i == i && is_overlapping_or_adjacent(*prev_end, start) {
+                *prev_end = (*prev_end).zip(end).map(|(a, b)| a.max(b));
+                continue;
+            }
+        }
+
+        merged_intervals.push((i, region, start, end));
+    }
+
+    Ok(merged_intervals
+        .into_iter()
+        .map(|(_, region, start, end)| {
+            let name = region.name().to_owned();
+
+            match end {
+                Some(end) => Region::new(name, start..=end),
+                None => Region::new(name, start..),
+            }
+        })
+        .collect())
+}
+
+fn is_overlapping_or_adjacent(prev_end: Option<Position>, start: Position) -> bool {
+    match prev_end {
+        Some(prev_end) => usize::from(start) - 1 <= usize::from(prev_end),
+        None => true,
+    }
+}
+
+/// Returns the chunks of the given index that intersect any of the given regions.
+///
+/// The chunks are sorted and merged so that reading them reads each record at most once.
+pub(crate) fn query_chunks<F>(
+    index: &dyn BinningIndex,
+    regions: &[Region],
+    reference_sequence_id: F,
+) -> io::Result<Vec<Chunk>>
+where
+    F: Fn(&Region) -> io::Result<usize>,
+{
+    let mut chunks = Vec::new();
+
+    for region in regions {
+        let i = reference_sequence_id(region)?;
+        chunks.extend(index.query(i, region.interval())?);
+    }
+
+    Ok(merge_chunks(&chunks))
+}
+
+/// Returns whether the given interval on the given reference sequence intersects any of the given
+/// regions.
+pub(crate) fn intersects(regions: &[Region], name: &[u8], interval: Interval) -> bool {
+    regions
+        .iter()
+        .any(|region| region.name() == name && region.interval().intersects(interval))
+}
+
+/// Filters records using the given predicate.
+///
+/// Errors from reading records or from the predicate are passed through.
+pub(crate) fn filter<'a, T, I, P>(
+    records: I,
+    mut predicate: P,
+) -> impl Iterator<Item = io::Result<T>> + 'a
+where
+    I: Iterator<Item = io::Result<T>> + 'a,
+    P: FnMut(&T) -> io::Result<bool> + 'a,
+{
+    records.filter_map(move |result| {
+        result
+            .and_then(|record| Ok(predicate(&record)?.then_some(record)))
+            .transpose()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() -> Result<(), Box<dyn std::error::Error>> {
+        let reference_sequence_index = |name: &[u8]| match name {
+            b"sq0" => Some(0),
+            b"sq1" => Some(1),
+            _ => None,
+        };
+
+        let regions = [
+            "sq1:5-8".parse()?,
+            "sq0:8-13".parse()?,
+            "sq0:1-5".parse()?,
+            "sq0:3-7".parse()?,
+            "sq1:13-21".parse()?,
+            "sq1:21".parse()?,
+            "sq0:21-34".parse()?,
+        ];
+
+        let actual = merge(&regions, reference_sequence_index)?;
+
+        let expected: Vec<Region> = vec![
+            "sq0:1-13".parse()?,
+            "sq0:21-34".parse()?,
+            "sq1:5-8".parse()?,
+            "sq1:13".parse()?,
+        ];
+
+        assert_eq!(actual, expected);
+
+        let regions = ["sq2:1-5".parse()?];
+
+        assert!(matches!(
+            merge(&regions, reference_sequence_index),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_intersects() -> Result<(), Box<dyn std::error::Error>> {
+        let regions = ["sq0:5-8".parse()?, "sq0:13-21".parse()?, "sq1:1-5".parse()?];
+
+        assert!(intersects(&regions, b"sq0", "1-5".parse()?));
+        assert!(intersects(&regions, b"sq0", "21-34".parse()?));
+        assert!(intersects(&regions, b"sq1", "2-3".parse()?));
+        assert!(!intersects(&regions, b"sq0", "9-12".parse()?));
+        assert!(!intersects(&regions, b"sq1", "8-13".parse()?));
+        assert!(!intersects(&regions, b"sq2", "1-5".parse()?));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_filter() {
+        let records = vec![
+            Ok(1),
+            Ok(2),
+            Err(io::Error::from(io::ErrorKind::InvalidData)),
+            Ok(3),
+            Ok(4),
+        ];
+
+        let actual: Vec<_> = filter(records.into_iter(), |&n| {
+            if n == 4 {
+                Err(io::Error::from(io::ErrorKind::InvalidInput))
+            } else {
+                Ok(n % 2 == 1)
+            }
+        })
+        .map(|result| result.map_err(|e| e.kind()))
+        .collect();
+
+        assert_eq!(
+            actual,
+            [
+                Ok(1),
+                Err(io::ErrorKind::InvalidData),
+                Ok(3),
+                Err(io::ErrorKind::InvalidInput),
+            ]
+        );
+    }
+}
@@ -1,12 +1,15 @@
 //! Variant format I/O.
 
+mod bed;
 mod compression_method;
 mod format;
 pub mod indexed_reader;
+mod passthrough_record;
 pub mod reader;
 pub mod writer;
 
 pub use self::{
-    compression_method::CompressionMethod, format::Format, indexed_reader::IndexedReader,
-    reader::Reader, writer::Writer,
+    bed::write_bed, compression_method::CompressionMethod, format::Format,
+    indexed_reader::IndexedReader, passthrough_record::PassthroughRecord, reader::Reader,
+    writer::Writer,
 };
@@ -0,0 +1,77 @@
+use std::io::{self, Write};
+
+use noodles_vcf::{self as vcf, variant::Record};
+
+/// Writes the intervals of variant records as BED3 lines.
+///
+/// Each record is written as `<chrom>\t<start>\t<end>`, where the interval is converted from the
+/// 1-based, inclusive variant start and end to 0-based, half-open coordinates. The variant end
+/// is the `END` info field, if present, or otherwise the end of the reference bases.
+///
+/// Records without a variant start (e.g., telomeric breakends at position 0) are skipped.
+///
+/// # Examples
+///
+/// ```
+/// # use std::io;
+/// use noodles_util::variant;
+/// use noodles_vcf as vcf;
+///
+/// let header = vcf::Header::default();
+/// let records = Vec::new();
+///
+/// let mut buf = Vec::new();
+/// variant::io::write_bed(&mut buf, &header, records)?;
+/// assert!(buf.is_empty());
+/// # Ok::<_, io::Error>(())
+/// ```
+pub fn write_bed<W, I>(writer: &mut W, header: &vcf::Header, records: I) -> io::Result<()>
+where
+    W: Write,
+    I: IntoIterator<Item = io::Result<Box<dyn Record>>>,
+{
+    use crate::bed::write_interval;
+
+    for result in records {
+        let record = result?;
+
+        let Some(start) = record.variant_start().transpose()? else {
+            continue;
+        };
+
+        let end = record.variant_end(header)?;
+        let reference_sequence_name = record.reference_sequence_name(header)?;
+
+        write_interval(writer, reference_sequence_name.as_bytes(), start, end)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_bed() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.4
+##INFO=<ID=END,Number=1,Type=Integer,Description=\"End position of the longest variant described in this record\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t100\t.\tACG\tA\t.\tPASS\t.
+sq0\t200\t.\tN\t<DEL>\t.\tPASS\tEND=299
+";
+
+        let mut reader = vcf::io::Reader::new(SRC);
+        let header = reader.read_header()?;
+        let records = reader
+            .records()
+            .map(|result| result.map(|record| Box::new(record) as Box<dyn Record>));
+
+        let mut buf = Vec::new();
+        write_bed(&mut buf, &header, records)?;
+
+        assert_eq!(buf, b"sq0\t99\t102\nsq0\t199\t299\n");
+
+        Ok(())
+    }
+}
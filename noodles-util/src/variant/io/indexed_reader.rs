@@ -4,11 +4,15 @@ mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, BufRead};
+use std::{
+    io::{self, BufRead},
+    iter,
+};
 
 use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
+use noodles_csi::{self as csi, BinningIndex};
 use noodles_vcf::{self as vcf, variant::Record};
 
 /// An indexed variant reader.
@@ -31,6 +35,16 @@ where
         }
     }
 
+    /// Returns the associated index.
+    ///
+    /// For VCF, this is typically a tabix index; and for BCF, a CSI.
+    pub fn index(&self) -> &dyn BinningIndex {
+        match self {
+            Self::Vcf(reader) => reader.index(),
+            Self::Bcf(reader) => reader.index(),
+        }
+    }
+
     /// Returns an iterator over records starting from the current stream position.
     pub fn records<'r, 'h: 'r>(
         &'r mut self,
@@ -77,4 +91,120 @@ where
 
         Ok(records)
     }
+
+    /// Returns an iterator over records that intersect any of the given regions.
+    ///
+    /// Overlapping and adjacent regions are first merged, and each merged region is queried in
+    /// contig and coordinate order. A record that intersects more than one merged region is only
+    /// returned once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use noodles_util::variant;
+    ///
+    /// let mut reader = variant::io::indexed_reader::Builder::default()
+    ///     .build_from_path("sample.vcf.gz")?;
+    ///
+    /// let header = reader.read_header()?;
+    ///
+    /// let regions = ["sq0:8-13".parse()?, "sq0:21-34".parse()?];
+    /// let query = reader.query_regions(&header, &regions)?;
+    ///
+    /// for result in query {
+    ///     let record = result?;
+    ///     // ...
+    /// }
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn query_regions<'r, 'h: 'r>(
+        &'r mut self,
+        header: &'h vcf::Header,
+        regions: &[Region],
+    ) -> io::Result<impl Iterator<Item = io::Result<Box<dyn Record>>> + 'r> {
+        let regions = crate::regions::merge(regions, |name| {
+            std::str::from_utf8(name)
+                .ok()
+                .and_then(|name| header.contigs().get_index_of(name))
+        })?;
+
+        let records: Box<dyn Iterator<Item = io::Result<Box<dyn Record>>>> = match self {
+            Self::Vcf(reader) => {
+                let index = reader.index();
+
+                let reference_sequence_names = index
+                    .header()
+                    .map(|index_header| index_header.reference_sequence_names())
+                    .ok_or_else(|| {
+                        io::Error::new(io::ErrorKind::InvalidInput, "missing tabix header")
+                    })?;
+
+                let chunks = crate::regions::query_chunks(index, &regions, |region| {
+                    std::str::from_utf8(region.name())
+                        .ok()
+                        .and_then(|name| reference_sequence_names.get_index_of(name))
+                        .ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "invalid reference sequence name",
+                            )
+                        })
+                })?;
+
+                let mut reader =
+                    vcf::io::Reader::new(csi::io::Query::new(reader.get_mut(), chunks));
+
+                Box::new(iter::from_fn(move || {
+                    let mut record = vcf::Record::default();
+
+                    match reader.read_record(&mut record) {
+                        Ok(0) => None,
+                        Ok(_) => Some(Ok(Box::new(record) as Box<dyn Record>)),
+                        Err(e) => Some(Err(e)),
+                    }
+                }))
+            }
+            Self::Bcf(reader) => {
+                let chunks = crate::regions::query_chunks(reader.index(), &regions, |region| {
+                    std::str::from_utf8(region.name())
+                        .ok()
+                        .and_then(|name| header.string_maps().contigs().get_index_of(name))
+                        .ok_or_else(|| {
+                            io::Error::new(
+                                io::ErrorKind::InvalidInput,
+                                "invalid reference sequence name",
+                            )
+                        })
+                })?;
+
+                let mut reader =
+                    bcf::io::Reader::from(csi::io::Query::new(reader.get_mut(), chunks));
+
+                Box::new(iter::from_fn(move || {
+                    let mut record = bcf::Record::default();
+
+                    match reader.read_record(&mut record) {
+                        Ok(0) => None,
+                        Ok(_) => Some(Ok(Box::new(record) as Box<dyn Record>)),
+                        Err(e) => Some(Err(e)),
+                    }
+                }))
+            }
+        };
+
+        Ok(crate::regions::filter(records, move |record| {
+            let Some(start) = record.variant_start().transpose()? else {
+                return Ok(false);
+            };
+
+            let name = record.reference_sequence_name(header)?;
+            let end = record.variant_end(header)?;
+
+            Ok(crate::regions::intersects(
+                &regions,
+                name.as_bytes(),
+                (start..=end).into(),
+            ))
+        }))
+    }
 }
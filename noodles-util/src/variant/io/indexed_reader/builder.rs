@@ -224,6 +224,45 @@ mod tests {
             .build_from_reader(&data[..])?;
 
         reader.read_header()?;
+        assert_eq!(reader.index().reference_sequences().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_build_from_reader_with_format_detection() -> io::Result<()> {
+        let header = vcf::Header::default();
+
+        let mut writer = bcf::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let data = writer.into_inner().finish()?;
+
+        let reader = Builder::default()
+            .set_index(csi::Index::default())
+            .build_from_reader(&data[..])?;
+
+        assert!(matches!(reader, IndexedReader::Bcf(_)));
+
+        let mut writer = vcf::io::Writer::new(bgzf::Writer::new(Vec::new()));
+        writer.write_header(&header)?;
+        let data = writer.into_inner().finish()?;
+
+        let reader = Builder::default()
+            .set_index(csi::Index::default())
+            .build_from_reader(&data[..])?;
+
+        assert!(matches!(reader, IndexedReader::Vcf(_)));
+
+        let mut writer = vcf::io::Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let data = writer.into_inner();
+
+        assert!(matches!(
+            Builder::default()
+                .set_index(csi::Index::default())
+                .build_from_reader(&data[..]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
 
         Ok(())
     }
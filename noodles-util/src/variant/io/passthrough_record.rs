@@ -0,0 +1,42 @@
+use noodles_vcf::variant::Record;
+
+use super::Format;
+
+/// A variant record that retains its raw encoded bytes.
+///
+/// This is read using [`super::Reader::passthrough_records`] and written using
+/// [`super::Writer::write_passthrough_record`]. When written in the same format it was read,
+/// the original bytes are emitted as is, rather than re-encoding the decoded record.
+pub struct PassthroughRecord {
+    format: Format,
+    buf: Vec<u8>,
+    record: Box<dyn Record>,
+}
+
+impl PassthroughRecord {
+    pub(crate) fn new(format: Format, buf: Vec<u8>, record: Box<dyn Record>) -> Self {
+        Self {
+            format,
+            buf,
+            record,
+        }
+    }
+
+    /// Returns the format of the raw encoded record.
+    pub fn format(&self) -> Format {
+        self.format
+    }
+
+    /// Returns the raw encoded record.
+    ///
+    /// For VCF, this is the record line, including the line terminator. For BCF, this is the
+    /// record including its shared and individual data lengths.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Returns the decoded record.
+    pub fn record(&self) -> &dyn Record {
+        self.record.as_ref()
+    }
+}
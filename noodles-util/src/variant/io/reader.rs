@@ -4,13 +4,24 @@ pub(crate) mod builder;
 
 pub use self::builder::Builder;
 
-use std::io::{self, BufRead};
+use std::{
+    io::{self, BufRead},
+    iter,
+};
 
+use noodles_bcf as bcf;
 use noodles_vcf::{self as vcf, variant::Record};
 
+use super::{Format, PassthroughRecord};
+
 /// A variant reader.
 pub struct Reader<R> {
-    inner: Box<dyn vcf::variant::io::Read<R>>,
+    inner: Inner<R>,
+}
+
+pub(crate) enum Inner<R> {
+    Vcf(vcf::io::Reader<R>),
+    Bcf(bcf::io::Reader<R>),
 }
 
 impl<R> Reader<R>
@@ -40,7 +51,10 @@ where
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<vcf::Header> {
-        self.inner.read_variant_header()
+        match &mut self.inner {
+            Inner::Vcf(reader) => reader.read_header(),
+            Inner::Bcf(reader) => reader.read_header(),
+        }
     }
 
     /// Returns an iterator over records starting from the current stream position.
@@ -70,6 +84,116 @@ where
         &'a mut self,
         header: &'a vcf::Header,
     ) -> impl Iterator<Item = io::Result<Box<dyn Record>>> + 'a {
-        self.inner.variant_records(header)
+        use vcf::variant::io::Read;
+
+        match &mut self.inner {
+            Inner::Vcf(reader) => reader.variant_records(header),
+            Inner::Bcf(reader) => reader.variant_records(header),
+        }
+    }
+
+    /// Returns an iterator over passthrough records starting from the current stream position.
+    ///
+    /// A passthrough record retains its raw encoded bytes alongside its decoded view, allowing
+    /// it to be written unmodified (see [`super::Writer::write_passthrough_record`]).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::variant;
+    ///
+    /// let data = Cursor::new(b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ");
+    ///
+    /// let mut reader = variant::io::reader::Builder::default().build_from_reader(data)?;
+    /// reader.read_header()?;
+    ///
+    /// let mut records = reader.passthrough_records();
+    ///
+    /// let record = records.next().transpose()?.unwrap();
+    /// assert_eq!(record.as_bytes(), b"sq0\t1\t.\tA\t.\t.\tPASS\t.\n");
+    ///
+    /// assert!(records.next().is_none());
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn passthrough_records(
+        &mut self,
+    ) -> impl Iterator<Item = io::Result<PassthroughRecord>> + '_ {
+        iter::from_fn(move || self.read_passthrough_record().transpose())
     }
+
+    fn read_passthrough_record(&mut self) -> io::Result<Option<PassthroughRecord>> {
+        match &mut self.inner {
+            Inner::Vcf(reader) => {
+                let mut buf = Vec::new();
+
+                if read_vcf_record(reader.get_mut(), &mut buf)? == 0 {
+                    return Ok(None);
+                }
+
+                let mut record = vcf::Record::default();
+                vcf::io::Reader::new(&buf[..]).read_record(&mut record)?;
+
+                Ok(Some(PassthroughRecord::new(
+                    Format::Vcf,
+                    buf,
+                    Box::new(record),
+                )))
+            }
+            Inner::Bcf(reader) => {
+                let mut buf = Vec::new();
+
+                if read_bcf_record(reader.get_mut(), &mut buf)? == 0 {
+                    return Ok(None);
+                }
+
+                let mut record = bcf::Record::default();
+                bcf::io::Reader::from(&buf[..]).read_record(&mut record)?;
+
+                Ok(Some(PassthroughRecord::new(
+                    Format::Bcf,
+                    buf,
+                    Box::new(record),
+                )))
+            }
+        }
+    }
+}
+
+fn read_vcf_record<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    const LINE_FEED: u8 = b'\n';
+    reader.read_until(LINE_FEED, buf)
+}
+
+fn read_bcf_record<R>(reader: &mut R, buf: &mut Vec<u8>) -> io::Result<usize>
+where
+    R: BufRead,
+{
+    // l_shared (u32) + l_indiv (u32)
+    const LENGTHS_SIZE: usize = 8;
+
+    if reader.fill_buf()?.is_empty() {
+        return Ok(0);
+    }
+
+    buf.resize(LENGTHS_SIZE, 0);
+    reader.read_exact(buf)?;
+
+    let l_shared = u32::from_le_bytes([buf[0], buf[1], buf[2], buf[3]]);
+    let l_indiv = u32::from_le_bytes([buf[4], buf[5], buf[6], buf[7]]);
+
+    let len = usize::try_from(l_shared)
+        .and_then(|m| usize::try_from(l_indiv).map(|n| m + n))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    buf.resize(LENGTHS_SIZE + len, 0);
+    reader.read_exact(&mut buf[LENGTHS_SIZE..])?;
+
+    Ok(buf.len())
 }
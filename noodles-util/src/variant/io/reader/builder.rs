@@ -8,7 +8,7 @@ use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
 use noodles_vcf as vcf;
 
-use super::Reader;
+use super::{Inner, Reader};
 use crate::variant::io::{CompressionMethod, Format};
 
 /// A variant reader builder.
@@ -100,22 +100,22 @@ impl Builder {
             None => detect_format(&mut reader, compression_method)?,
         };
 
-        let inner: Box<dyn vcf::variant::io::Read<_>> = match (format, compression_method) {
+        let inner = match (format, compression_method) {
             (Format::Vcf, None) => {
                 let inner: Box<dyn BufRead> = Box::new(reader);
-                Box::new(vcf::io::Reader::new(inner))
+                Inner::Vcf(vcf::io::Reader::new(inner))
             }
             (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
                 let inner: Box<dyn BufRead> = Box::new(bgzf::Reader::new(reader));
-                Box::new(vcf::io::Reader::new(inner))
+                Inner::Vcf(vcf::io::Reader::new(inner))
             }
             (Format::Bcf, None) => {
                 let inner: Box<dyn BufRead> = Box::new(reader);
-                Box::new(bcf::io::Reader::from(inner))
+                Inner::Bcf(bcf::io::Reader::from(inner))
             }
             (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
                 let inner: Box<dyn BufRead> = Box::new(bgzf::Reader::new(reader));
-                Box::new(bcf::io::Reader::from(inner))
+                Inner::Bcf(bcf::io::Reader::from(inner))
             }
         };
 
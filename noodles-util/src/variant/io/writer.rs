@@ -4,13 +4,22 @@ pub(crate) mod builder;
 
 pub use self::builder::Builder;
 
-use std::io;
+use std::io::{self, Write};
 
-use noodles_vcf as vcf;
+use noodles_bcf as bcf;
+use noodles_vcf::{self as vcf, variant::io::Write as _};
+
+use super::{Format, PassthroughRecord};
 
 /// A variant writer.
 pub struct Writer {
-    inner: Box<dyn vcf::variant::io::Write>,
+    inner: Inner,
+    header: Option<vcf::Header>,
+}
+
+pub(crate) enum Inner {
+    Vcf(vcf::io::Writer<Box<dyn Write>>),
+    Bcf(bcf::io::Writer<Box<dyn Write>>),
 }
 
 impl Writer {
@@ -33,7 +42,14 @@ impl Writer {
     /// # Ok::<_, io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &vcf::Header) -> io::Result<()> {
-        self.inner.write_variant_header(header)
+        match &mut self.inner {
+            Inner::Vcf(writer) => writer.write_variant_header(header)?,
+            Inner::Bcf(writer) => writer.write_variant_header(header)?,
+        }
+
+        self.header = Some(header.clone());
+
+        Ok(())
     }
 
     /// Writes a variant record.
@@ -62,6 +78,377 @@ impl Writer {
         header: &vcf::Header,
         record: &dyn vcf::variant::Record,
     ) -> io::Result<()> {
-        self.inner.write_variant_record(header, record)
+        match &mut self.inner {
+            Inner::Vcf(writer) => writer.write_variant_record(header, record),
+            Inner::Bcf(writer) => writer.write_variant_record(header, record),
+        }
+    }
+
+    /// Writes a passthrough record.
+    ///
+    /// The given header is the one the record was read with.
+    ///
+    /// If the record was read in the same format as the output and with the same header that was
+    /// written, its raw encoded bytes are written unmodified. Otherwise, the record is decoded
+    /// using the given header and re-encoded using the written header.
+    ///
+    /// This returns an error if the header has not been written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io::{self, Cursor};
+    /// use noodles_util::variant::{self, io::Format};
+    ///
+    /// let data = Cursor::new(b"##fileformat=VCFv4.3
+    /// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+    /// sq0\t1\t.\tA\t.\t.\tPASS\t.
+    /// ");
+    ///
+    /// let mut reader = variant::io::reader::Builder::default().build_from_reader(data)?;
+    /// let header = reader.read_header()?;
+    ///
+    /// let mut writer = variant::io::writer::Builder::default()
+    ///     .set_format(Format::Vcf)
+    ///     .build_from_writer(io::sink());
+    ///
+    /// writer.write_header(&header)?;
+    ///
+    /// for result in reader.passthrough_records() {
+    ///     let record = result?;
+    ///     writer.write_passthrough_record(&header, &record)?;
+    /// }
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn write_passthrough_record(
+        &mut self,
+        header: &vcf::Header,
+        record: &PassthroughRecord,
+    ) -> io::Result<()> {
+        let output_header = self
+            .header
+            .as_ref()
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "header not written"))?;
+
+        if output_header != header {
+            let record = vcf::variant::RecordBuf::try_from_variant_record(header, record.record())?;
+
+            return match &mut self.inner {
+                Inner::Vcf(writer) => writer.write_variant_record(output_header, &record),
+                Inner::Bcf(writer) => writer.write_variant_record(output_header, &record),
+            };
+        }
+
+        match (&mut self.inner, record.format()) {
+            (Inner::Vcf(writer), Format::Vcf) => {
+                const LINE_FEED: u8 = b'\n';
+
+                let buf = record.as_bytes();
+                let inner = writer.get_mut();
+
+                inner.write_all(buf)?;
+
+                // The last line of the input may not be terminated.
+                if !buf.ends_with(&[LINE_FEED]) {
+                    inner.write_all(&[LINE_FEED])?;
+                }
+
+                Ok(())
+            }
+            (Inner::Bcf(writer), Format::Bcf) => writer.get_mut().write_all(record.as_bytes()),
+            _ => self.write_record(header, record.record()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::RefCell, io::Write, rc::Rc};
+
+    use noodles_core::Position;
+    use vcf::header::record::value::{map::Contig, Map};
+
+    use super::*;
+    use crate::variant::io::{reader, Format};
+
+    #[derive(Clone, Default)]
+    struct SharedBuf(Rc<RefCell<Vec<u8>>>);
+
+    impl Write for SharedBuf {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.borrow_mut().write(buf)
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_write_record() -> io::Result<()> {
+        let header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let records = [
+            vcf::variant::RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .build(),
+            vcf::variant::RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(
+                    Position::try_from(8).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+                )
+                .set_reference_bases("C")
+                .set_alternate_bases(vec![String::from("G")].into())
+                .build(),
+        ];
+
+        for format in [Format::Vcf, Format::Bcf] {
+            let buf = SharedBuf::default();
+
+            let mut writer = Builder::default()
+                .set_format(format)
+                .build_from_writer(buf.clone());
+
+            writer.write_header(&header)?;
+
+            for record in &records {
+                writer.write_record(&header, record)?;
+            }
+
+            drop(writer);
+
+            let data = buf.0.take();
+
+            let mut reader = reader::Builder::default()
+                .set_format(format)
+                .build_from_reader(io::Cursor::new(data))?;
+
+            let actual_header = reader.read_header()?;
+            assert_eq!(actual_header.contigs(), header.contigs());
+
+            let actual: Vec<_> = reader
+                .records(&actual_header)
+                .map(|result| {
+                    result.and_then(|record| {
+                        Ok((
+                            record.reference_sequence_name(&actual_header)?.to_string(),
+                            record.variant_start().transpose()?,
+                        ))
+                    })
+                })
+                .collect::<io::Result<_>>()?;
+
+            let expected: Vec<_> = records
+                .iter()
+                .map(|record| {
+                    (
+                        record.reference_sequence_name().to_string(),
+                        record.variant_start(),
+                    )
+                })
+                .collect();
+
+            assert_eq!(actual, expected);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_passthrough_record() -> io::Result<()> {
+        use crate::variant::io::Reader;
+
+        fn build_reader(data: Vec<u8>) -> io::Result<Reader<Box<dyn std::io::BufRead>>> {
+            reader::Builder::default().build_from_reader(io::Cursor::new(data))
+        }
+
+        // VCF
+        let src = b"##fileformat=VCFv4.4
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t5.0\tPASS\t.
+sq0\t8\t.\tC\tG\t30.00\tPASS\t.
+";
+
+        let mut reader = build_reader(src.to_vec())?;
+        let header = reader.read_header()?;
+
+        let buf = SharedBuf::default();
+        let mut writer = Builder::default()
+            .set_format(Format::Vcf)
+            .build_from_writer(buf.clone());
+
+        writer.write_header(&header)?;
+
+        for result in reader.passthrough_records() {
+            let record = result?;
+
+            if record.record().variant_start().transpose()? == Position::new(8) {
+                writer.write_passthrough_record(&header, &record)?;
+            }
+        }
+
+        drop(writer);
+
+        assert!(buf.0.borrow().ends_with(
+            b"#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\nsq0\t8\t.\tC\tG\t30.00\tPASS\t.\n"
+        ));
+
+        // BCF
+        let write_bcf = |records: &[vcf::variant::RecordBuf]| -> io::Result<Vec<u8>> {
+            let buf = SharedBuf::default();
+
+            let mut writer = Builder::default()
+                .set_format(Format::Bcf)
+                .set_compression_method(None)
+                .build_from_writer(buf.clone());
+
+            writer.write_header(&header)?;
+
+            for record in records {
+                writer.write_record(&header, record)?;
+            }
+
+            drop(writer);
+
+            Ok(buf.0.take())
+        };
+
+        let records = [
+            vcf::variant::RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(Position::MIN)
+                .set_reference_bases("A")
+                .build(),
+            vcf::variant::RecordBuf::builder()
+                .set_reference_sequence_name("sq0")
+                .set_variant_start(
+                    Position::try_from(8).map_err(|e| io::Error::new(io::ErrorKind::Other, e))?,
+                )
+                .set_reference_bases("C")
+                .set_alternate_bases(vec![String::from("G")].into())
+                .build(),
+        ];
+
+        let src = write_bcf(&records)?;
+
+        let mut reader = build_reader(src)?;
+        reader.read_header()?;
+
+        let buf = SharedBuf::default();
+        let mut writer = Builder::default()
+            .set_format(Format::Bcf)
+            .set_compression_method(None)
+            .build_from_writer(buf.clone());
+
+        writer.write_header(&header)?;
+
+        for result in reader.passthrough_records() {
+            let record = result?;
+
+            if record.record().variant_start().transpose()? == Position::new(8) {
+                writer.write_passthrough_record(&header, &record)?;
+            }
+        }
+
+        drop(writer);
+
+        let expected = write_bcf(&records[1..])?;
+        assert_eq!(buf.0.take(), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_passthrough_record_with_different_header() -> io::Result<()> {
+        let src_header = vcf::Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let dst_header = vcf::Header::builder()
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq0", Map::<Contig>::new())
+            .build();
+
+        let buf = SharedBuf::default();
+        let mut writer = Builder::default()
+            .set_format(Format::Bcf)
+            .set_compression_method(None)
+            .build_from_writer(buf.clone());
+
+        writer.write_header(&src_header)?;
+
+        let record = vcf::variant::RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .build();
+
+        writer.write_record(&src_header, &record)?;
+        drop(writer);
+
+        let mut reader =
+            reader::Builder::default().build_from_reader(io::Cursor::new(buf.0.take()))?;
+        let src_header = reader.read_header()?;
+
+        let buf = SharedBuf::default();
+        let mut writer = Builder::default()
+            .set_format(Format::Bcf)
+            .set_compression_method(None)
+            .build_from_writer(buf.clone());
+
+        writer.write_header(&dst_header)?;
+
+        for result in reader.passthrough_records() {
+            let record = result?;
+            writer.write_passthrough_record(&src_header, &record)?;
+        }
+
+        drop(writer);
+
+        let mut reader =
+            reader::Builder::default().build_from_reader(io::Cursor::new(buf.0.take()))?;
+        let header = reader.read_header()?;
+
+        let names: Vec<_> = reader
+            .records(&header)
+            .map(|result| {
+                result.and_then(|record| record.reference_sequence_name(&header).map(String::from))
+            })
+            .collect::<io::Result<_>>()?;
+
+        assert_eq!(names, ["sq0"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_passthrough_record_without_header() -> io::Result<()> {
+        let src = b"##fileformat=VCFv4.4
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = reader::Builder::default().build_from_reader(&src[..])?;
+        let header = reader.read_header()?;
+
+        let mut writer = Builder::default()
+            .set_format(Format::Vcf)
+            .build_from_writer(io::sink());
+
+        for result in reader.passthrough_records() {
+            let record = result?;
+
+            assert!(matches!(
+                writer.write_passthrough_record(&header, &record),
+                Err(e) if e.kind() == io::ErrorKind::InvalidInput
+            ));
+        }
+
+        Ok(())
     }
 }
@@ -8,7 +8,7 @@ use noodles_bcf as bcf;
 use noodles_bgzf as bgzf;
 use noodles_vcf as vcf;
 
-use super::Writer;
+use super::{Inner, Writer};
 use crate::variant::io::{CompressionMethod, Format};
 
 /// A variant writer builder.
@@ -101,16 +101,29 @@ impl Builder {
             },
         };
 
-        let inner: Box<dyn vcf::variant::io::Write> = match (format, compression_method) {
-            (Format::Vcf, None) => Box::new(vcf::io::Writer::new(writer)),
+        let inner = match (format, compression_method) {
+            (Format::Vcf, None) => {
+                let inner: Box<dyn Write> = Box::new(writer);
+                Inner::Vcf(vcf::io::Writer::new(inner))
+            }
             (Format::Vcf, Some(CompressionMethod::Bgzf)) => {
-                Box::new(vcf::io::Writer::new(bgzf::Writer::new(writer)))
+                let inner: Box<dyn Write> = Box::new(bgzf::Writer::new(writer));
+                Inner::Vcf(vcf::io::Writer::new(inner))
+            }
+            (Format::Bcf, None) => {
+                let inner: Box<dyn Write> = Box::new(writer);
+                Inner::Bcf(bcf::io::Writer::from(inner))
+            }
+            (Format::Bcf, Some(CompressionMethod::Bgzf)) => {
+                let inner: Box<dyn Write> = Box::new(bgzf::Writer::new(writer));
+                Inner::Bcf(bcf::io::Writer::from(inner))
             }
-            (Format::Bcf, None) => Box::new(bcf::io::Writer::from(writer)),
-            (Format::Bcf, Some(CompressionMethod::Bgzf)) => Box::new(bcf::io::Writer::new(writer)),
         };
 
-        Writer { inner }
+        Writer {
+            inner,
+            header: None,
+        }
     }
 }
 
@@ -0,0 +1,5 @@
+//! Composable iterators for variant records.
+
+mod downsample;
+
+pub use self::downsample::Downsample;
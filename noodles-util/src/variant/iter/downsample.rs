@@ -0,0 +1,175 @@
+use std::io::{self, Write};
+
+use noodles_vcf::{self as vcf, variant::Record};
+
+use crate::downsample::Sampler;
+
+/// A deterministic downsampling iterator.
+///
+/// This takes an iterator of records and keeps a fraction of them, chosen by a seeded hash of
+/// their `CHROM:POS:REF:ALT` keys. The same seed always keeps the same subset.
+///
+/// Multiple alternate bases are joined by a comma (`,`) in the key.
+pub struct Downsample<'h, I> {
+    header: &'h vcf::Header,
+    records: I,
+    sampler: Sampler,
+    buf: Vec<u8>,
+}
+
+impl<'h, I> Downsample<'h, I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    /// Creates a downsampling iterator.
+    ///
+    /// `fraction` is the expected fraction of records to keep and must be in `[0.0, 1.0]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::io;
+    /// use noodles_util::variant::iter::Downsample;
+    /// use noodles_vcf as vcf;
+    /// let header = vcf::Header::default();
+    /// let records = Vec::new().into_iter();
+    /// let downsample = Downsample::new(&header, records, 0.1, 8)?;
+    /// # Ok::<_, io::Error>(())
+    /// ```
+    pub fn new(header: &'h vcf::Header, records: I, fraction: f64, seed: u64) -> io::Result<Self> {
+        Sampler::new(fraction, seed).map(|sampler| Self {
+            header,
+            records,
+            sampler,
+            buf: Vec::new(),
+        })
+    }
+}
+
+impl<'h, I> Iterator for Downsample<'h, I>
+where
+    I: Iterator<Item = io::Result<Box<dyn Record>>>,
+{
+    type Item = io::Result<Box<dyn Record>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for result in &mut self.records {
+            let record = match result {
+                Ok(record) => record,
+                Err(e) => return Some(Err(e)),
+            };
+
+            if let Err(e) = write_key(&mut self.buf, self.header, record.as_ref()) {
+                return Some(Err(e));
+            }
+
+            if self.sampler.keep(&self.buf) {
+                return Some(Ok(record));
+            }
+        }
+
+        None
+    }
+}
+
+fn write_key(buf: &mut Vec<u8>, header: &vcf::Header, record: &dyn Record) -> io::Result<()> {
+    buf.clear();
+
+    let reference_sequence_name = record.reference_sequence_name(header)?;
+    write!(buf, "{reference_sequence_name}:")?;
+
+    if let Some(position) = record.variant_start().transpose()? {
+        write!(buf, "{position}")?;
+    }
+
+    buf.push(b':');
+
+    for result in record.reference_bases().iter() {
+        buf.push(result?);
+    }
+
+    buf.push(b':');
+
+    for (i, result) in record.alternate_bases().iter().enumerate() {
+        if i > 0 {
+            buf.push(b',');
+        }
+
+        buf.extend_from_slice(result?.as_bytes());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use noodles_core::Position;
+    use vcf::variant::{record_buf::AlternateBases, RecordBuf};
+
+    use super::*;
+
+    #[test]
+    fn test_write_key() -> Result<(), Box<dyn std::error::Error>> {
+        let header = vcf::Header::default();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("G"),
+            ]))
+            .build();
+
+        let mut buf = Vec::new();
+        write_key(&mut buf, &header, &record)?;
+        assert_eq!(buf, b"sq0:8:A:C,G");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_next() -> Result<(), Box<dyn std::error::Error>> {
+        const N: usize = 10000;
+
+        let header = vcf::Header::default();
+
+        let records = || {
+            (1..=N).map(|i| -> io::Result<Box<dyn Record>> {
+                let position = Position::try_from(i)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+
+                let record = RecordBuf::builder()
+                    .set_reference_sequence_name("sq0")
+                    .set_variant_start(position)
+                    .set_reference_bases("A")
+                    .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+                    .build();
+
+                Ok(Box::new(record) as Box<dyn Record>)
+            })
+        };
+
+        let downsample = |seed| -> io::Result<Vec<Position>> {
+            Downsample::new(&header, records(), 0.4, seed)?
+                .map(|result| {
+                    result.and_then(|record| {
+                        record
+                            .variant_start()
+                            .unwrap_or_else(|| Err(io::Error::from(io::ErrorKind::InvalidData)))
+                    })
+                })
+                .collect()
+        };
+
+        let actual = downsample(3)?;
+        assert_eq!(downsample(3)?, actual);
+        assert_ne!(downsample(34)?, actual);
+
+        let fraction = actual.len() as f64 / N as f64;
+        assert!((fraction - 0.4).abs() < 0.02, "fraction = {fraction}");
+
+        Ok(())
+    }
+}
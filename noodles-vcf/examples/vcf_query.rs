@@ -25,6 +25,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let stdout = io::stdout().lock();
     let mut writer = vcf::io::Writer::new(BufWriter::new(stdout));
+    writer.write_header(&header)?;
 
     for result in query {
         let record = result?;
@@ -0,0 +1,228 @@
+//! Async indexed VCF reader.
+
+use futures::Stream;
+use noodles_bgzf as bgzf;
+use noodles_core::Region;
+use noodles_csi::BinningIndex;
+use tokio::io::{self, AsyncBufRead, AsyncRead, AsyncSeek};
+
+use super::Reader;
+use crate::{variant::RecordBuf, Header, Record};
+
+/// An async indexed VCF reader.
+///
+/// This wraps an async VCF reader over a bgzipped input and its associated index (tabix or
+/// CSI), which is used to seek to the records that intersect a queried region.
+///
+/// # Examples
+///
+/// ```no_run
+/// # #[tokio::main]
+/// # async fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// use futures::TryStreamExt;
+/// use noodles_tabix as tabix;
+/// use noodles_vcf as vcf;
+/// use tokio::fs::File;
+///
+/// let index = tabix::r#async::read("sample.vcf.gz.tbi").await?;
+/// let mut reader = File::open("sample.vcf.gz")
+///     .await
+///     .map(|file| vcf::r#async::io::IndexedReader::new(file, index))?;
+///
+/// let header = reader.read_header().await?;
+///
+/// let region = "sq0:8-13".parse()?;
+/// let mut query = reader.query(&header, &region)?;
+///
+/// while let Some(record) = query.try_next().await? {
+///     // ...
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct IndexedReader<R> {
+    inner: Reader<R>,
+    index: Box<dyn BinningIndex>,
+}
+
+impl<R> IndexedReader<R> {
+    /// Returns a reference to the underlying reader.
+    pub fn get_ref(&self) -> &R {
+        self.inner.get_ref()
+    }
+
+    /// Returns a mutable reference to the underlying reader.
+    pub fn get_mut(&mut self) -> &mut R {
+        self.inner.get_mut()
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.inner.into_inner()
+    }
+
+    /// Returns the associated index.
+    pub fn index(&self) -> &dyn BinningIndex {
+        &self.index
+    }
+}
+
+impl<R> IndexedReader<R>
+where
+    R: AsyncBufRead + Unpin,
+{
+    /// Reads the VCF header.
+    pub async fn read_header(&mut self) -> io::Result<Header> {
+        self.inner.read_header().await
+    }
+
+    /// Reads a single record into a VCF record buffer.
+    pub async fn read_record_buf(
+        &mut self,
+        header: &Header,
+        record: &mut RecordBuf,
+    ) -> io::Result<usize> {
+        self.inner.read_record_buf(header, record).await
+    }
+
+    /// Reads a single record without eagerly parsing its fields.
+    pub async fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
+        self.inner.read_record(record).await
+    }
+
+    /// Returns a stream over records starting from the current stream position.
+    pub fn records(&mut self) -> impl Stream<Item = io::Result<Record>> + '_ {
+        self.inner.records()
+    }
+}
+
+impl<R> IndexedReader<bgzf::AsyncReader<R>>
+where
+    R: AsyncRead + AsyncSeek + Unpin,
+{
+    /// Returns a stream over records that intersect the given region.
+    ///
+    /// The stream ends after the last record that starts within the region on the reference
+    /// sequence, rather than reading to the end of the final index chunk.
+    pub fn query<'r>(
+        &'r mut self,
+        header: &'r Header,
+        region: &Region,
+    ) -> io::Result<impl Stream<Item = io::Result<Record>> + 'r> {
+        self.inner.query(header, &self.index, region)
+    }
+}
+
+impl<R> IndexedReader<bgzf::AsyncReader<R>>
+where
+    R: AsyncRead + Unpin,
+{
+    /// Creates an async indexed VCF reader.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_tabix as tabix;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let index = tabix::Index::default();
+    /// let reader = vcf::r#async::io::IndexedReader::new(tokio::io::empty(), index);
+    /// ```
+    pub fn new<I>(inner: R, index: I) -> Self
+    where
+        I: BinningIndex + 'static,
+    {
+        Self {
+            inner: Reader::new(bgzf::AsyncReader::new(inner)),
+            index: Box::new(index),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::TryStreamExt;
+    use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+    use noodles_tabix as tabix;
+
+    use super::*;
+    use crate::variant::Record as _;
+
+    fn build_data_and_index() -> std::io::Result<(Vec<u8>, tabix::Index)> {
+        use std::io::Write;
+
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t8\t.\tACGT\t.\t.\tPASS\t.
+sq0\t13\t.\tC\t.\t.\tPASS\t.
+sq0\t21\t.\tG\t.\t.\tPASS\t.
+sq1\t5\t.\tT\t.\t.\tPASS\t.
+";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let data = writer.finish()?;
+
+        let mut reader = crate::io::Reader::new(bgzf::Reader::new(&data[..]));
+        let header = reader.read_header()?;
+
+        let mut indexer = tabix::index::Indexer::default();
+        indexer.set_header(csi::binning_index::index::header::Builder::vcf().build());
+
+        let mut record = Record::default();
+        let mut start_position = reader.get_ref().virtual_position();
+
+        while reader.read_record(&mut record)? != 0 {
+            let end_position = reader.get_ref().virtual_position();
+            let chunk = Chunk::new(start_position, end_position);
+
+            let start = record.variant_start().transpose()?.expect("missing start");
+            let end = record.variant_end(&header)?;
+
+            indexer.add_record(record.reference_sequence_name(), start, end, chunk)?;
+
+            start_position = end_position;
+        }
+
+        Ok((data, indexer.build()))
+    }
+
+    #[tokio::test]
+    async fn test_query() -> Result<(), Box<dyn std::error::Error>> {
+        let (data, index) = build_data_and_index()?;
+
+        let mut sync_reader =
+            crate::io::IndexedReader::new(std::io::Cursor::new(data.clone()), index.clone());
+        let header = sync_reader.read_header()?;
+
+        let mut reader = IndexedReader::new(std::io::Cursor::new(data), index);
+        reader.read_header().await?;
+
+        for raw_region in ["sq0:10-13", "sq0:2-8", "sq0", "sq1:5", "sq1:6-8"] {
+            let region = raw_region.parse()?;
+
+            let expected: Vec<_> = sync_reader
+                .query(&header, &region)?
+                .collect::<std::io::Result<_>>()?;
+
+            let actual: Vec<_> = reader.query(&header, &region)?.try_collect().await?;
+
+            assert_eq!(actual, expected, "{raw_region}");
+        }
+
+        let region = "sq0:10-13".parse()?;
+        let reference_bases: Vec<_> = reader
+            .query(&header, &region)?
+            .map_ok(|record| String::from(record.reference_bases()))
+            .try_collect()
+            .await?;
+
+        assert_eq!(reference_bases, ["ACGT", "C"]);
+
+        Ok(())
+    }
+}
@@ -193,7 +193,7 @@ where
         match read_line(&mut self.inner, &mut self.buf).await? {
             0 => Ok(0),
             n => {
-                parse_record_buf(&self.buf, header, record)
+                parse_record_buf(&self.buf, header, record, None)
                     .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
 
                 Ok(n)
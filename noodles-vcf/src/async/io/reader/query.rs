@@ -72,6 +72,11 @@ where
                             ctx.state = State::Seek;
                         }
 
+                        if is_past_interval(&record, &ctx.reference_sequence_name, ctx.interval)? {
+                            ctx.state = State::Done;
+                            continue;
+                        }
+
                         if intersects(
                             ctx.header,
                             &record,
@@ -89,6 +94,24 @@ where
     }))
 }
 
+// Records are sorted by position, so once a record on the same reference sequence starts after
+// the end of the region, no subsequent records can intersect it.
+fn is_past_interval(
+    record: &Record,
+    reference_sequence_name: &[u8],
+    interval: Interval,
+) -> io::Result<bool> {
+    if record.reference_sequence_name().as_bytes() != reference_sequence_name {
+        return Ok(false);
+    }
+
+    let (Some(start), Some(end)) = (record.variant_start().transpose()?, interval.end()) else {
+        return Ok(false);
+    };
+
+    Ok(start > end)
+}
+
 async fn next_record<R>(reader: &mut Reader<bgzf::AsyncReader<R>>) -> io::Result<Option<Record>>
 where
     R: AsyncRead + Unpin,
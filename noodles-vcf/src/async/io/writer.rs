@@ -1,6 +1,9 @@
 use tokio::io::{self, AsyncWrite, AsyncWriteExt};
 
-use crate::{variant::io::Write, Header, Record};
+use crate::{
+    io::writer::{write_header, write_record, HeaderOptions, RecordOptions},
+    Header, Record,
+};
 
 /// An async VCF writer.
 ///
@@ -8,6 +11,7 @@ use crate::{variant::io::Write, Header, Record};
 /// dropped.
 pub struct Writer<W> {
     inner: W,
+    is_header_written: bool,
 }
 
 impl<W> Writer<W>
@@ -23,7 +27,10 @@ where
     /// let writer = vcf::r#async::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            is_header_written: false,
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -86,6 +93,9 @@ where
 
     /// Writes a VCF header.
     ///
+    /// The header can only be written once, and it must be written before any records. Writing it
+    /// again returns an [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -103,13 +113,26 @@ where
     /// # }
     /// ```
     pub async fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        let mut writer = crate::io::Writer::new(Vec::new());
-        writer.write_header(header)?;
-        self.inner.write_all(writer.get_ref()).await
+        if self.is_header_written {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header already written",
+            ));
+        }
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, header, &HeaderOptions::default())?;
+        self.inner.write_all(&buf).await?;
+        self.is_header_written = true;
+
+        Ok(())
     }
 
     /// Writes a VCF record.
     ///
+    /// The header must be written first (see [`Self::write_header`]). Otherwise, this returns an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -121,20 +144,28 @@ where
     /// let mut writer = vcf::r#async::io::Writer::new(Vec::new());
     ///
     /// let header = vcf::Header::default();
+    /// writer.write_header(&header).await?;
+    ///
     /// let record = vcf::Record::default();
     /// writer.write_record(&header, &record).await?;
     /// # Ok(())
     /// # }
     /// ```
     pub async fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        let mut writer = crate::io::Writer::new(Vec::new());
-        writer.write_record(header, record)?;
-        self.inner.write_all(writer.get_ref()).await?;
+        self.validate_header_is_written()?;
+
+        let mut buf = Vec::new();
+        let options = RecordOptions::default();
+        write_record(&mut buf, header, record, &options)?;
+        self.inner.write_all(&buf).await?;
         Ok(())
     }
 
     /// Writes a variant record.
     ///
+    /// The header must be written first (see [`Self::write_header`]). Otherwise, this returns an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -152,6 +183,7 @@ where
     ///     .build();
     ///
     /// let mut writer = vcf::r#async::io::Writer::new(Vec::new());
+    /// writer.write_header(&header).await?;
     /// writer.write_variant_record(&header, &record).await?;
     /// # Ok(())
     /// # }
@@ -161,9 +193,64 @@ where
         header: &Header,
         record: &dyn crate::variant::Record,
     ) -> io::Result<()> {
-        let mut writer = crate::io::Writer::new(Vec::new());
-        writer.write_variant_record(header, record)?;
-        self.inner.write_all(writer.get_ref()).await?;
+        self.validate_header_is_written()?;
+
+        let mut buf = Vec::new();
+        let options = RecordOptions::default();
+        write_record(&mut buf, header, record, &options)?;
+        self.inner.write_all(&buf).await?;
+        Ok(())
+    }
+
+    fn validate_header_is_written(&self) -> io::Result<()> {
+        if self.is_header_written {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header not written",
+            ))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variant::RecordBuf;
+
+    #[tokio::test]
+    async fn test_write_header_twice() -> io::Result<()> {
+        let header = Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header).await?;
+
+        assert!(matches!(
+            writer.write_header(&header).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_write_record_before_header() -> io::Result<()> {
+        let header = Header::default();
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            writer.write_record(&header, &Record::default()).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &RecordBuf::default()).await,
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(writer.get_ref().is_empty());
+
         Ok(())
     }
 }
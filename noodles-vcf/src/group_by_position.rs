@@ -0,0 +1,236 @@
+use std::{collections::HashSet, io, iter};
+
+use noodles_core::Position;
+
+use crate::variant::RecordBuf;
+
+/// Groups records from a coordinate-sorted record stream by locus.
+///
+/// Each item is a batch of consecutive records that share the same reference sequence (contig)
+/// name and position.
+///
+/// The input must be coordinate-sorted, i.e., records on the same contig are contiguous and
+/// ordered by position. An out-of-order record returns an [`io::ErrorKind::InvalidData`] error.
+///
+/// When an error occurs, the batch read before it is returned first. Iteration stops after the
+/// error.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf as vcf;
+///
+/// const SRC: &[u8] = b"##fileformat=VCFv4.3
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t1\t.\tA\t.\t.\tPASS\t.
+/// sq0\t1\t.\tA\tC\t.\tPASS\t.
+/// sq0\t8\t.\tA\t.\t.\tPASS\t.
+/// ";
+///
+/// let mut reader = vcf::io::Reader::new(SRC);
+/// let header = reader.read_header()?;
+///
+/// for result in vcf::group_by_position(reader.record_bufs(&header)) {
+///     let records = result?;
+///     // ...
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn group_by_position<I>(records: I) -> impl Iterator<Item = io::Result<Vec<RecordBuf>>>
+where
+    I: IntoIterator<Item = io::Result<RecordBuf>>,
+{
+    let mut records = records.into_iter();
+    let mut pending: Option<RecordBuf> = None;
+    let mut visited_reference_sequence_names = HashSet::new();
+    let mut error = None;
+    let mut is_done = false;
+
+    iter::from_fn(move || {
+        if is_done {
+            return None;
+        }
+
+        if let Some(e) = error.take() {
+            is_done = true;
+            return Some(Err(e));
+        }
+
+        let first = match pending.take() {
+            Some(record) => record,
+            None => match records.next()? {
+                Ok(record) => record,
+                Err(e) => {
+                    is_done = true;
+                    return Some(Err(e));
+                }
+            },
+        };
+
+        let (reference_sequence_name, position) = locus(&first);
+        let key = (reference_sequence_name.to_string(), position);
+        let mut batch = vec![first];
+
+        for result in records.by_ref() {
+            let record = match result {
+                Ok(record) => record,
+                Err(e) => {
+                    error = Some(e);
+                    break;
+                }
+            };
+
+            let next_key = locus(&record);
+
+            if next_key == (key.0.as_str(), key.1) {
+                batch.push(record);
+                continue;
+            }
+
+            let is_unsorted = if next_key.0 == key.0 {
+                next_key.1 < key.1
+            } else {
+                visited_reference_sequence_names.insert(key.0.clone());
+                visited_reference_sequence_names.contains(next_key.0)
+            };
+
+            if is_unsorted {
+                error = Some(unsorted_error(&record));
+            } else {
+                pending = Some(record);
+            }
+
+            break;
+        }
+
+        Some(Ok(batch))
+    })
+}
+
+fn locus(record: &RecordBuf) -> (&str, Option<Position>) {
+    (record.reference_sequence_name(), record.variant_start())
+}
+
+fn unsorted_error(record: &RecordBuf) -> io::Error {
+    let (reference_sequence_name, position) = locus(record);
+
+    let position = position
+        .map(|position| position.to_string())
+        .unwrap_or_default();
+
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("unsorted record: {reference_sequence_name}:{position}"),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::io::Reader;
+
+    #[test]
+    fn test_group_by_position() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tr0\tA\t.\t.\tPASS\t.
+sq0\t1\tr1\tA\tC\t.\tPASS\t.
+sq0\t8\tr2\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(SRC);
+        let header = reader.read_header()?;
+
+        let batches: Vec<_> =
+            group_by_position(reader.record_bufs(&header)).collect::<io::Result<_>>()?;
+
+        let actual: Vec<Vec<_>> = batches
+            .iter()
+            .map(|records| {
+                records
+                    .iter()
+                    .map(|record| {
+                        record
+                            .ids()
+                            .as_ref()
+                            .iter()
+                            .next()
+                            .map(String::as_str)
+                            .unwrap_or_default()
+                    })
+                    .collect()
+            })
+            .collect();
+
+        assert_eq!(actual, [vec!["r0", "r1"], vec!["r2"]]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_position_with_unsorted_input() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t8\t.\tA\t.\t.\tPASS\t.
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(SRC);
+        let header = reader.read_header()?;
+        let mut batches = group_by_position(reader.record_bufs(&header));
+
+        assert!(matches!(batches.next(), Some(Ok(records)) if records.len() == 1));
+        assert!(matches!(
+            batches.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(batches.next().is_none());
+
+        const SRC_WITH_SPLIT_CONTIG: &[u8] = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq1\t1\t.\tA\t.\t.\tPASS\t.
+sq0\t8\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(SRC_WITH_SPLIT_CONTIG);
+        let header = reader.read_header()?;
+        let mut batches = group_by_position(reader.record_bufs(&header));
+
+        assert!(matches!(batches.next(), Some(Ok(_))));
+        assert!(matches!(batches.next(), Some(Ok(_))));
+        assert!(matches!(
+            batches.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(batches.next().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_by_position_with_invalid_record() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tr0\tA\t.\t.\tPASS\t.
+sq0\t1\tr1\tA\tC\t.\tPASS\t.
+sq0\tndls\tr2\tA\t.\t.\tPASS\t.
+sq0\t8\tr3\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(SRC);
+        let header = reader.read_header()?;
+        let mut batches = group_by_position(reader.record_bufs(&header));
+
+        let records = batches.next().transpose()?.expect("missing batch");
+        assert_eq!(records.len(), 2);
+
+        assert!(matches!(
+            batches.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+        assert!(batches.next().is_none());
+
+        Ok(())
+    }
+}
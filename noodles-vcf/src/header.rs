@@ -11,13 +11,13 @@ pub use self::{
     string_maps::StringMaps,
 };
 
-use std::{hash::Hash, str::FromStr};
+use std::{collections::HashSet, hash::Hash, io, str::FromStr};
 
 use indexmap::{IndexMap, IndexSet};
 
 use self::record::value::{
-    map::{AlternativeAllele, Contig, Filter, Format, Info},
-    Map,
+    map::{AlternativeAllele, Contig, Filter, Format, Info, Other},
+    Collection, Map,
 };
 
 /// VCF header info records.
@@ -338,6 +338,80 @@ impl Header {
         &mut self.contigs
     }
 
+    /// Returns a copy of this header with its contig records reordered.
+    ///
+    /// The contigs are placed in the order of the given names, e.g., the reference sequence names
+    /// of a FASTA index. Contig indices (`IDX`) are cleared so that the new order is also used for
+    /// the contig string map.
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if `order` has duplicate names, declared or not. If `strict` is set, an
+    /// error is also returned if a declared contig is missing from `order` or if `order` has a
+    /// name that is not a declared contig. Otherwise, undeclared names are skipped, and declared
+    /// contigs missing from `order` are placed after the others in their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{self as vcf, header::record::value::{map::Contig, Map}};
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_contig("sq0", Map::<Contig>::new())
+    ///     .add_contig("sq1", Map::<Contig>::new())
+    ///     .build();
+    ///
+    /// let header = header.reorder_contigs(&["sq1", "sq0"], true)?;
+    /// assert!(header.contigs().keys().eq(["sq1", "sq0"]));
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn reorder_contigs(&self, order: &[&str], strict: bool) -> io::Result<Self> {
+        let mut contigs = Contigs::with_capacity(self.contigs.len());
+        let mut names = HashSet::with_capacity(order.len());
+
+        for &name in order {
+            if !names.insert(name) {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("duplicate contig: {name}"),
+                ));
+            }
+
+            if let Some(contig) = self.contigs.get(name) {
+                contigs.insert(name.into(), contig.clone());
+            } else if strict {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("undeclared contig: {name}"),
+                ));
+            }
+        }
+
+        for (name, contig) in &self.contigs {
+            if contigs.contains_key(name) {
+                continue;
+            } else if strict {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("missing contig in order: {name}"),
+                ));
+            }
+
+            contigs.insert(name.clone(), contig.clone());
+        }
+
+        for contig in contigs.values_mut() {
+            *contig.idx_mut() = None;
+        }
+
+        let mut header = self.clone();
+        header.contigs = contigs;
+        header.string_maps = StringMaps::try_from(&header)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        Ok(header)
+    }
+
     /// Returns a list of sample names that come after the FORMAT column in the header record.
     ///
     /// # Examples
@@ -383,6 +457,41 @@ impl Header {
         &mut self.sample_names
     }
 
+    /// Adds a sample name to the end of the list of sample names.
+    ///
+    /// The index of the added sample name is returned. This is the index of the sample values
+    /// added to a record using [`crate::variant::RecordBuf::push_sample`].
+    ///
+    /// # Errors
+    ///
+    /// An error is returned if the sample name already exists.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf as vcf;
+    ///
+    /// let mut header = vcf::Header::builder().add_sample_name("sample0").build();
+    /// assert_eq!(header.add_sample_name("sample1")?, 1);
+    /// assert!(header.add_sample_name("sample1").is_err());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn add_sample_name<N>(&mut self, sample_name: N) -> io::Result<usize>
+    where
+        N: Into<String>,
+    {
+        let (i, inserted) = self.sample_names.insert_full(sample_name.into());
+
+        if inserted {
+            Ok(i)
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "duplicate sample name",
+            ))
+        }
+    }
+
     /// Returns a map of records with nonstandard keys.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`, and
@@ -404,6 +513,41 @@ impl Header {
         &self.other_records
     }
 
+    /// Returns the pedigree records (`PEDIGREE`).
+    ///
+    /// This is a view of the structured collection of other records with the `PEDIGREE` key. If
+    /// there are no pedigree records or the collection is unstructured, this returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Other, Map},
+    /// };
+    ///
+    /// let header = vcf::Header::default();
+    /// assert!(header.pedigrees().is_none());
+    ///
+    /// let pedigree = Map::<Other>::builder()
+    ///     .insert("Original".parse()?, "PARENT")
+    ///     .build()?;
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_pedigree("CHILD", pedigree)
+    ///     .build();
+    ///
+    /// let pedigrees = header.pedigrees().expect("missing PEDIGREE records");
+    /// assert!(pedigrees.contains_key("CHILD"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn pedigrees(&self) -> Option<&IndexMap<String, Map<Other>>> {
+        match self.other_records.get(record::key::other::PEDIGREE) {
+            Some(Collection::Structured(maps)) => Some(maps),
+            _ => None,
+        }
+    }
+
     /// Returns a mutable reference to a map of collections of records with nonstandard keys.
     ///
     /// This includes all records other than `fileformat`, `INFO`, `FILTER`, `FORMAT`, `ALT`, and
@@ -555,4 +699,97 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        let s = "\
+##fileformat=VCFv4.3
+##contig=<ID=sq0,length=8>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0\tsample1
+";
+
+        let header: Header = s.parse()?;
+
+        assert_eq!(header.file_format(), FileFormat::new(4, 3));
+        assert!(header.contigs().contains_key("sq0"));
+
+        let expected: SampleNames = [String::from("sample0"), String::from("sample1")]
+            .into_iter()
+            .collect();
+        assert_eq!(header.sample_names(), &expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_pedigrees_round_trip() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::io::Writer;
+
+        let src = "\
+##fileformat=VCFv4.2
+##PEDIGREE=<Derived=CHILD,Original=PARENT>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+";
+
+        let header: Header = src.parse()?;
+
+        let pedigrees = header.pedigrees().ok_or("missing PEDIGREE records")?;
+        let pedigree = pedigrees.get("CHILD").ok_or("missing CHILD")?;
+        assert_eq!(pedigree.id_tag().as_ref(), "Derived");
+        assert_eq!(
+            pedigree.other_fields().get("Original").map(|v| v.as_str()),
+            Some("PARENT")
+        );
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        assert_eq!(writer.get_ref(), src.as_bytes());
+
+        let mut map = Map::<Other>::builder()
+            .insert("Original".parse()?, "PARENT")
+            .build()?;
+        *map.id_tag_mut() = record::value::map::other::Tag::from("Derived");
+
+        let header = Header::builder()
+            .set_file_format(FileFormat::new(4, 2))
+            .add_pedigree("CHILD", map)
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        assert_eq!(writer.get_ref(), src.as_bytes());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reorder_contigs() -> io::Result<()> {
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_contig("sq1", Map::<Contig>::new())
+            .add_contig("sq2", Map::<Contig>::new())
+            .build();
+
+        let actual = header.reorder_contigs(&["sq2", "sq0", "sq1"], true)?;
+        assert!(actual.contigs().keys().eq(["sq2", "sq0", "sq1"]));
+        assert_eq!(actual.string_maps().contigs().get_index_of("sq2"), Some(0));
+        assert_eq!(actual.string_maps().contigs().get_index_of("sq1"), Some(2));
+
+        let result = header.reorder_contigs(&["sq2", "sq0"], true);
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidInput));
+
+        let result = header.reorder_contigs(&["sq2", "sq0", "sq1", "sq3"], true);
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidInput));
+
+        let actual = header.reorder_contigs(&["sq3", "sq2", "sq0"], false)?;
+        assert!(actual.contigs().keys().eq(["sq2", "sq0", "sq1"]));
+
+        let result = header.reorder_contigs(&["sq2", "sq2", "sq0", "sq1"], false);
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidInput));
+
+        let result = header.reorder_contigs(&["sq3", "sq2", "sq3", "sq0", "sq1"], false);
+        assert!(matches!(result, Err(e) if e.kind() == io::ErrorKind::InvalidInput));
+
+        Ok(())
+    }
 }
@@ -2,8 +2,8 @@ use super::{
     record::{
         self,
         value::{
-            map::{AlternativeAllele, Contig, Filter, Format, Info},
-            Map,
+            map::{AlternativeAllele, Contig, Filter, Format, Info, Other},
+            Collection, Map,
         },
     },
     AlternativeAlleles, Contigs, FileFormat, Filters, Formats, Header, Infos, OtherRecords,
@@ -188,6 +188,56 @@ impl Builder {
         self
     }
 
+    /// Adds a pedigree record (`PEDIGREE`).
+    ///
+    /// The record is added to the other records with the `PEDIGREE` key. The ID field tag
+    /// defaults to `ID` but can be changed, e.g., to `Derived` for VCF < 4.3, using
+    /// [`Map::<Other>::id_tag_mut`]. If the existing `PEDIGREE` collection is unstructured, it is
+    /// replaced.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::{other::Tag, Other}, Map},
+    /// };
+    ///
+    /// let mut pedigree = Map::<Other>::builder()
+    ///     .insert("Original".parse()?, "PARENT")
+    ///     .build()?;
+    /// *pedigree.id_tag_mut() = Tag::from("Derived");
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_pedigree("CHILD", pedigree.clone())
+    ///     .build();
+    ///
+    /// let pedigrees = header.pedigrees().expect("missing PEDIGREE records");
+    /// assert_eq!(pedigrees.get("CHILD"), Some(&pedigree));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn add_pedigree<I>(mut self, id: I, pedigree: Map<Other>) -> Self
+    where
+        I: Into<String>,
+    {
+        let collection = self
+            .other_records
+            .entry(record::key::Other::pedigree())
+            .or_insert_with(|| Collection::Structured(IndexMap::new()));
+
+        match collection {
+            Collection::Structured(maps) => {
+                maps.insert(id.into(), pedigree);
+            }
+            Collection::Unstructured(_) => {
+                let maps = [(id.into(), pedigree)].into_iter().collect();
+                *collection = Collection::Structured(maps);
+            }
+        }
+
+        self
+    }
+
     /// Sets sample names.
     ///
     /// # Examples
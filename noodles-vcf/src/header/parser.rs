@@ -163,6 +163,11 @@ pub enum ParseError {
     /// The position of the entry in the string match does not match the absolute position defined
     /// by the `IDX` field of a record.
     StringMapPositionMismatch((usize, String), (usize, String)),
+    /// The absolute position defined by the `IDX` field of a record is already used by another
+    /// entry in the string map.
+    ///
+    /// This holds the position, the existing entry, and the entry that collides with it.
+    DuplicateStringMapPosition(usize, String, String),
 }
 
 impl error::Error for ParseError {
@@ -203,6 +208,10 @@ impl std::fmt::Display for ParseError {
                 "string map position mismatch: expected {} (IDX={}), got {} (IDX={})",
                 expected.1, expected.0, actual.1, actual.0,
             ),
+            Self::DuplicateStringMapPosition(i, existing_id, id) => write!(
+                f,
+                "duplicate string map position: IDX={i} is used by both {existing_id} and {id}"
+            ),
         }
     }
 }
@@ -2,10 +2,18 @@
 
 use std::{borrow::Borrow, error, fmt, str::FromStr};
 
+pub(crate) const PEDIGREE: &str = "PEDIGREE";
+
 /// A nonstandard VCF record key.
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub struct Other(pub(super) String);
 
+impl Other {
+    pub(crate) fn pedigree() -> Self {
+        Self(String::from(PEDIGREE))
+    }
+}
+
 impl AsRef<str> for Other {
     fn as_ref(&self) -> &str {
         &self.0
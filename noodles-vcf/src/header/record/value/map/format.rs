@@ -10,6 +10,9 @@ pub use self::{number::Number, tag::Tag, ty::Type};
 use super::{builder, Described, Indexed, Inner, Map, OtherFields, Typed};
 use crate::header::FileFormat;
 
+const SOURCE: &str = "Source";
+const VERSION: &str = "Version";
+
 /// An inner VCF header format map value.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct Format {
@@ -93,6 +96,52 @@ impl Map<Format> {
             other_fields: OtherFields::new(),
         }
     }
+
+    /// Returns the source of the definition, i.e., the `Source` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{
+    ///     map::{format::{Number, Type}, Format},
+    ///     Map,
+    /// };
+    ///
+    /// let mut map = Map::<Format>::new(Number::Count(1), Type::Integer, "Depth");
+    /// assert!(map.source().is_none());
+    ///
+    /// let tag = "Source".parse()?;
+    ///
+    /// map.other_fields_mut().insert(tag, String::from("caller"));
+    /// assert_eq!(map.source(), Some("caller"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn source(&self) -> Option<&str> {
+        self.other_fields.get(SOURCE).map(|s| s.as_str())
+    }
+
+    /// Returns the version of the source of the definition, i.e., the `Version` field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{
+    ///     map::{format::{Number, Type}, Format},
+    ///     Map,
+    /// };
+    ///
+    /// let mut map = Map::<Format>::new(Number::Count(1), Type::Integer, "Depth");
+    /// assert!(map.version().is_none());
+    ///
+    /// let tag = "Version".parse()?;
+    ///
+    /// map.other_fields_mut().insert(tag, String::from("1.0"));
+    /// assert_eq!(map.version(), Some("1.0"));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn version(&self) -> Option<&str> {
+        self.other_fields.get(VERSION).map(|s| s.as_str())
+    }
 }
 
 impl From<&str> for Map<Format> {
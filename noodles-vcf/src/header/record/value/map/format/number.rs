@@ -23,6 +23,47 @@ pub enum Number {
     Unknown,
 }
 
+impl Number {
+    /// Returns the expected number of values given the number of alternate alleles and the
+    /// ploidy.
+    ///
+    /// `A` is the number of alternate alleles; `R`, the number of alleles, including the
+    /// reference; `G`, the number of possible genotypes; and `P`, the ploidy. This returns `None`
+    /// if the number is unknown (`.`), depends on the record's local alleles (`LA`, `LR`, `LG`)
+    /// or base modifications (`M`), or the count overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::map::format::Number;
+    ///
+    /// assert_eq!(Number::Count(1).expected_count(2, 2), Some(1));
+    /// assert_eq!(Number::AlternateBases.expected_count(2, 2), Some(2));
+    /// assert_eq!(Number::ReferenceAlternateBases.expected_count(2, 2), Some(3));
+    /// assert_eq!(Number::Samples.expected_count(2, 2), Some(6));
+    /// assert_eq!(Number::Ploidy.expected_count(2, 2), Some(2));
+    /// assert!(Number::Unknown.expected_count(2, 2).is_none());
+    /// ```
+    pub fn expected_count(&self, alternate_allele_count: usize, ploidy: usize) -> Option<usize> {
+        use crate::header::record::value::map::info::number::genotype_count;
+
+        match self {
+            Self::Count(n) => Some(*n),
+            Self::AlternateBases => Some(alternate_allele_count),
+            Self::ReferenceAlternateBases => alternate_allele_count.checked_add(1),
+            Self::Samples => alternate_allele_count
+                .checked_add(1)
+                .and_then(|allele_count| genotype_count(allele_count, ploidy)),
+            Self::Ploidy => Some(ploidy),
+            Self::LocalAlternateBases
+            | Self::LocalReferenceAlternateBases
+            | Self::LocalSamples
+            | Self::BaseModifications
+            | Self::Unknown => None,
+        }
+    }
+}
+
 impl Default for Number {
     fn default() -> Self {
         Self::Count(1)
@@ -37,4 +78,25 @@ mod tests {
     fn test_default() {
         assert_eq!(Number::default(), Number::Count(1));
     }
+
+    #[test]
+    fn test_expected_count() {
+        assert_eq!(Number::Count(3).expected_count(2, 2), Some(3));
+        assert_eq!(Number::AlternateBases.expected_count(2, 2), Some(2));
+        assert_eq!(
+            Number::ReferenceAlternateBases.expected_count(2, 2),
+            Some(3)
+        );
+        assert_eq!(Number::Samples.expected_count(2, 2), Some(6));
+        assert_eq!(Number::Samples.expected_count(1, 1), Some(2));
+        assert_eq!(Number::Ploidy.expected_count(2, 3), Some(3));
+
+        assert!(Number::LocalAlternateBases.expected_count(2, 2).is_none());
+        assert!(Number::LocalReferenceAlternateBases
+            .expected_count(2, 2)
+            .is_none());
+        assert!(Number::LocalSamples.expected_count(2, 2).is_none());
+        assert!(Number::BaseModifications.expected_count(2, 2).is_none());
+        assert!(Number::Unknown.expected_count(2, 2).is_none());
+    }
 }
@@ -22,6 +22,50 @@ impl Number {
 
     /// The number of samples (`G`).
     pub const G: Self = Self::Samples;
+
+    /// Returns the expected number of values given the number of alternate alleles and the
+    /// ploidy.
+    ///
+    /// `A` is the number of alternate alleles; `R`, the number of alleles, including the
+    /// reference; and `G`, the number of possible genotypes. This returns `None` if the number is
+    /// unknown (`.`) or the count overflows.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::map::info::Number;
+    ///
+    /// assert_eq!(Number::Count(1).expected_count(2, 2), Some(1));
+    /// assert_eq!(Number::A.expected_count(2, 2), Some(2));
+    /// assert_eq!(Number::R.expected_count(2, 2), Some(3));
+    /// assert_eq!(Number::G.expected_count(2, 2), Some(6));
+    /// assert!(Number::Unknown.expected_count(2, 2).is_none());
+    /// ```
+    pub fn expected_count(&self, alternate_allele_count: usize, ploidy: usize) -> Option<usize> {
+        match self {
+            Self::Count(n) => Some(*n),
+            Self::AlternateBases => Some(alternate_allele_count),
+            Self::ReferenceAlternateBases => alternate_allele_count.checked_add(1),
+            Self::Samples => alternate_allele_count
+                .checked_add(1)
+                .and_then(|allele_count| genotype_count(allele_count, ploidy)),
+            Self::Unknown => None,
+        }
+    }
+}
+
+/// Returns the number of unordered genotypes for the given number of alleles and ploidy.
+///
+/// This is the number of multisets of size `ploidy` from `allele_count` alleles, i.e.,
+/// `(allele_count + ploidy - 1) choose ploidy`.
+pub(crate) fn genotype_count(allele_count: usize, ploidy: usize) -> Option<usize> {
+    let mut n: usize = 1;
+
+    for i in 1..=ploidy {
+        n = n.checked_mul(allele_count.checked_add(i - 1)?)? / i;
+    }
+
+    Some(n)
 }
 
 impl Default for Number {
@@ -38,4 +82,35 @@ mod tests {
     fn test_default() {
         assert_eq!(Number::default(), Number::Count(1));
     }
+
+    #[test]
+    fn test_expected_count() {
+        assert_eq!(Number::Count(0).expected_count(2, 2), Some(0));
+        assert_eq!(Number::Count(3).expected_count(2, 2), Some(3));
+
+        assert_eq!(Number::A.expected_count(2, 2), Some(2));
+        assert_eq!(Number::A.expected_count(0, 2), Some(0));
+
+        assert_eq!(Number::R.expected_count(2, 2), Some(3));
+        assert_eq!(Number::R.expected_count(0, 2), Some(1));
+
+        // 0/0, 0/1, 1/1
+        assert_eq!(Number::G.expected_count(1, 2), Some(3));
+        // 0/0, 0/1, 1/1, 0/2, 1/2, 2/2
+        assert_eq!(Number::G.expected_count(2, 2), Some(6));
+        assert_eq!(Number::G.expected_count(2, 1), Some(3));
+        assert_eq!(Number::G.expected_count(1, 3), Some(4));
+        assert!(Number::G.expected_count(usize::MAX, 2).is_none());
+
+        assert!(Number::Unknown.expected_count(2, 2).is_none());
+    }
+
+    #[test]
+    fn test_genotype_count() {
+        assert_eq!(genotype_count(2, 0), Some(1));
+        assert_eq!(genotype_count(2, 2), Some(3));
+        assert_eq!(genotype_count(3, 2), Some(6));
+        assert_eq!(genotype_count(4, 3), Some(20));
+        assert!(genotype_count(usize::MAX, 2).is_none());
+    }
 }
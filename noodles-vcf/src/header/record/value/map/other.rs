@@ -36,7 +36,32 @@ impl Map<Other> {
         Self::default()
     }
 
-    pub(crate) fn id_tag(&self) -> &Tag {
+    /// Returns the tag of the ID field.
+    ///
+    /// This is typically `ID`, but pedigree records (`PEDIGREE`) can use `Derived` or `Child`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::{other::Tag, Other}, Map};
+    /// let map = Map::<Other>::new();
+    /// assert_eq!(map.id_tag(), &Tag::from("ID"));
+    /// ```
+    pub fn id_tag(&self) -> &Tag {
         &self.inner.id_tag
     }
+
+    /// Returns a mutable reference to the tag of the ID field.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::header::record::value::{map::{other::Tag, Other}, Map};
+    /// let mut map = Map::<Other>::new();
+    /// *map.id_tag_mut() = Tag::from("Derived");
+    /// assert_eq!(map.id_tag(), &Tag::from("Derived"));
+    /// ```
+    pub fn id_tag_mut(&mut self) -> &mut Tag {
+        &mut self.inner.id_tag
+    }
 }
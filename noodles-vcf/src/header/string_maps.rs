@@ -194,6 +194,12 @@ fn insert(string_map: &mut StringMap, id: &str, idx: Option<usize>) -> Result<()
             if actual != expected {
                 return Err(ParseError::StringMapPositionMismatch(actual, expected));
             }
+        } else if let Some(entry) = string_map.get_index(i) {
+            return Err(ParseError::DuplicateStringMapPosition(
+                i,
+                entry.into(),
+                id.into(),
+            ));
         } else {
             string_map.insert_at(i, id.into());
         }
@@ -405,6 +411,41 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_from_str_with_a_duplicate_position() {
+        let s = r#"##fileformat=VCFv4.3
+##INFO=<ID=NS,Number=1,Type=Integer,Description="Number of samples with data",IDX=1>
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        assert_eq!(
+            s.parse::<StringMaps>(),
+            Err(ParseError::DuplicateStringMapPosition(
+                1,
+                String::from("NS"),
+                String::from("DP")
+            ))
+        );
+
+        // An entry without an `IDX` field takes the next position, which may later be claimed.
+        let s = r#"##fileformat=VCFv4.3
+##contig=<ID=sq0,IDX=0>
+##contig=<ID=sq1>
+##contig=<ID=sq2,IDX=1>
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO	FORMAT	sample0
+"#;
+
+        assert_eq!(
+            s.parse::<StringMaps>(),
+            Err(ParseError::DuplicateStringMapPosition(
+                1,
+                String::from("sq1"),
+                String::from("sq2")
+            ))
+        );
+    }
+
     #[test]
     fn test_try_from_vcf_header_for_string_maps() -> Result<(), Box<dyn std::error::Error>> {
         use crate::{
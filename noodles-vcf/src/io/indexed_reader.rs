@@ -8,7 +8,7 @@ use std::io::{self, BufRead, Read};
 
 use noodles_bgzf as bgzf;
 use noodles_core::Region;
-use noodles_csi::BinningIndex;
+use noodles_csi::{binning_index::index::header::ReferenceSequenceNames, BinningIndex};
 
 use super::{
     reader::{Query, RecordBufs},
@@ -76,6 +76,35 @@ where
     pub fn index(&self) -> &dyn BinningIndex {
         &self.index
     }
+
+    /// Returns the reference sequence names in the associated index.
+    ///
+    /// This returns `None` if the index does not have a header, e.g., a CSI without tabix
+    /// metadata.
+    pub fn reference_sequence_names(&self) -> Option<&ReferenceSequenceNames> {
+        self.index
+            .header()
+            .map(|header| header.reference_sequence_names())
+    }
+
+    /// Returns whether the associated index has any records on the given reference sequence.
+    ///
+    /// This can be used to skip queries that would return no records. A reference sequence
+    /// that is not in the index has no records.
+    pub fn has_records(&self, reference_sequence_name: &str) -> io::Result<bool> {
+        use noodles_core::region::Interval;
+
+        let Some(reference_sequence_id) = self
+            .reference_sequence_names()
+            .and_then(|names| names.get_index_of(reference_sequence_name))
+        else {
+            return Ok(false);
+        };
+
+        self.index
+            .query(reference_sequence_id, Interval::from(..))
+            .map(|chunks| !chunks.is_empty())
+    }
 }
 
 impl<R> IndexedReader<R>
@@ -107,3 +136,76 @@ where
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use noodles_csi::{self as csi, binning_index::index::reference_sequence::bin::Chunk};
+    use noodles_tabix as tabix;
+
+    use super::*;
+    use crate::variant::Record as _;
+
+    fn build_indexed_reader() -> io::Result<IndexedReader<bgzf::Reader<io::Cursor<Vec<u8>>>>> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##contig=<ID=chr1>
+##contig=<ID=chr2>
+##contig=<ID=chr3>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+chr1\t8\t.\tA\t.\t.\tPASS\t.
+chr2\t13\t.\tC\t.\t.\tPASS\t.
+";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        io::Write::write_all(&mut writer, DATA)?;
+        let data = writer.finish()?;
+
+        let mut reader = Reader::new(bgzf::Reader::new(&data[..]));
+        let header = reader.read_header()?;
+
+        let mut indexer = tabix::index::Indexer::default();
+        indexer.set_header(csi::binning_index::index::header::Builder::vcf().build());
+
+        let mut record = Record::default();
+        let mut start_position = reader.get_ref().virtual_position();
+
+        while reader.read_record(&mut record)? != 0 {
+            let end_position = reader.get_ref().virtual_position();
+            let chunk = Chunk::new(start_position, end_position);
+
+            let start = record.variant_start().transpose()?.expect("missing start");
+            let end = record.variant_end(&header)?;
+
+            indexer.add_record(record.reference_sequence_name(), start, end, chunk)?;
+
+            start_position = end_position;
+        }
+
+        Ok(IndexedReader::new(io::Cursor::new(data), indexer.build()))
+    }
+
+    #[test]
+    fn test_reference_sequence_names() -> io::Result<()> {
+        let reader = build_indexed_reader()?;
+
+        let actual = reader.reference_sequence_names();
+        let expected: ReferenceSequenceNames = [String::from("chr1"), String::from("chr2")]
+            .into_iter()
+            .collect();
+
+        assert_eq!(actual, Some(&expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_has_records() -> io::Result<()> {
+        let reader = build_indexed_reader()?;
+
+        assert!(reader.has_records("chr1")?);
+        assert!(reader.has_records("chr2")?);
+        assert!(!reader.has_records("chr3")?);
+
+        Ok(())
+    }
+}
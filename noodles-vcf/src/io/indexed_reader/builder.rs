@@ -1,7 +1,7 @@
 use std::{
     ffi::{OsStr, OsString},
     fs::File,
-    io::{self, Read},
+    io::{self, Read, Seek, SeekFrom},
     path::{Path, PathBuf},
 };
 
@@ -39,6 +39,11 @@ impl Builder {
 
     /// Builds an indexed VCF reader from a path.
     ///
+    /// The input must be BGZF-compressed. This returns an error if it is gzip-compressed but not
+    /// BGZF-compressed (e.g., compressed using `gzip` instead of `bgzip`), since queries require
+    /// seeking to BGZF blocks. Such a file can still be read sequentially using
+    /// [`crate::io::reader::Builder`].
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -57,7 +62,9 @@ impl Builder {
             None => read_associated_index(src)?,
         };
 
-        let file = File::open(src)?;
+        let mut file = File::open(src)?;
+        validate_bgzf(&mut file)?;
+        file.seek(SeekFrom::Start(0))?;
 
         Ok(IndexedReader::new(file, index))
     }
@@ -89,6 +96,28 @@ impl Builder {
     }
 }
 
+fn validate_bgzf<R>(reader: &mut R) -> io::Result<()>
+where
+    R: Read,
+{
+    use crate::io::reader::builder::is_gzip_but_not_bgzf;
+
+    // gzip header (10) + XLEN (2) + BGZF subfield ID (2)
+    const PREFIX_LEN: u64 = 14;
+
+    let mut buf = Vec::new();
+    reader.take(PREFIX_LEN).read_to_end(&mut buf)?;
+
+    if is_gzip_but_not_bgzf(&buf) {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "input is gzip-compressed but not BGZF-compressed; indexed queries require a file compressed using bgzip",
+        ))
+    } else {
+        Ok(())
+    }
+}
+
 fn read_associated_index<P>(src: P) -> io::Result<Box<dyn BinningIndex>>
 where
     P: AsRef<Path>,
@@ -127,6 +156,32 @@ where
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_bgzf() -> io::Result<()> {
+        use std::io::Write;
+
+        use flate2::write::GzEncoder;
+
+        const DATA: &[u8] = b"##fileformat=VCFv4.3\n";
+
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(DATA)?;
+        let src = writer.finish()?;
+        assert!(validate_bgzf(&mut &src[..]).is_ok());
+
+        let mut encoder = GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(DATA)?;
+        let src = encoder.finish()?;
+        assert!(matches!(
+            validate_bgzf(&mut &src[..]),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(validate_bgzf(&mut &DATA[..]).is_ok());
+
+        Ok(())
+    }
+
     #[test]
     fn test_push_ext() {
         assert_eq!(
@@ -1,7 +1,9 @@
 //! VCF reader and iterators.
 
-mod builder;
+pub(crate) mod builder;
 mod header;
+mod line_error;
+mod parsing_mode;
 pub(crate) mod query;
 pub(crate) mod record;
 pub mod record_buf;
@@ -9,7 +11,10 @@ mod record_bufs;
 
 use self::record::read_record;
 pub(crate) use self::record_buf::parse_record_buf;
-pub use self::{builder::Builder, query::Query, record_bufs::RecordBufs};
+pub use self::{
+    builder::Builder, line_error::LineError, parsing_mode::ParsingMode, query::Query,
+    record_bufs::RecordBufs,
+};
 
 use std::{
     io::{self, BufRead},
@@ -50,6 +55,8 @@ use crate::{variant::RecordBuf, Header, Record};
 pub struct Reader<R> {
     inner: R,
     buf: String,
+    parsing_mode: Option<ParsingMode>,
+    line_number: usize,
 }
 
 impl<R> Reader<R> {
@@ -118,6 +125,8 @@ where
         Self {
             inner,
             buf: String::new(),
+            parsing_mode: None,
+            line_number: 0,
         }
     }
 
@@ -144,7 +153,9 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn read_header(&mut self) -> io::Result<Header> {
-        read_header(&mut self.inner)
+        let (header, line_count) = read_header(&mut self.inner)?;
+        self.line_number = line_count;
+        Ok(header)
     }
 
     /// Reads a single VCF record.
@@ -160,6 +171,15 @@ where
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
     /// stream reached EOF.
     ///
+    /// INFO field and sample values are parsed using the types declared in the header. A value that
+    /// does not match its declared type, e.g., `DP=abc` for an `Integer` field, is an error.
+    ///
+    /// If the reader has a parsing mode (see [`ParsingMode`]), recoverable spec violations are
+    /// handled according to it.
+    ///
+    /// If the record fails to parse, the returned error wraps a [`LineError`], which includes the
+    /// line number and raw line of the record.
+    ///
     /// # Examples
     ///
     /// ```
@@ -187,8 +207,12 @@ where
         match read_line(&mut self.inner, &mut self.buf)? {
             0 => Ok(0),
             n => {
-                parse_record_buf(&self.buf, header, record)
-                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                self.line_number += 1;
+
+                parse_record_buf(&self.buf, header, record, self.parsing_mode).map_err(|e| {
+                    let e = LineError::new(self.line_number, self.buf.clone(), e);
+                    io::Error::new(io::ErrorKind::InvalidData, e)
+                })?;
 
                 Ok(n)
             }
@@ -227,6 +251,10 @@ where
     /// a newline is reached. No fields are parsed, meaning the record is not necessarily valid.
     /// However, the structure of the line is guaranteed to be record-like.
     ///
+    /// Because no fields are parsed, the reader's parsing mode (see [`ParsingMode`]) does not
+    /// apply. To eagerly parse values using the types declared in the header, use
+    /// [`Self::read_record_buf`].
+    ///
     /// The stream is expected to be directly after the header or at the start of another record.
     ///
     /// If successful, the number of bytes read is returned. If the number of bytes read is 0, the
@@ -250,7 +278,13 @@ where
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn read_record(&mut self, record: &mut Record) -> io::Result<usize> {
-        read_record(&mut self.inner, record)
+        let n = read_record(&mut self.inner, record)?;
+
+        if n > 0 {
+            self.line_number += 1;
+        }
+
+        Ok(n)
     }
 
     /// Returns an iterator over records.
@@ -440,6 +474,127 @@ sq0\t1\t.\tA\t.\t.\tPASS\t.
         Ok(())
     }
 
+    #[test]
+    fn test_read_record_buf_with_typed_values() -> io::Result<()> {
+        const DATA: &[u8] = b"##fileformat=VCFv4.3
+##INFO=<ID=DP,Number=1,Type=Integer,Description=\"Combined depth across samples\">
+##FORMAT=<ID=GQ,Number=1,Type=Integer,Description=\"Conditional genotype quality\">
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO\tFORMAT\tsample0
+sq0\t1\t.\tA\t.\t.\tPASS\tDP=8\tGQ\t13
+sq0\t2\t.\tA\t.\t.\tPASS\tDP=abc\tGQ\t13
+sq0\t3\t.\tA\t.\t.\tPASS\tDP=8\tGQ\tndls
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+
+        reader.read_record_buf(&header, &mut record)?;
+        assert_eq!(
+            record.info().get("DP"),
+            Some(Some(
+                &crate::variant::record_buf::info::field::Value::Integer(8)
+            ))
+        );
+
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(reader.read_record_buf(&header, &mut record)?, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_buf_with_parsing_mode() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tacgt\t.\t.\tPASS\t.
+sq0\t8\t.\tA\tC
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+        let mut record = RecordBuf::default();
+
+        reader.read_record_buf(&header, &mut record)?;
+        assert_eq!(record.reference_bases(), "acgt");
+
+        let mut reader = Builder::default()
+            .set_parsing_mode(ParsingMode::Lenient)
+            .build_from_reader(DATA)?;
+
+        let header = reader.read_header()?;
+
+        reader.read_record_buf(&header, &mut record)?;
+        assert_eq!(record.reference_bases(), "ACGT");
+
+        reader.read_record_buf(&header, &mut record)?;
+        assert!(record.quality_score().is_none());
+        assert!(record.filters().as_ref().is_empty());
+        assert!(record.info().as_ref().is_empty());
+
+        let mut reader = Builder::default()
+            .set_parsing_mode(ParsingMode::Strict)
+            .build_from_reader(DATA)?;
+
+        let header = reader.read_header()?;
+
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(matches!(
+            reader.read_record_buf(&header, &mut record),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_record_buf_with_invalid_record() -> io::Result<()> {
+        static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+##fileDate=20200501
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+sq0\tndls\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(DATA);
+        let header = reader.read_header()?;
+
+        let mut records = reader.record_bufs(&header);
+        assert!(records.next().transpose()?.is_some());
+
+        let e = records.next().unwrap().unwrap_err();
+        assert_eq!(e.kind(), io::ErrorKind::InvalidData);
+
+        let line_error = e
+            .get_ref()
+            .and_then(|e| e.downcast_ref::<LineError>())
+            .unwrap();
+
+        assert_eq!(line_error.line_number(), 5);
+        assert_eq!(line_error.line(), "sq0\tndls\t.\tA\t.\t.\tPASS\t.");
+        assert!(matches!(
+            line_error.parse_error(),
+            record_buf::ParseError::InvalidPosition(_)
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn test_read_line() -> io::Result<()> {
         let mut buf = String::new();
@@ -4,15 +4,17 @@ use std::{
     path::Path,
 };
 
+use flate2::bufread::MultiGzDecoder;
 use noodles_bgzf as bgzf;
 
-use super::Reader;
+use super::{ParsingMode, Reader};
 use crate::io::CompressionMethod;
 
 /// A VCF reader builder.
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    parsing_mode: Option<ParsingMode>,
 }
 
 impl Builder {
@@ -29,11 +31,34 @@ impl Builder {
         self
     }
 
+    /// Sets the record parsing mode.
+    ///
+    /// This controls whether recoverable spec violations in record buffers are errors or are
+    /// normalized. See [`ParsingMode`] for the list of recoverable violations. It does not apply to
+    /// lazily-evaluated records (see [`Reader::read_record`]).
+    ///
+    /// By default, no parsing mode is set, and recoverable violations are neither rejected nor
+    /// normalized, e.g., lowercase reference bases are kept as is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::reader::{Builder, ParsingMode};
+    /// let builder = Builder::default().set_parsing_mode(ParsingMode::Strict);
+    /// ```
+    pub fn set_parsing_mode(mut self, parsing_mode: ParsingMode) -> Self {
+        self.parsing_mode = Some(parsing_mode);
+        self
+    }
+
     /// Builds a VCF reader from a path.
     ///
     /// By default, the compression method will be autodetected. This can be overridden by using
     /// [`Self::set_compression_method`].
     ///
+    /// A file with a `.gz` extension that is gzip-compressed rather than BGZF-compressed (e.g.,
+    /// compressed using `gzip` instead of `bgzip`) can still be read sequentially.
+    ///
     /// # Examples
     ///
     /// ```no_run
@@ -73,10 +98,94 @@ impl Builder {
         R: Read + 'r,
     {
         let inner: Box<dyn BufRead> = match self.compression_method {
-            Some(CompressionMethod::Bgzf) => Box::new(bgzf::Reader::new(reader)),
+            Some(CompressionMethod::Bgzf) => {
+                let mut reader = BufReader::new(reader);
+
+                if is_gzip_but_not_bgzf(reader.fill_buf()?) {
+                    Box::new(BufReader::new(MultiGzDecoder::new(reader)))
+                } else {
+                    Box::new(bgzf::Reader::new(reader))
+                }
+            }
             Some(CompressionMethod::None) | None => Box::new(BufReader::new(reader)),
         };
 
-        Ok(Reader::new(inner))
+        let mut reader = Reader::new(inner);
+        reader.parsing_mode = self.parsing_mode;
+        Ok(reader)
+    }
+}
+
+/// Returns whether the given stream prefix is a gzip member that is not a BGZF block.
+///
+/// A BGZF block is a gzip member with an extra field that includes a `BC` subfield.
+pub(crate) fn is_gzip_but_not_bgzf(src: &[u8]) -> bool {
+    const GZIP_MAGIC_NUMBER: [u8; 2] = [0x1f, 0x8b];
+    const FLG_POSITION: usize = 3;
+    const FEXTRA: u8 = 0x04;
+    const BGZF_SUBFIELD_ID_RANGE: std::ops::Range<usize> = 12..14;
+    const BGZF_SUBFIELD_ID: [u8; 2] = [b'B', b'C'];
+
+    if src.get(..GZIP_MAGIC_NUMBER.len()) != Some(&GZIP_MAGIC_NUMBER[..]) {
+        return false;
+    }
+
+    match src.get(FLG_POSITION) {
+        Some(flg) if flg & FEXTRA == 0 => true,
+        Some(_) => src
+            .get(BGZF_SUBFIELD_ID_RANGE)
+            .map(|id| id != BGZF_SUBFIELD_ID)
+            .unwrap_or(false),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use super::*;
+
+    static DATA: &[u8] = b"\
+##fileformat=VCFv4.3
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\t.\tA\t.\t.\tPASS\t.
+";
+
+    fn gzip(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), Default::default());
+        encoder.write_all(data)?;
+        encoder.finish()
+    }
+
+    fn bgzip(data: &[u8]) -> io::Result<Vec<u8>> {
+        let mut writer = bgzf::Writer::new(Vec::new());
+        writer.write_all(data)?;
+        writer.finish()
+    }
+
+    #[test]
+    fn test_build_from_reader_with_bgzf_compression_method() -> io::Result<()> {
+        for src in [gzip(DATA)?, bgzip(DATA)?] {
+            let mut reader = Builder::default()
+                .set_compression_method(CompressionMethod::Bgzf)
+                .build_from_reader(&src[..])?;
+
+            reader.read_header()?;
+
+            let records: Vec<_> = reader.records().collect::<io::Result<_>>()?;
+            assert_eq!(records.len(), 1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_gzip_but_not_bgzf() -> io::Result<()> {
+        assert!(is_gzip_but_not_bgzf(&gzip(DATA)?));
+        assert!(!is_gzip_but_not_bgzf(&bgzip(DATA)?));
+        assert!(!is_gzip_but_not_bgzf(DATA));
+        assert!(!is_gzip_but_not_bgzf(&[]));
+        Ok(())
     }
 }
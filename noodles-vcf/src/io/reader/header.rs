@@ -56,7 +56,8 @@ where
     }
 }
 
-pub(super) fn read_header<R>(reader: &mut R) -> io::Result<Header>
+/// Reads and parses the header, returning it along with the number of header lines read.
+pub(super) fn read_header<R>(reader: &mut R) -> io::Result<(Header, usize)>
 where
     R: BufRead,
 {
@@ -64,16 +65,21 @@ where
 
     let mut parser = header::Parser::default();
     let mut buf = Vec::new();
+    let mut line_count = 0;
 
     while read_line(&mut reader, &mut buf)? != 0 {
+        line_count += 1;
+
         parser
             .parse_partial(&buf)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
     }
 
-    parser
+    let header = parser
         .finish()
-        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+    Ok((header, line_count))
 }
 
 fn read_line<R>(reader: &mut R, dst: &mut Vec<u8>) -> io::Result<usize>
@@ -0,0 +1,54 @@
+use std::{error, fmt};
+
+use super::record_buf::ParseError;
+
+/// An error when a VCF record line fails to parse.
+///
+/// This includes the 1-based line number of the record and the raw line.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LineError {
+    line_number: usize,
+    line: String,
+    source: ParseError,
+}
+
+impl LineError {
+    pub(super) fn new(line_number: usize, line: String, source: ParseError) -> Self {
+        Self {
+            line_number,
+            line,
+            source,
+        }
+    }
+
+    /// Returns the 1-based line number of the record.
+    ///
+    /// The line number is counted from the start of the stream, including header lines. It is
+    /// only meaningful when the stream was read sequentially from its start, i.e., not after a
+    /// seek.
+    pub fn line_number(&self) -> usize {
+        self.line_number
+    }
+
+    /// Returns the raw record line, without the line terminator.
+    pub fn line(&self) -> &str {
+        &self.line
+    }
+
+    /// Returns the record parse error.
+    pub fn parse_error(&self) -> &ParseError {
+        &self.source
+    }
+}
+
+impl error::Error for LineError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.source)
+    }
+}
+
+impl fmt::Display for LineError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid record at line {}", self.line_number)
+    }
+}
@@ -0,0 +1,28 @@
+/// A VCF record parsing mode.
+///
+/// This controls how recoverable spec violations in record fields are handled when reading
+/// record buffers (see [`super::Reader::read_record_buf`] and [`super::Reader::record_bufs`]).
+/// The recoverable violations are
+///
+///   * lowercase reference bases (`REF`), e.g., `acgt`; and
+///   * missing or empty quality score (`QUAL`), filters (`FILTER`), or info (`INFO`) fields, e.g.,
+///     a record line that ends after the alternate bases (`ALT`).
+///
+/// All other violations are always errors.
+///
+/// A parsing mode is opt-in (see [`super::Builder::set_parsing_mode`]). When it is not set,
+/// recoverable violations are neither rejected nor normalized, e.g., lowercase reference bases are
+/// kept as is.
+///
+/// The parsing mode does not apply to lazily-evaluated records (see [`super::Reader::read_record`]),
+/// whose fields are not parsed when read. The async reader does not have a parsing mode.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParsingMode {
+    /// Recoverable violations are errors.
+    Strict,
+    /// Recoverable violations are normalized.
+    ///
+    /// Lowercase reference bases are uppercased, and missing trailing fields are set as missing
+    /// (`.`).
+    Lenient,
+}
@@ -14,11 +14,17 @@ mod value;
 use std::{error, fmt};
 
 use self::{
-    alternate_bases::parse_alternate_bases, filters::parse_filters, ids::parse_ids,
-    info::parse_info, position::parse_position, quality_score::parse_quality_score,
-    reference_bases::parse_reference_bases, reference_sequence_name::parse_reference_sequence_name,
+    alternate_bases::parse_alternate_bases,
+    filters::parse_filters,
+    ids::parse_ids,
+    info::parse_info,
+    position::parse_position,
+    quality_score::parse_quality_score,
+    reference_bases::{normalize_reference_bases, parse_reference_bases},
+    reference_sequence_name::parse_reference_sequence_name,
     samples::parse_samples,
 };
+use super::ParsingMode;
 use crate::{variant::RecordBuf, Header};
 
 const MISSING: &str = ".";
@@ -79,6 +85,7 @@ pub(crate) fn parse_record_buf(
     mut s: &str,
     header: &Header,
     record: &mut RecordBuf,
+    parsing_mode: Option<ParsingMode>,
 ) -> Result<(), ParseError> {
     let field = next_field(&mut s);
     parse_reference_sequence_name(field, record.reference_sequence_name_mut());
@@ -96,6 +103,11 @@ pub(crate) fn parse_record_buf(
     parse_reference_bases(field, record.reference_bases_mut())
         .map_err(ParseError::InvalidReferenceBases)?;
 
+    if let Some(parsing_mode) = parsing_mode {
+        normalize_reference_bases(record.reference_bases_mut(), parsing_mode)
+            .map_err(ParseError::InvalidReferenceBases)?;
+    }
+
     record.alternate_bases_mut().as_mut().clear();
     let field = next_field(&mut s);
     if field != MISSING {
@@ -103,7 +115,7 @@ pub(crate) fn parse_record_buf(
             .map_err(ParseError::InvalidAlternateBases)?;
     }
 
-    let field = next_field(&mut s);
+    let field = next_trailing_field(&mut s, parsing_mode);
     *record.quality_score_mut() = match field {
         MISSING => None,
         _ => parse_quality_score(field)
@@ -111,14 +123,14 @@ pub(crate) fn parse_record_buf(
             .map_err(ParseError::InvalidQualityScore)?,
     };
 
-    let field = next_field(&mut s);
+    let field = next_trailing_field(&mut s, parsing_mode);
     match field {
         MISSING => record.filters_mut().as_mut().clear(),
         _ => parse_filters(field, record.filters_mut()).map_err(ParseError::InvalidFilters)?,
     }
 
     record.info_mut().clear();
-    let field = next_field(&mut s);
+    let field = next_trailing_field(&mut s, parsing_mode);
     if field != MISSING {
         parse_info(header, field, record.info_mut()).map_err(ParseError::InvalidInfo)?;
     }
@@ -139,3 +151,10 @@ fn next_field<'a>(s: &mut &'a str) -> &'a str {
 
     field
 }
+
+fn next_trailing_field<'a>(s: &mut &'a str, parsing_mode: Option<ParsingMode>) -> &'a str {
+    match next_field(s) {
+        "" if parsing_mode == Some(ParsingMode::Lenient) => MISSING,
+        field => field,
+    }
+}
@@ -1,10 +1,14 @@
 use std::{error, fmt};
 
+use crate::io::reader::ParsingMode;
+
 /// An error when raw VCF record reference bases fail to parse.
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum ParseError {
     /// The input is empty.
     Empty,
+    /// The input has lowercase bases.
+    Lowercase,
 }
 
 impl error::Error for ParseError {}
@@ -13,6 +17,7 @@ impl fmt::Display for ParseError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             Self::Empty => write!(f, "empty input"),
+            Self::Lowercase => write!(f, "lowercase bases"),
         }
     }
 }
@@ -31,6 +36,23 @@ pub(super) fn parse_reference_bases(
     Ok(())
 }
 
+pub(super) fn normalize_reference_bases(
+    reference_bases: &mut str,
+    parsing_mode: ParsingMode,
+) -> Result<(), ParseError> {
+    if !reference_bases.bytes().any(|b| b.is_ascii_lowercase()) {
+        return Ok(());
+    }
+
+    match parsing_mode {
+        ParsingMode::Strict => Err(ParseError::Lowercase),
+        ParsingMode::Lenient => {
+            reference_bases.make_ascii_uppercase();
+            Ok(())
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,4 +74,27 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_normalize_reference_bases() {
+        let mut buf = String::from("ATCGN");
+        assert_eq!(
+            normalize_reference_bases(&mut buf, ParsingMode::Strict),
+            Ok(())
+        );
+        assert_eq!(buf, "ATCGN");
+
+        let mut buf = String::from("AtCgN");
+        assert_eq!(
+            normalize_reference_bases(&mut buf, ParsingMode::Strict),
+            Err(ParseError::Lowercase)
+        );
+
+        let mut buf = String::from("AtCgN");
+        assert_eq!(
+            normalize_reference_bases(&mut buf, ParsingMode::Lenient),
+            Ok(())
+        );
+        assert_eq!(buf, "ATCGN");
+    }
 }
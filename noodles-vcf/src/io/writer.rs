@@ -7,7 +7,10 @@ mod record;
 use std::io::{self, Write};
 
 pub use self::builder::Builder;
-use self::{header::write_header, record::write_record};
+pub(crate) use self::{
+    header::{write_header, Options as HeaderOptions},
+    record::{write_record, Options as RecordOptions},
+};
 use crate::{Header, Record};
 
 /// A VCF writer.
@@ -51,6 +54,9 @@ use crate::{Header, Record};
 #[derive(Debug)]
 pub struct Writer<W> {
     inner: W,
+    is_header_written: bool,
+    header_options: HeaderOptions,
+    record_options: RecordOptions,
 }
 
 impl<W> Writer<W>
@@ -66,7 +72,12 @@ where
     /// let writer = vcf::io::Writer::new(Vec::new());
     /// ```
     pub fn new(inner: W) -> Self {
-        Self { inner }
+        Self {
+            inner,
+            is_header_written: false,
+            header_options: HeaderOptions::default(),
+            record_options: RecordOptions::default(),
+        }
     }
 
     /// Returns a reference to the underlying writer.
@@ -110,6 +121,9 @@ where
 
     /// Writes a VCF header.
     ///
+    /// The header can only be written once, and it must be written before any records. Writing it
+    /// again returns an [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -123,11 +137,24 @@ where
     /// # Ok::<(), io::Error>(())
     /// ```
     pub fn write_header(&mut self, header: &Header) -> io::Result<()> {
-        write_header(&mut self.inner, header)
+        if self.is_header_written {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header already written",
+            ));
+        }
+
+        write_header(&mut self.inner, header, &self.header_options)?;
+        self.is_header_written = true;
+
+        Ok(())
     }
 
     /// Writes a VCF record.
     ///
+    /// The header must be written first (see [`Self::write_header`]). Otherwise, this returns an
+    /// [`io::ErrorKind::InvalidInput`] error.
+    ///
     /// # Examples
     ///
     /// ```
@@ -137,14 +164,35 @@ where
     /// let mut writer = vcf::io::Writer::new(Vec::new());
     ///
     /// let header = vcf::Header::default();
+    /// writer.write_header(&header)?;
+    ///
     /// let record = vcf::Record::default();
     /// writer.write_record(&header, &record)?;
     ///
-    /// assert_eq!(writer.get_ref(), b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
+    /// assert!(writer.get_ref().ends_with(b"sq0\t1\t.\tA\t.\t.\t.\t.\n"));
     /// # Ok::<_, std::io::Error>(())
     /// ```
     pub fn write_record(&mut self, header: &Header, record: &Record) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        self.validate_header_is_written()?;
+        self.write_record_inner(header, record)
+    }
+
+    fn write_record_inner<R>(&mut self, header: &Header, record: &R) -> io::Result<()>
+    where
+        R: crate::variant::Record + ?Sized,
+    {
+        write_record(&mut self.inner, header, record, &self.record_options)
+    }
+
+    fn validate_header_is_written(&self) -> io::Result<()> {
+        if self.is_header_written {
+            Ok(())
+        } else {
+            Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "header not written",
+            ))
+        }
     }
 }
 
@@ -161,7 +209,8 @@ where
         header: &Header,
         record: &dyn crate::variant::Record,
     ) -> io::Result<()> {
-        write_record(&mut self.inner, header, record)
+        self.validate_header_is_written()?;
+        self.write_record_inner(header, record)
     }
 }
 
@@ -183,6 +232,9 @@ mod tests {
             .build();
 
         let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.get_mut().clear();
+
         writer.write_variant_record(&header, &record)?;
 
         let expected = b"sq0\t1\t.\tA\t.\t.\t.\t.\n";
@@ -221,6 +273,9 @@ mod tests {
             .build();
 
         let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        writer.get_mut().clear();
+
         writer.write_variant_record(&header, &record)?;
 
         let expected = b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT:GQ\t0|0:13\n";
@@ -228,4 +283,189 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_record_with_fields_in_header_order() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{
+                map::{Format, Info},
+                Map,
+            },
+            variant::{
+                record::{info::field::key as info_key, samples::keys::key as format_key},
+                record_buf::{info::field::Value as InfoValue, samples::sample::Value, Samples},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(
+                info_key::IS_IN_DB_SNP,
+                Map::<Info>::from(info_key::IS_IN_DB_SNP),
+            )
+            .add_info(
+                info_key::SAMPLES_WITH_DATA_COUNT,
+                Map::<Info>::from(info_key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .add_format(
+                format_key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(format_key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(format_key::GENOTYPE),
+            )
+            .add_sample_name("sample0")
+            .build();
+
+        let info = [
+            (
+                String::from(info_key::SAMPLES_WITH_DATA_COUNT),
+                Some(InfoValue::from(1)),
+            ),
+            (String::from(info_key::IS_IN_DB_SNP), Some(InfoValue::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        let samples = Samples::new(
+            [
+                String::from(format_key::CONDITIONAL_GENOTYPE_QUALITY),
+                String::from(format_key::GENOTYPE),
+            ]
+            .into_iter()
+            .collect(),
+            vec![vec![Some(Value::from(13)), Some(Value::from("0|0"))]],
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_info(info)
+            .set_samples(samples)
+            .build();
+
+        let mut buf = Vec::new();
+
+        {
+            let mut writer = Builder::default()
+                .set_fields_in_header_order(true)
+                .build_from_writer(&mut buf);
+
+            writer.write_header(&header)?;
+            writer.write_variant_record(&header, &record)?;
+        }
+
+        let expected = b"sq0\t1\t.\tA\t.\t.\t.\tDB;NS=1\tGT:GQ\t0|0:13\n";
+        assert!(buf.ends_with(expected));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_with_trailing_missing_sample_series(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::{
+            record::samples::keys::key,
+            record_buf::{samples::sample::Value, Samples},
+        };
+
+        fn t(
+            header: &Header,
+            record: &RecordBuf,
+            trim_trailing_missing_sample_series: bool,
+            expected: &[u8],
+        ) -> io::Result<()> {
+            let mut buf = Vec::new();
+
+            {
+                let mut writer = Builder::default()
+                    .set_trim_trailing_missing_sample_series(trim_trailing_missing_sample_series)
+                    .build_from_writer(&mut buf);
+
+                writer.write_header(header)?;
+                writer.write_variant_record(header, record)?;
+            }
+
+            assert!(buf.ends_with(expected));
+
+            Ok(())
+        }
+
+        let header = Header::builder()
+            .add_sample_name("sample0")
+            .add_sample_name("sample1")
+            .build();
+
+        let samples = Samples::new(
+            [
+                String::from(key::GENOTYPE),
+                String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            ]
+            .into_iter()
+            .collect(),
+            vec![
+                vec![Some(Value::from("0|0")), None],
+                vec![Some(Value::from("0/1")), None],
+            ],
+        );
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_samples(samples)
+            .build();
+
+        t(
+            &header,
+            &record,
+            false,
+            b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT:GQ\t0|0:.\t0/1:.\n",
+        )?;
+
+        t(
+            &header,
+            &record,
+            true,
+            b"sq0\t1\t.\tA\t.\t.\t.\t.\tGT\t0|0\t0/1\n",
+        )?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_header_twice() -> io::Result<()> {
+        let header = Header::default();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+
+        assert!(matches!(
+            writer.write_header(&header),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_record_before_header() -> io::Result<()> {
+        let header = Header::default();
+        let mut writer = Writer::new(Vec::new());
+
+        assert!(matches!(
+            writer.write_record(&header, &Record::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(matches!(
+            writer.write_variant_record(&header, &RecordBuf::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+
+        assert!(writer.get_ref().is_empty());
+
+        Ok(())
+    }
 }
@@ -13,6 +13,9 @@ use crate::io::CompressionMethod;
 #[derive(Debug, Default)]
 pub struct Builder {
     compression_method: Option<CompressionMethod>,
+    fields_in_header_order: bool,
+    trim_trailing_missing_sample_series: bool,
+    idx_fields: bool,
 }
 
 impl Builder {
@@ -29,6 +32,66 @@ impl Builder {
         self
     }
 
+    /// Sets whether to write fields in header declaration order.
+    ///
+    /// When enabled, info fields and sample series are written in the order they are declared in
+    /// the header, with the genotype (`GT`) series always first. Keys that are not declared in the
+    /// header are written last, in their original order. This matches the field ordering of
+    /// bcftools output.
+    ///
+    /// By default, fields are written in their record order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_fields_in_header_order(true);
+    /// ```
+    pub fn set_fields_in_header_order(mut self, fields_in_header_order: bool) -> Self {
+        self.fields_in_header_order = fields_in_header_order;
+        self
+    }
+
+    /// Sets whether to trim trailing sample series that are missing in all samples.
+    ///
+    /// When enabled, series at the end of the format keys (`FORMAT`) whose values are missing in
+    /// every sample are not written. The genotype (`GT`) series is never trimmed, and at least one
+    /// series is always written.
+    ///
+    /// By default, all series are written, with missing values written as `.`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_trim_trailing_missing_sample_series(true);
+    /// ```
+    pub fn set_trim_trailing_missing_sample_series(
+        mut self,
+        trim_trailing_missing_sample_series: bool,
+    ) -> Self {
+        self.trim_trailing_missing_sample_series = trim_trailing_missing_sample_series;
+        self
+    }
+
+    /// Sets whether to write the string map positions (`IDX`) of header records.
+    ///
+    /// When enabled, the `IDX` fields of contig, filter, format, and info header records are
+    /// written. This is used when serializing the header of a BCF file.
+    ///
+    /// By default, `IDX` fields are not written.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::io::writer::Builder;
+    /// let builder = Builder::default().set_idx_fields(true);
+    /// ```
+    pub fn set_idx_fields(mut self, idx_fields: bool) -> Self {
+        self.idx_fields = idx_fields;
+        self
+    }
+
     /// Builds a VCF writer from a path.
     ///
     /// If the compression method is not set, it is detected from the path extension.
@@ -77,6 +140,15 @@ impl Builder {
             Some(CompressionMethod::None) | None => Box::new(BufWriter::new(writer)),
         };
 
-        Writer::new(inner)
+        let mut writer = Writer::new(inner);
+        writer.header_options = super::HeaderOptions {
+            idx_fields: self.idx_fields,
+        };
+        writer.record_options = super::RecordOptions {
+            fields_in_header_order: self.fields_in_header_order,
+            trim_trailing_missing_sample_series: self.trim_trailing_missing_sample_series,
+        };
+
+        writer
     }
 }
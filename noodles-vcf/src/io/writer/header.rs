@@ -8,7 +8,15 @@ use self::record::{
 };
 use crate::{header::SampleNames, Header};
 
-pub(super) fn write_header<W>(writer: &mut W, header: &Header) -> io::Result<()>
+/// Header serialization options.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Options {
+    /// Whether the string map positions (`IDX`) of contig, filter, format, and info records are
+    /// written.
+    pub(crate) idx_fields: bool,
+}
+
+pub(crate) fn write_header<W>(writer: &mut W, header: &Header, options: &Options) -> io::Result<()>
 where
     W: Write,
 {
@@ -17,15 +25,15 @@ where
     write_file_format(writer, file_format)?;
 
     for (id, info) in header.infos() {
-        write_info(writer, id, info)?;
+        write_info(writer, id, info, options)?;
     }
 
     for (id, filter) in header.filters() {
-        write_filter(writer, id, filter)?;
+        write_filter(writer, id, filter, options)?;
     }
 
     for (id, format) in header.formats() {
-        write_format(writer, id, format)?;
+        write_format(writer, id, format, options)?;
     }
 
     for (id, alternative_allele) in header.alternative_alleles() {
@@ -33,7 +41,7 @@ where
     }
 
     for (id, contig) in header.contigs() {
-        write_contig(writer, id, contig)?;
+        write_contig(writer, id, contig, options)?;
     }
 
     for (key, collection) in header.other_records() {
@@ -101,7 +109,7 @@ mod tests {
         let mut buf = Vec::new();
 
         let header = Header::default();
-        write_header(&mut buf, &header)?;
+        write_header(&mut buf, &header, &Options::default())?;
 
         let expected = b"##fileformat=VCFv4.4
 #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
@@ -112,6 +120,41 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_write_header_with_info_and_format_extra_fields(
+    ) -> Result<(), Box<dyn std::error::Error>> {
+        use crate::header::record::value::{
+            map::{Format, Info},
+            Map,
+        };
+
+        const SRC: &str = r#"##fileformat=VCFv4.4
+##INFO=<ID=DP,Number=1,Type=Integer,Description="Combined depth across samples",Source="caller",Version="1.0">
+##FORMAT=<ID=DP,Number=1,Type=Integer,Description="Read depth",Source="caller",Version="1.0",Extra="noodles">
+#CHROM	POS	ID	REF	ALT	QUAL	FILTER	INFO
+"#;
+
+        let header: Header = SRC.parse()?;
+
+        let info: &Map<Info> = &header.infos()["DP"];
+        assert_eq!(info.source(), Some("caller"));
+        assert_eq!(info.version(), Some("1.0"));
+
+        let format: &Map<Format> = &header.formats()["DP"];
+        assert_eq!(format.source(), Some("caller"));
+        assert_eq!(format.version(), Some("1.0"));
+        assert_eq!(
+            format.other_fields().get("Extra").map(|s| s.as_str()),
+            Some("noodles")
+        );
+
+        let mut buf = Vec::new();
+        write_header(&mut buf, &header, &Options::default())?;
+        assert_eq!(buf, SRC.as_bytes());
+
+        Ok(())
+    }
+
     #[test]
     fn test_write_column_names() -> io::Result<()> {
         let mut buf = Vec::new();
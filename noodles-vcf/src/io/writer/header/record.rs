@@ -4,7 +4,7 @@ mod value;
 use std::io::{self, Write};
 
 use self::key::write_key;
-use super::write_newline;
+use super::{write_newline, Options};
 use crate::header::{
     record::{
         self,
@@ -48,30 +48,45 @@ where
     })
 }
 
-pub(super) fn write_info<W>(writer: &mut W, id: &str, info: &Map<Info>) -> io::Result<()>
+pub(super) fn write_info<W>(
+    writer: &mut W,
+    id: &str,
+    info: &Map<Info>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
     write_record(writer, &record::key::INFO, |w| {
-        value::write_map(w, id, |x| value::map::write_info(x, info))
+        value::write_map(w, id, |x| value::map::write_info(x, info, options))
     })
 }
 
-pub(super) fn write_filter<W>(writer: &mut W, id: &str, filter: &Map<Filter>) -> io::Result<()>
+pub(super) fn write_filter<W>(
+    writer: &mut W,
+    id: &str,
+    filter: &Map<Filter>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
     write_record(writer, &record::key::FILTER, |w| {
-        value::write_map(w, id, |x| value::map::write_filter(x, filter))
+        value::write_map(w, id, |x| value::map::write_filter(x, filter, options))
     })
 }
 
-pub(super) fn write_format<W>(writer: &mut W, id: &str, format: &Map<Format>) -> io::Result<()>
+pub(super) fn write_format<W>(
+    writer: &mut W,
+    id: &str,
+    format: &Map<Format>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
     write_record(writer, &record::key::FORMAT, |w| {
-        value::write_map(w, id, |x| value::map::write_format(x, format))
+        value::write_map(w, id, |x| value::map::write_format(x, format, options))
     })
 }
 
@@ -90,12 +105,17 @@ where
     })
 }
 
-pub(super) fn write_contig<W>(writer: &mut W, id: &str, contig: &Map<Contig>) -> io::Result<()>
+pub(super) fn write_contig<W>(
+    writer: &mut W,
+    id: &str,
+    contig: &Map<Contig>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
     write_record(writer, &record::key::CONTIG, |w| {
-        value::write_map(w, id, |x| value::map::write_contig(x, contig))
+        value::write_map(w, id, |x| value::map::write_contig(x, contig, options))
     })
 }
 
@@ -108,6 +128,8 @@ pub(super) fn write_other<W>(
 where
     W: Write,
 {
+    use crate::header::record::key::other::PEDIGREE;
+
     const META: &str = "META";
 
     match collection {
@@ -119,12 +141,10 @@ where
         Collection::Structured(maps) => {
             for (id, map) in maps {
                 write_record(writer, key, |w| {
-                    value::write_other_map(w, map.id_tag(), id, |x| {
-                        if key.as_ref() == META {
-                            value::map::write_meta(x, map)
-                        } else {
-                            value::map::write_other(x, map)
-                        }
+                    value::write_other_map(w, map.id_tag(), id, |x| match key.as_ref() {
+                        META => value::map::write_meta(x, map),
+                        PEDIGREE => value::map::write_pedigree(x, map),
+                        _ => value::map::write_other(x, map),
                     })
                 })?;
             }
@@ -5,12 +5,14 @@ mod format;
 mod info;
 mod meta;
 mod other;
+mod pedigree;
 
 use std::io::{self, Write};
 
 pub(crate) use self::{
     alternative_allele::write_alternative_allele, contig::write_contig, filter::write_filter,
     format::write_format, info::write_info, meta::write_meta, other::write_other,
+    pedigree::write_pedigree,
 };
 use crate::{
     header::record::value::map::{self, tag, OtherFields},
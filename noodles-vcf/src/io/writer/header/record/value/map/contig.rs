@@ -1,12 +1,19 @@
 use std::io::{self, Write};
 
 use super::{write_delimiter, write_other_fields, write_value_field};
-use crate::header::record::value::{
-    map::{contig::tag, Contig},
-    Map,
+use crate::{
+    header::record::value::{
+        map::{contig::tag, Contig},
+        Map,
+    },
+    io::writer::header::Options,
 };
 
-pub(crate) fn write_contig<W>(writer: &mut W, contig: &Map<Contig>) -> io::Result<()>
+pub(crate) fn write_contig<W>(
+    writer: &mut W,
+    contig: &Map<Contig>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -27,6 +34,13 @@ where
 
     write_other_fields(writer, contig.other_fields())?;
 
+    if options.idx_fields {
+        if let Some(idx) = contig.idx() {
+            write_delimiter(writer)?;
+            write_value_field(writer, tag::IDX, idx.to_string())?;
+        }
+    }
+
     Ok(())
 }
 
@@ -40,7 +54,7 @@ mod tests {
 
         buf.clear();
         let map = Map::<Contig>::new();
-        write_contig(&mut buf, &map)?;
+        write_contig(&mut buf, &map, &Options::default())?;
         assert!(buf.is_empty());
 
         buf.clear();
@@ -50,12 +64,22 @@ mod tests {
             .set_url("https://example.com/reference.fa")
             .insert("noodles".parse()?, "vcf")
             .build()?;
-        write_contig(&mut buf, &map)?;
+        write_contig(&mut buf, &map, &Options::default())?;
         assert_eq!(
             buf,
             br#",length=8,md5=d7eba311421bbc9d3ada44709dd61534,URL=https://example.com/reference.fa,noodles="vcf""#
         );
 
+        buf.clear();
+        let map = Map::<Contig>::builder().set_idx(1).build()?;
+        write_contig(&mut buf, &map, &Options::default())?;
+        assert!(buf.is_empty());
+
+        buf.clear();
+        let options = Options { idx_fields: true };
+        write_contig(&mut buf, &map, &options)?;
+        assert_eq!(buf, b",IDX=1");
+
         Ok(())
     }
 }
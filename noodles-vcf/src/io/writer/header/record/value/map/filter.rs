@@ -1,14 +1,31 @@
 use std::io::{self, Write};
 
-use super::{write_description_field, write_other_fields};
-use crate::header::record::value::{map::Filter, Map};
+use super::{write_delimiter, write_description_field, write_other_fields, write_value_field};
+use crate::{
+    header::record::value::{map::Filter, Map},
+    io::writer::header::Options,
+};
 
-pub(crate) fn write_filter<W>(writer: &mut W, filter: &Map<Filter>) -> io::Result<()>
+pub(crate) fn write_filter<W>(
+    writer: &mut W,
+    filter: &Map<Filter>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
     write_description_field(writer, filter.description())?;
     write_other_fields(writer, filter.other_fields())?;
+
+    if options.idx_fields {
+        if let Some(idx) = filter.idx() {
+            use crate::header::record::value::map::filter::tag::IDX;
+
+            write_delimiter(writer)?;
+            write_value_field(writer, IDX, idx.to_string())?;
+        }
+    }
+
     Ok(())
 }
 
@@ -22,7 +39,7 @@ mod tests {
 
         buf.clear();
         let map = Map::<Filter>::new("All filters passed");
-        write_filter(&mut buf, &map)?;
+        write_filter(&mut buf, &map, &Options::default())?;
         assert_eq!(buf, br#",Description="All filters passed""#);
 
         buf.clear();
@@ -30,9 +47,20 @@ mod tests {
             .set_description("All filters passed")
             .insert("noodles".parse()?, "vcf")
             .build()?;
-        write_filter(&mut buf, &map)?;
+        write_filter(&mut buf, &map, &Options::default())?;
         assert_eq!(buf, br#",Description="All filters passed",noodles="vcf""#);
 
+        buf.clear();
+        let mut map = Map::<Filter>::new("All filters passed");
+        *map.idx_mut() = Some(0);
+        write_filter(&mut buf, &map, &Options::default())?;
+        assert_eq!(buf, br#",Description="All filters passed""#);
+
+        buf.clear();
+        let options = Options { idx_fields: true };
+        write_filter(&mut buf, &map, &options)?;
+        assert_eq!(buf, br#",Description="All filters passed",IDX=0"#);
+
         Ok(())
     }
 }
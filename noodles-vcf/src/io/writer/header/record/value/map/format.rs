@@ -6,16 +6,24 @@ use std::io::{self, Write};
 use self::{number::write_number, ty::write_type};
 use super::{
     write_delimiter, write_description_field, write_key, write_other_fields, write_separator,
+    write_value_field,
 };
-use crate::header::record::value::{
-    map::{
-        format::{Number, Type},
-        Format,
+use crate::{
+    header::record::value::{
+        map::{
+            format::{Number, Type},
+            Format,
+        },
+        Map,
     },
-    Map,
+    io::writer::header::Options,
 };
 
-pub(crate) fn write_format<W>(writer: &mut W, format: &Map<Format>) -> io::Result<()>
+pub(crate) fn write_format<W>(
+    writer: &mut W,
+    format: &Map<Format>,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
 {
@@ -23,6 +31,16 @@ where
     write_type_field(writer, format.ty())?;
     write_description_field(writer, format.description())?;
     write_other_fields(writer, format.other_fields())?;
+
+    if options.idx_fields {
+        if let Some(idx) = format.idx() {
+            use crate::header::record::value::map::format::tag::IDX;
+
+            write_delimiter(writer)?;
+            write_value_field(writer, IDX, idx.to_string())?;
+        }
+    }
+
     Ok(())
 }
 
@@ -65,19 +83,33 @@ mod tests {
 
         buf.clear();
         let map = Map::<Format>::from(key::GENOTYPE);
-        write_format(&mut buf, &map)?;
+        write_format(&mut buf, &map, &Options::default())?;
         assert_eq!(buf, br#",Number=1,Type=String,Description="Genotype""#);
 
         buf.clear();
         let mut map = Map::<Format>::from(key::GENOTYPE);
         map.other_fields_mut()
             .insert("noodles".parse()?, String::from("vcf"));
-        write_format(&mut buf, &map)?;
+        write_format(&mut buf, &map, &Options::default())?;
         assert_eq!(
             buf,
             br#",Number=1,Type=String,Description="Genotype",noodles="vcf""#
         );
 
+        buf.clear();
+        let mut map = Map::<Format>::from(key::GENOTYPE);
+        *map.idx_mut() = Some(2);
+        write_format(&mut buf, &map, &Options::default())?;
+        assert_eq!(buf, br#",Number=1,Type=String,Description="Genotype""#);
+
+        buf.clear();
+        let options = Options { idx_fields: true };
+        write_format(&mut buf, &map, &options)?;
+        assert_eq!(
+            buf,
+            br#",Number=1,Type=String,Description="Genotype",IDX=2"#
+        );
+
         Ok(())
     }
 }
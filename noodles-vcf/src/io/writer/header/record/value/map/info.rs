@@ -6,16 +6,20 @@ use std::io::{self, Write};
 use self::{number::write_number, ty::write_type};
 use super::{
     write_delimiter, write_description_field, write_key, write_other_fields, write_separator,
+    write_value_field,
 };
-use crate::header::record::value::{
-    map::{
-        info::{Number, Type},
-        Info,
+use crate::{
+    header::record::value::{
+        map::{
+            info::{Number, Type},
+            Info,
+        },
+        Map,
     },
-    Map,
+    io::writer::header::Options,
 };
 
-pub(crate) fn write_info<W>(writer: &mut W, info: &Map<Info>) -> io::Result<()>
+pub(crate) fn write_info<W>(writer: &mut W, info: &Map<Info>, options: &Options) -> io::Result<()>
 where
     W: Write,
 {
@@ -23,6 +27,16 @@ where
     write_type_field(writer, info.ty())?;
     write_description_field(writer, info.description())?;
     write_other_fields(writer, info.other_fields())?;
+
+    if options.idx_fields {
+        if let Some(idx) = info.idx() {
+            use crate::header::record::value::map::info::tag::IDX;
+
+            write_delimiter(writer)?;
+            write_value_field(writer, IDX, idx.to_string())?;
+        }
+    }
+
     Ok(())
 }
 
@@ -65,7 +79,7 @@ mod tests {
 
         buf.clear();
         let map = Map::<Info>::from(key::SAMPLES_WITH_DATA_COUNT);
-        write_info(&mut buf, &map)?;
+        write_info(&mut buf, &map, &Options::default())?;
         assert_eq!(
             buf,
             br#",Number=1,Type=Integer,Description="Number of samples with data""#
@@ -75,12 +89,29 @@ mod tests {
         let mut map = Map::<Info>::from(key::SAMPLES_WITH_DATA_COUNT);
         map.other_fields_mut()
             .insert("noodles".parse()?, String::from("vcf"));
-        write_info(&mut buf, &map)?;
+        write_info(&mut buf, &map, &Options::default())?;
         assert_eq!(
             buf,
             br#",Number=1,Type=Integer,Description="Number of samples with data",noodles="vcf""#
         );
 
+        buf.clear();
+        let mut map = Map::<Info>::from(key::SAMPLES_WITH_DATA_COUNT);
+        *map.idx_mut() = Some(1);
+        write_info(&mut buf, &map, &Options::default())?;
+        assert_eq!(
+            buf,
+            br#",Number=1,Type=Integer,Description="Number of samples with data""#
+        );
+
+        buf.clear();
+        let options = Options { idx_fields: true };
+        write_info(&mut buf, &map, &options)?;
+        assert_eq!(
+            buf,
+            br#",Number=1,Type=Integer,Description="Number of samples with data",IDX=1"#
+        );
+
         Ok(())
     }
 }
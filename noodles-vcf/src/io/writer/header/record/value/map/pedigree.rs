@@ -0,0 +1,50 @@
+use std::io::{self, Write};
+
+use super::{write_delimiter, write_string_field, write_value_field};
+use crate::header::record::value::{map::Other, Map};
+
+pub(crate) fn write_pedigree<W>(writer: &mut W, pedigree: &Map<Other>) -> io::Result<()>
+where
+    W: Write,
+{
+    for (key, value) in pedigree.other_fields() {
+        write_delimiter(writer)?;
+
+        // Pedigree values are typically sample names, which are written unquoted when possible.
+        if is_unquotable(value) {
+            write_value_field(writer, key, value)?;
+        } else {
+            write_string_field(writer, key, value)?;
+        }
+    }
+
+    Ok(())
+}
+
+fn is_unquotable(s: &str) -> bool {
+    !s.is_empty()
+        && !s
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, ',' | '<' | '>' | '"' | '\\' | '='))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_write_pedigree() -> Result<(), Box<dyn std::error::Error>> {
+        let mut buf = Vec::new();
+
+        let map = Map::<Other>::builder()
+            .insert("Father".parse()?, "FID")
+            .insert("Mother".parse()?, "MID")
+            .insert("noodles".parse()?, "vcf, bcf")
+            .build()?;
+
+        write_pedigree(&mut buf, &map)?;
+        assert_eq!(buf, br#",Father=FID,Mother=MID,noodles="vcf, bcf""#);
+
+        Ok(())
+    }
+}
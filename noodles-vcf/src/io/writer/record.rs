@@ -12,16 +12,41 @@ pub(super) mod value;
 use std::io::{self, Write};
 
 use self::{
-    alternate_bases::write_alternate_bases, filters::write_filters, ids::write_ids,
-    info::write_info, position::write_position, quality_score::write_quality_score,
-    reference_bases::write_reference_bases, reference_sequence_name::write_reference_sequence_name,
-    samples::write_samples,
+    alternate_bases::write_alternate_bases,
+    filters::write_filters,
+    ids::write_ids,
+    info::{write_info, write_info_in_header_order},
+    position::write_position,
+    quality_score::write_quality_score,
+    reference_bases::write_reference_bases,
+    reference_sequence_name::write_reference_sequence_name,
+    samples::{write_samples, write_samples_with_options},
 };
 use crate::{variant::Record, Header};
 
 const MISSING: &[u8] = b".";
 
-pub(super) fn write_record<W, R>(writer: &mut W, header: &Header, record: &R) -> io::Result<()>
+/// Record serialization options.
+#[derive(Clone, Copy, Debug, Default)]
+pub(crate) struct Options {
+    /// Whether info fields and sample series are written in header declaration order.
+    pub(crate) fields_in_header_order: bool,
+    /// Whether trailing sample series that are missing in all samples are trimmed.
+    pub(crate) trim_trailing_missing_sample_series: bool,
+}
+
+impl Options {
+    fn is_default(&self) -> bool {
+        !self.fields_in_header_order && !self.trim_trailing_missing_sample_series
+    }
+}
+
+pub(crate) fn write_record<W, R>(
+    writer: &mut W,
+    header: &Header,
+    record: &R,
+    options: &Options,
+) -> io::Result<()>
 where
     W: Write,
     R: Record + ?Sized,
@@ -53,13 +78,22 @@ where
     write_filters(writer, header, record.filters())?;
 
     writer.write_all(DELIMITER)?;
-    write_info(writer, header, record.info())?;
+    if options.fields_in_header_order {
+        write_info_in_header_order(writer, header, record.info())?;
+    } else {
+        write_info(writer, header, record.info())?;
+    }
 
     let samples = record.samples()?;
 
     if !samples.is_empty() {
         writer.write_all(DELIMITER)?;
-        write_samples(writer, header, samples)?;
+
+        if options.is_default() {
+            write_samples(writer, header, samples)?;
+        } else {
+            write_samples_with_options(writer, header, samples, options)?;
+        }
     }
 
     writer.write_all(b"\n")?;
@@ -84,7 +118,7 @@ mod tests {
 
         let header = Header::default();
         let mut buf = Vec::new();
-        write_record(&mut buf, &header, &record)?;
+        write_record(&mut buf, &header, &record, &Options::default())?;
         assert_eq!(buf, b"sq0\t1\t.\tA\t.\t.\t.\t.\n");
 
         Ok(())
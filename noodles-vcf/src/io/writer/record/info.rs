@@ -30,6 +30,38 @@ where
     Ok(())
 }
 
+/// Writes info fields ordered by their header declaration order.
+///
+/// Keys that are not declared in the header are written last, in their original order.
+pub(super) fn write_info_in_header_order<W, I>(
+    writer: &mut W,
+    header: &Header,
+    info: I,
+) -> io::Result<()>
+where
+    W: Write,
+    I: Info,
+{
+    const DELIMITER: &[u8] = b";";
+
+    if info.is_empty() {
+        return writer.write_all(MISSING);
+    }
+
+    let mut fields: Vec<_> = info.iter(header).collect::<io::Result<_>>()?;
+    fields.sort_by_key(|(key, _)| header.infos().get_index_of(*key).unwrap_or(usize::MAX));
+
+    for (i, (key, value)) in fields.into_iter().enumerate() {
+        if i > 0 {
+            writer.write_all(DELIMITER)?;
+        }
+
+        write_field(writer, key, value.as_ref())?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -81,4 +113,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_info_in_header_order() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map::Info as InfoMap, Map},
+            variant::{
+                record::info::field::key,
+                record_buf::{info::field::Value as ValueBuf, Info as InfoBuf},
+            },
+        };
+
+        let header = Header::builder()
+            .add_info(key::IS_IN_DB_SNP, Map::<InfoMap>::from(key::IS_IN_DB_SNP))
+            .add_info(
+                key::SAMPLES_WITH_DATA_COUNT,
+                Map::<InfoMap>::from(key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .build();
+
+        let info: InfoBuf = [
+            (String::from("nd"), Some(ValueBuf::Flag)),
+            (
+                String::from(key::SAMPLES_WITH_DATA_COUNT),
+                Some(ValueBuf::from(2)),
+            ),
+            (String::from(key::IS_IN_DB_SNP), Some(ValueBuf::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut buf = Vec::new();
+        write_info_in_header_order(&mut buf, &header, &info)?;
+        assert_eq!(buf, b"DB;NS=2;nd");
+
+        Ok(())
+    }
 }
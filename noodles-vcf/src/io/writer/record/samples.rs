@@ -3,8 +3,15 @@ mod sample;
 
 use std::io::{self, Write};
 
-use self::{keys::write_keys, sample::write_sample};
-use crate::{variant::record::Samples, Header};
+use self::{
+    keys::write_keys,
+    sample::{write_sample, write_sample_values},
+};
+use super::Options;
+use crate::{
+    variant::record::{samples::keys::key, Samples},
+    Header,
+};
 
 pub(super) fn write_samples<W, S>(writer: &mut W, header: &Header, samples: S) -> io::Result<()>
 where
@@ -23,6 +30,83 @@ where
     Ok(())
 }
 
+/// Writes samples using the given record options.
+///
+/// When fields are written in header order, the genotype (`GT`) series is first, and the
+/// remaining series are ordered by their header declaration order. Keys that are not declared in
+/// the header are written last, in their original order.
+///
+/// When trailing missing series are trimmed, series at the end of the keys whose values are
+/// missing in all samples are not written. The genotype series and the first series are never
+/// trimmed.
+pub(super) fn write_samples_with_options<W, S>(
+    writer: &mut W,
+    header: &Header,
+    samples: S,
+    options: &Options,
+) -> io::Result<()>
+where
+    W: Write,
+    S: Samples,
+{
+    const DELIMITER: &[u8] = b"\t";
+
+    let keys: Vec<_> = samples.column_names(header).collect::<io::Result<_>>()?;
+
+    let mut indices: Vec<_> = (0..keys.len()).collect();
+
+    if options.fields_in_header_order {
+        indices.sort_by_key(|&i| {
+            let key = keys[i];
+
+            if key == key::GENOTYPE {
+                (false, 0)
+            } else {
+                let j = header.formats().get_index_of(key).unwrap_or(usize::MAX);
+                (true, j)
+            }
+        });
+    }
+
+    let samples: Vec<_> = samples.iter().collect();
+
+    let values: Vec<Vec<_>> = samples
+        .iter()
+        .map(|sample| {
+            sample
+                .iter(header)
+                .map(|result| result.map(|(_, value)| value))
+                .collect()
+        })
+        .collect::<io::Result<_>>()?;
+
+    if options.trim_trailing_missing_sample_series {
+        while indices.len() > 1 {
+            let j = indices[indices.len() - 1];
+
+            let is_missing = keys[j] != key::GENOTYPE
+                && values
+                    .iter()
+                    .all(|vs| vs.get(j).map(|v| v.is_none()).unwrap_or(true));
+
+            if !is_missing {
+                break;
+            }
+
+            indices.pop();
+        }
+    }
+
+    write_keys(writer, indices.iter().map(|&i| Ok(keys[i])))?;
+
+    for vs in &values {
+        writer.write_all(DELIMITER)?;
+        write_sample_values(writer, header, vs, &indices)?;
+    }
+
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -69,4 +153,103 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_write_samples_with_fields_in_header_order() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{map::Format, Map},
+            variant::{record::samples::keys::key, record_buf::samples::sample::Value},
+        };
+
+        let header = Header::builder()
+            .add_format(
+                key::CONDITIONAL_GENOTYPE_QUALITY,
+                Map::<Format>::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            )
+            .add_format(key::READ_DEPTH, Map::<Format>::from(key::READ_DEPTH))
+            .add_format(key::GENOTYPE, Map::<Format>::from(key::GENOTYPE))
+            .build();
+
+        let samples = SamplesBuf::new(
+            [
+                String::from(key::READ_DEPTH),
+                String::from("nd"),
+                String::from(key::GENOTYPE),
+                String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            ]
+            .into_iter()
+            .collect(),
+            vec![
+                vec![
+                    Some(Value::from(5)),
+                    Some(Value::from(1)),
+                    Some(Value::from("0|0")),
+                    Some(Value::from(13)),
+                ],
+                vec![
+                    Some(Value::from(8)),
+                    None,
+                    Some(Value::from("0/1")),
+                    Some(Value::from(21)),
+                ],
+            ],
+        );
+
+        let mut buf = Vec::new();
+        let options = Options {
+            fields_in_header_order: true,
+            ..Default::default()
+        };
+
+        write_samples_with_options(&mut buf, &header, &samples, &options)?;
+        assert_eq!(buf, b"GT:GQ:DP:nd\t0|0:13:5:1\t0/1:21:8:.");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_samples_with_trimmed_trailing_missing_series() -> io::Result<()> {
+        use crate::variant::{record::samples::keys::key, record_buf::samples::sample::Value};
+
+        let header = Header::default();
+
+        let samples = SamplesBuf::new(
+            [
+                String::from(key::GENOTYPE),
+                String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+                String::from(key::READ_DEPTH),
+            ]
+            .into_iter()
+            .collect(),
+            vec![
+                vec![Some(Value::from("0|0")), Some(Value::from(13)), None],
+                vec![Some(Value::from("0/1")), None, None],
+            ],
+        );
+
+        let options = Options {
+            trim_trailing_missing_sample_series: true,
+            ..Default::default()
+        };
+
+        let mut buf = Vec::new();
+        write_samples_with_options(&mut buf, &header, &samples, &options)?;
+        assert_eq!(buf, b"GT:GQ\t0|0:13\t0/1:.");
+
+        let samples = SamplesBuf::new(
+            [
+                String::from(key::GENOTYPE),
+                String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            ]
+            .into_iter()
+            .collect(),
+            vec![vec![None, None]],
+        );
+
+        buf.clear();
+        write_samples_with_options(&mut buf, &header, &samples, &options)?;
+        assert_eq!(buf, b"GT\t.");
+
+        Ok(())
+    }
 }
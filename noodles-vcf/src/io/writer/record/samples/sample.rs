@@ -3,7 +3,11 @@ mod value;
 use std::io::{self, Write};
 
 use self::value::write_value;
-use crate::{io::writer::record::MISSING, variant::record::samples::Sample, Header};
+use crate::{
+    io::writer::record::MISSING,
+    variant::record::samples::{series::Value, Sample},
+    Header,
+};
 
 pub(super) fn write_sample<W, S>(writer: &mut W, header: &Header, sample: S) -> io::Result<()>
 where
@@ -27,3 +31,29 @@ where
 
     Ok(())
 }
+
+/// Writes the given values of a sample in the order of the given series indices.
+pub(super) fn write_sample_values<W>(
+    writer: &mut W,
+    header: &Header,
+    values: &[Option<Value<'_>>],
+    indices: &[usize],
+) -> io::Result<()>
+where
+    W: Write,
+{
+    const DELIMITER: &[u8] = b":";
+
+    for (i, &j) in indices.iter().enumerate() {
+        if i > 0 {
+            writer.write_all(DELIMITER)?;
+        }
+
+        match values.get(j).and_then(|value| value.as_ref()) {
+            Some(v) => write_value(writer, header, v)?,
+            None => writer.write_all(MISSING)?,
+        }
+    }
+
+    Ok(())
+}
@@ -22,13 +22,18 @@
 #[cfg(feature = "async")]
 pub mod r#async;
 
+mod group_by_position;
 pub mod header;
 mod indexer;
 pub mod io;
+mod merge;
 pub mod record;
 pub mod variant;
 
-pub use self::{header::Header, indexer::index, record::Record};
+pub use self::{
+    group_by_position::group_by_position, header::Header, indexer::index, merge::merge,
+    record::Record,
+};
 
 #[cfg(feature = "async")]
 pub use self::r#async::io::{Reader as AsyncReader, Writer as AsyncWriter};
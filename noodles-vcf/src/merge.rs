@@ -0,0 +1,264 @@
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    io::{self, BufRead},
+    iter,
+};
+
+use noodles_core::Position;
+
+use crate::{io::Reader, variant::RecordBuf, Header};
+
+/// Merges records from multiple coordinate-sorted VCF readers into a single coordinate-sorted
+/// iterator.
+///
+/// Records are ordered by reference sequence (contig) index, as defined by the given header, and
+/// then by position. Records that compare equal are returned in input order.
+///
+/// Each reader is expected to be directly after its header, and its records are parsed using the
+/// given header. All inputs must be sorted using the contig ordering of the given header.
+///
+/// Iteration stops after the first error.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf as vcf;
+///
+/// const SRC_0: &[u8] = b"##fileformat=VCFv4.3
+/// ###contig=<ID=sq0>
+/// ###contig=<ID=sq1>
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq0\t1\t.\tA\t.\t.\tPASS\t.
+/// ";
+///
+/// const SRC_1: &[u8] = b"##fileformat=VCFv4.3
+/// ###contig=<ID=sq0>
+/// ###contig=<ID=sq1>
+/// #CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+/// sq1\t1\t.\tA\t.\t.\tPASS\t.
+/// ";
+///
+/// let mut readers = vec![vcf::io::Reader::new(SRC_0), vcf::io::Reader::new(SRC_1)];
+///
+/// let mut header = None;
+///
+/// for reader in &mut readers {
+///     header = Some(reader.read_header()?);
+/// }
+///
+/// let header = header.expect("missing header");
+///
+/// for result in vcf::merge(readers, &header) {
+///     let record = result?;
+///     // ...
+/// }
+/// # Ok::<_, std::io::Error>(())
+/// ```
+pub fn merge<'h, R>(
+    mut readers: Vec<Reader<R>>,
+    header: &'h Header,
+) -> impl Iterator<Item = io::Result<RecordBuf>> + 'h
+where
+    R: BufRead + 'h,
+{
+    let mut heap = BinaryHeap::with_capacity(readers.len());
+    let mut is_initialized = false;
+    let mut error = None;
+
+    iter::from_fn(move || {
+        if let Some(e) = error.take() {
+            heap.clear();
+            return Some(Err(e));
+        }
+
+        if !is_initialized {
+            is_initialized = true;
+
+            for (i, reader) in readers.iter_mut().enumerate() {
+                match read_entry(reader, header, i) {
+                    Ok(Some(entry)) => heap.push(Reverse(entry)),
+                    Ok(None) => {}
+                    Err(e) => {
+                        heap.clear();
+                        return Some(Err(e));
+                    }
+                }
+            }
+        }
+
+        let Reverse(entry) = heap.pop()?;
+
+        match read_entry(&mut readers[entry.i], header, entry.i) {
+            Ok(Some(next_entry)) => heap.push(Reverse(next_entry)),
+            Ok(None) => {}
+            Err(e) => error = Some(e),
+        }
+
+        Some(Ok(entry.record))
+    })
+}
+
+type Key = (usize, Option<Position>);
+
+struct Entry {
+    key: Key,
+    i: usize,
+    record: RecordBuf,
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other).is_eq()
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key.cmp(&other.key).then(self.i.cmp(&other.i))
+    }
+}
+
+fn read_entry<R>(reader: &mut Reader<R>, header: &Header, i: usize) -> io::Result<Option<Entry>>
+where
+    R: BufRead,
+{
+    let mut record = RecordBuf::default();
+
+    if reader.read_record_buf(header, &mut record)? == 0 {
+        return Ok(None);
+    }
+
+    let key = sort_key(header, &record)?;
+
+    Ok(Some(Entry { key, i, record }))
+}
+
+fn sort_key(header: &Header, record: &RecordBuf) -> io::Result<Key> {
+    let reference_sequence_name = record.reference_sequence_name();
+
+    let reference_sequence_id = header
+        .contigs()
+        .get_index_of(reference_sequence_name)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("missing contig in header: {reference_sequence_name}"),
+            )
+        })?;
+
+    Ok((reference_sequence_id, record.variant_start()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() -> io::Result<()> {
+        const SRC_0: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tr0\tA\t.\t.\tPASS\t.
+sq0\t5\tr1\tA\t.\t.\tPASS\t.
+sq1\t3\tr2\tA\t.\t.\tPASS\t.
+";
+
+        const SRC_1: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0>
+##contig=<ID=sq1>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t2\tr3\tA\t.\t.\tPASS\t.
+sq0\t5\tr4\tA\t.\t.\tPASS\t.
+sq1\t1\tr5\tA\t.\t.\tPASS\t.
+";
+
+        let mut readers = vec![Reader::new(SRC_0), Reader::new(SRC_1)];
+        let header = readers[0].read_header()?;
+        readers[1].read_header()?;
+
+        let records: Vec<_> = merge(readers, &header).collect::<io::Result<_>>()?;
+
+        let actual: Vec<_> = records
+            .iter()
+            .map(|record| {
+                record
+                    .ids()
+                    .as_ref()
+                    .iter()
+                    .next()
+                    .map(String::as_str)
+                    .unwrap_or_default()
+            })
+            .collect();
+
+        assert_eq!(actual, ["r0", "r3", "r1", "r4", "r5", "r2"]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_missing_contig() -> io::Result<()> {
+        const SRC: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq1\t1\t.\tA\t.\t.\tPASS\t.
+";
+
+        let mut reader = Reader::new(SRC);
+        let header = reader.read_header()?;
+
+        let mut records = merge(vec![reader], &header);
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_with_invalid_record() -> io::Result<()> {
+        const SRC_0: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t1\tr0\tA\t.\t.\tPASS\t.
+sq0\tndls\tr1\tA\t.\t.\tPASS\t.
+";
+
+        const SRC_1: &[u8] = b"##fileformat=VCFv4.3
+##contig=<ID=sq0>
+#CHROM\tPOS\tID\tREF\tALT\tQUAL\tFILTER\tINFO
+sq0\t2\tr2\tA\t.\t.\tPASS\t.
+sq0\t3\tr3\tA\t.\t.\tPASS\t.
+";
+
+        let mut readers = vec![Reader::new(SRC_0), Reader::new(SRC_1)];
+        let header = readers[0].read_header()?;
+        readers[1].read_header()?;
+
+        let mut records = merge(readers, &header);
+
+        let record = records.next().transpose()?.expect("missing record");
+        assert_eq!(record.variant_start(), Position::new(1));
+
+        assert!(matches!(
+            records.next(),
+            Some(Err(e)) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert!(records.next().is_none());
+
+        Ok(())
+    }
+}
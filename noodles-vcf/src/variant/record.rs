@@ -1,6 +1,6 @@
 //! Variant record.
 
-mod alternate_bases;
+pub mod alternate_bases;
 mod filters;
 mod ids;
 pub mod info;
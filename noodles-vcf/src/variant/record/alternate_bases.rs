@@ -1,3 +1,10 @@
+//! Variant record alternate bases.
+
+pub mod breakend;
+pub mod symbol;
+
+pub use self::{breakend::Breakend, symbol::Symbol};
+
 use std::io;
 
 /// Variant record alternate bases.
@@ -0,0 +1,379 @@
+//! Variant record alternate bases breakend.
+
+use std::{error, fmt, str::FromStr};
+
+use noodles_core::{position, Position};
+
+/// The side of the sequence the breakend join is on.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Join {
+    /// The join is before the sequence (`]p]t`, `[p[t`, `.t`).
+    Before,
+    /// The join is after the sequence (`t[p[`, `t]p]`, `t.`).
+    After,
+}
+
+/// The direction the joined piece extends from the mate position.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Direction {
+    /// The joined piece extends to the left of the mate position (`]`).
+    Left,
+    /// The joined piece extends to the right of the mate position (`[`).
+    Right,
+}
+
+impl Direction {
+    fn as_char(&self) -> char {
+        match self {
+            Self::Left => ']',
+            Self::Right => '[',
+        }
+    }
+}
+
+/// A breakend mate.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Mate {
+    reference_sequence_name: String,
+    position: Position,
+    direction: Direction,
+}
+
+impl Mate {
+    /// Creates a breakend mate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::variant::record::alternate_bases::breakend::{Direction, Mate};
+    /// let mate = Mate::new(String::from("sq1"), Position::try_from(13)?, Direction::Right);
+    /// # Ok::<_, noodles_core::position::TryFromIntError>(())
+    /// ```
+    pub fn new(reference_sequence_name: String, position: Position, direction: Direction) -> Self {
+        Self {
+            reference_sequence_name,
+            position,
+            direction,
+        }
+    }
+
+    /// Returns the reference sequence name of the mate.
+    ///
+    /// An assembly contig is given in angle brackets, e.g., `<ctg1>`.
+    pub fn reference_sequence_name(&self) -> &str {
+        &self.reference_sequence_name
+    }
+
+    /// Returns the position of the mate.
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    /// Returns the direction the joined piece extends from the mate position.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+}
+
+/// A breakend alternate allele.
+///
+/// A breakend is either paired with a mate breakend (`t[p[`, `t]p]`, `]p]t`, `[p[t`) or single
+/// (`t.`, `.t`), where `t` is the sequence and `p` is the mate position.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_core::Position;
+/// use noodles_vcf::variant::record::alternate_bases::{
+///     breakend::{Direction, Join},
+///     Breakend,
+/// };
+///
+/// let breakend: Breakend = "G[sq1:13[".parse()?;
+/// assert_eq!(breakend.sequence(), "G");
+/// assert_eq!(breakend.join(), Join::After);
+///
+/// let mate = breakend.mate().expect("missing mate");
+/// assert_eq!(mate.reference_sequence_name(), "sq1");
+/// assert_eq!(mate.position(), Position::try_from(13)?);
+/// assert_eq!(mate.direction(), Direction::Right);
+///
+/// assert_eq!(breakend.to_string(), "G[sq1:13[");
+/// # Ok::<_, Box<dyn std::error::Error>>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Breakend {
+    sequence: String,
+    join: Join,
+    mate: Option<Mate>,
+}
+
+impl Breakend {
+    /// Creates a breakend.
+    ///
+    /// A breakend without a mate is a single breakend.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::alternate_bases::{breakend::Join, Breakend};
+    /// let breakend = Breakend::new(String::from("G"), Join::After, None);
+    /// assert_eq!(breakend.to_string(), "G.");
+    /// ```
+    pub fn new(sequence: String, join: Join, mate: Option<Mate>) -> Self {
+        Self {
+            sequence,
+            join,
+            mate,
+        }
+    }
+
+    /// Returns the sequence.
+    ///
+    /// This is the reference base at the breakend, possibly with inserted bases.
+    pub fn sequence(&self) -> &str {
+        &self.sequence
+    }
+
+    /// Returns the side of the sequence the join is on.
+    pub fn join(&self) -> Join {
+        self.join
+    }
+
+    /// Returns the mate breakend.
+    ///
+    /// This is `None` for a single breakend.
+    pub fn mate(&self) -> Option<&Mate> {
+        self.mate.as_ref()
+    }
+}
+
+impl fmt::Display for Breakend {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const SINGLE: char = '.';
+
+        let Some(mate) = self.mate() else {
+            return match self.join {
+                Join::Before => write!(f, "{SINGLE}{}", self.sequence),
+                Join::After => write!(f, "{}{SINGLE}", self.sequence),
+            };
+        };
+
+        let bracket = mate.direction().as_char();
+        let name = mate.reference_sequence_name();
+        let position = mate.position();
+
+        match self.join {
+            Join::Before => write!(f, "{bracket}{name}:{position}{bracket}{}", self.sequence),
+            Join::After => write!(f, "{}{bracket}{name}:{position}{bracket}", self.sequence),
+        }
+    }
+}
+
+/// An error returned when a raw breakend fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+    /// The sequence is missing.
+    MissingSequence,
+    /// The mate reference sequence name is missing.
+    MissingMateReferenceSequenceName,
+    /// The mate position is invalid.
+    InvalidMatePosition(position::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidMatePosition(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+            Self::MissingSequence => f.write_str("missing sequence"),
+            Self::MissingMateReferenceSequenceName => {
+                f.write_str("missing mate reference sequence name")
+            }
+            Self::InvalidMatePosition(_) => f.write_str("invalid mate position"),
+        }
+    }
+}
+
+impl FromStr for Breakend {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        const SINGLE: char = '.';
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        if let Some(direction) = s.chars().next().and_then(parse_direction) {
+            let bracket = direction.as_char();
+
+            let (raw_mate, sequence) = s[1..].split_once(bracket).ok_or(ParseError::Invalid)?;
+            let sequence = parse_sequence(sequence)?;
+            let mate = parse_mate(raw_mate, direction)?;
+
+            Ok(Self::new(sequence, Join::Before, Some(mate)))
+        } else if let Some(direction) = s.chars().next_back().and_then(parse_direction) {
+            let bracket = direction.as_char();
+
+            let (sequence, raw_mate) = s[..s.len() - 1]
+                .rsplit_once(bracket)
+                .ok_or(ParseError::Invalid)?;
+            let sequence = parse_sequence(sequence)?;
+            let mate = parse_mate(raw_mate, direction)?;
+
+            Ok(Self::new(sequence, Join::After, Some(mate)))
+        } else if let Some(sequence) = s.strip_prefix(SINGLE) {
+            parse_sequence(sequence).map(|sequence| Self::new(sequence, Join::Before, None))
+        } else if let Some(sequence) = s.strip_suffix(SINGLE) {
+            parse_sequence(sequence).map(|sequence| Self::new(sequence, Join::After, None))
+        } else {
+            Err(ParseError::Invalid)
+        }
+    }
+}
+
+fn parse_direction(c: char) -> Option<Direction> {
+    match c {
+        ']' => Some(Direction::Left),
+        '[' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+fn parse_sequence(s: &str) -> Result<String, ParseError> {
+    const SINGLE: char = '.';
+
+    if s.is_empty() {
+        Err(ParseError::MissingSequence)
+    } else if s.contains(['[', ']', SINGLE]) {
+        Err(ParseError::Invalid)
+    } else {
+        Ok(s.into())
+    }
+}
+
+fn parse_mate(s: &str, direction: Direction) -> Result<Mate, ParseError> {
+    let (reference_sequence_name, raw_position) = s.rsplit_once(':').ok_or(ParseError::Invalid)?;
+
+    if reference_sequence_name.is_empty() {
+        return Err(ParseError::MissingMateReferenceSequenceName);
+    }
+
+    let position = raw_position
+        .parse()
+        .map_err(ParseError::InvalidMatePosition)?;
+
+    Ok(Mate::new(
+        reference_sequence_name.into(),
+        position,
+        direction,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() -> Result<(), Box<dyn std::error::Error>> {
+        let position = Position::try_from(123)?;
+
+        let mate = |direction| Some(Mate::new(String::from("chr2"), position, direction));
+
+        assert_eq!(
+            "t[chr2:123[".parse(),
+            Ok(Breakend::new(
+                String::from("t"),
+                Join::After,
+                mate(Direction::Right)
+            ))
+        );
+        assert_eq!(
+            "t]chr2:123]".parse(),
+            Ok(Breakend::new(
+                String::from("t"),
+                Join::After,
+                mate(Direction::Left)
+            ))
+        );
+        assert_eq!(
+            "]chr2:123]t".parse(),
+            Ok(Breakend::new(
+                String::from("t"),
+                Join::Before,
+                mate(Direction::Left)
+            ))
+        );
+        assert_eq!(
+            "[chr2:123[t".parse(),
+            Ok(Breakend::new(
+                String::from("t"),
+                Join::Before,
+                mate(Direction::Right)
+            ))
+        );
+
+        assert_eq!(
+            "G.".parse(),
+            Ok(Breakend::new(String::from("G"), Join::After, None))
+        );
+        assert_eq!(
+            ".G".parse(),
+            Ok(Breakend::new(String::from("G"), Join::Before, None))
+        );
+
+        assert_eq!("".parse::<Breakend>(), Err(ParseError::Empty));
+        assert_eq!("G".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!(".".parse::<Breakend>(), Err(ParseError::MissingSequence));
+        assert_eq!(
+            "[chr2:123[".parse::<Breakend>(),
+            Err(ParseError::MissingSequence)
+        );
+        assert_eq!("G[chr2:123]".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!("G[chr2[".parse::<Breakend>(), Err(ParseError::Invalid));
+        assert_eq!(
+            "G[:123[".parse::<Breakend>(),
+            Err(ParseError::MissingMateReferenceSequenceName)
+        );
+        assert!(matches!(
+            "G[chr2:0[".parse::<Breakend>(),
+            Err(ParseError::InvalidMatePosition(_))
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt_round_trip() -> Result<(), ParseError> {
+        for s in [
+            "t[chr2:123[",
+            "t]chr2:123]",
+            "]chr2:123]t",
+            "[chr2:123[t",
+            "TGC[chr2:123[",
+            "C[<ctg1>:7[",
+            "G.",
+            ".G",
+        ] {
+            let breakend: Breakend = s.parse()?;
+            assert_eq!(breakend.to_string(), s);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,152 @@
+//! Variant record alternate bases symbol.
+
+pub mod structural_variant;
+
+pub use self::structural_variant::StructuralVariant;
+
+use std::{error, fmt, str::FromStr};
+
+const UNSPECIFIED: &str = "*";
+
+/// A symbolic alternate allele.
+///
+/// This is the ID of a symbolic allele, i.e., the text between the angle brackets, e.g., `DEL` in
+/// `<DEL>`.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::record::alternate_bases::{
+///     symbol::{structural_variant::Type, StructuralVariant},
+///     Symbol,
+/// };
+///
+/// let symbol: Symbol = "DUP:TANDEM".parse()?;
+/// assert_eq!(
+///     symbol,
+///     Symbol::StructuralVariant(StructuralVariant::new(
+///         Type::Duplication,
+///         vec![String::from("TANDEM")],
+///     ))
+/// );
+///
+/// let symbol: Symbol = "NON_REF".parse()?;
+/// assert_eq!(symbol, Symbol::NonstructuralVariant(String::from("NON_REF")));
+/// # Ok::<_, noodles_vcf::variant::record::alternate_bases::symbol::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Symbol {
+    /// A structural variant (`DEL`, `INS`, `DUP`, `INV`, `CNV`, `BND`), with optional subtypes.
+    StructuralVariant(StructuralVariant),
+    /// A nonstructural variant, e.g., `NON_REF`.
+    NonstructuralVariant(String),
+    /// An unspecified allele (`*`).
+    Unspecified,
+}
+
+impl fmt::Display for Symbol {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::StructuralVariant(structural_variant) => write!(f, "{structural_variant}"),
+            Self::NonstructuralVariant(id) => f.write_str(id),
+            Self::Unspecified => f.write_str(UNSPECIFIED),
+        }
+    }
+}
+
+/// An error returned when a raw symbolic allele fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The structural variant is invalid.
+    InvalidStructuralVariant(structural_variant::ParseError),
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::Empty => None,
+            Self::InvalidStructuralVariant(e) => Some(e),
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidStructuralVariant(_) => f.write_str("invalid structural variant"),
+        }
+    }
+}
+
+impl FromStr for Symbol {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use self::structural_variant::{ParseError as StructuralVariantParseError, TypeParseError};
+
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        } else if s == UNSPECIFIED {
+            return Ok(Self::Unspecified);
+        }
+
+        match s.parse() {
+            Ok(structural_variant) => Ok(Self::StructuralVariant(structural_variant)),
+            Err(StructuralVariantParseError::InvalidType(TypeParseError::Invalid)) => {
+                Ok(Self::NonstructuralVariant(s.into()))
+            }
+            Err(e) => Err(ParseError::InvalidStructuralVariant(e)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{structural_variant::Type, *};
+
+    #[test]
+    fn test_fmt() {
+        let symbol = Symbol::StructuralVariant(StructuralVariant::new(
+            Type::Duplication,
+            vec![String::from("TANDEM")],
+        ));
+        assert_eq!(symbol.to_string(), "DUP:TANDEM");
+
+        let symbol = Symbol::NonstructuralVariant(String::from("NON_REF"));
+        assert_eq!(symbol.to_string(), "NON_REF");
+
+        assert_eq!(Symbol::Unspecified.to_string(), "*");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "DEL".parse(),
+            Ok(Symbol::StructuralVariant(StructuralVariant::new(
+                Type::Deletion,
+                Vec::new()
+            )))
+        );
+        assert_eq!(
+            "DUP:TANDEM".parse(),
+            Ok(Symbol::StructuralVariant(StructuralVariant::new(
+                Type::Duplication,
+                vec![String::from("TANDEM")]
+            )))
+        );
+        assert_eq!(
+            "NON_REF".parse(),
+            Ok(Symbol::NonstructuralVariant(String::from("NON_REF")))
+        );
+        assert_eq!("*".parse(), Ok(Symbol::Unspecified));
+
+        assert_eq!("".parse::<Symbol>(), Err(ParseError::Empty));
+        assert!(matches!(
+            "DEL::ME".parse::<Symbol>(),
+            Err(ParseError::InvalidStructuralVariant(_))
+        ));
+    }
+}
@@ -0,0 +1,296 @@
+//! Variant record alternate bases symbol structural variant.
+
+use std::{error, fmt, str::FromStr};
+
+const DELIMITER: char = ':';
+
+/// A structural variant type.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Type {
+    /// Deletion relative to the reference (`DEL`).
+    Deletion,
+    /// Insertion of novel sequence relative to the reference (`INS`).
+    Insertion,
+    /// Region of elevated copy number relative to the reference (`DUP`).
+    Duplication,
+    /// Inversion of reference sequence (`INV`).
+    Inversion,
+    /// Copy number variable region (`CNV`).
+    CopyNumberVariation,
+    /// Breakend (`BND`).
+    Breakend,
+}
+
+impl AsRef<str> for Type {
+    fn as_ref(&self) -> &str {
+        match self {
+            Self::Deletion => "DEL",
+            Self::Insertion => "INS",
+            Self::Duplication => "DUP",
+            Self::Inversion => "INV",
+            Self::CopyNumberVariation => "CNV",
+            Self::Breakend => "BND",
+        }
+    }
+}
+
+impl fmt::Display for Type {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_ref())
+    }
+}
+
+/// An error returned when a raw structural variant type fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum TypeParseError {
+    /// The input is empty.
+    Empty,
+    /// The input is invalid.
+    Invalid,
+}
+
+impl error::Error for TypeParseError {}
+
+impl fmt::Display for TypeParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::Invalid => f.write_str("invalid input"),
+        }
+    }
+}
+
+impl FromStr for Type {
+    type Err = TypeParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "" => Err(TypeParseError::Empty),
+            "DEL" => Ok(Self::Deletion),
+            "INS" => Ok(Self::Insertion),
+            "DUP" => Ok(Self::Duplication),
+            "INV" => Ok(Self::Inversion),
+            "CNV" => Ok(Self::CopyNumberVariation),
+            "BND" => Ok(Self::Breakend),
+            _ => Err(TypeParseError::Invalid),
+        }
+    }
+}
+
+/// A structural variant symbol.
+///
+/// A structural variant has a type and a list of colon-delimited subtypes, e.g., `DUP:TANDEM`.
+/// Subtypes are not restricted to the ones reserved by the VCF specification.
+///
+/// # Examples
+///
+/// ```
+/// use noodles_vcf::variant::record::alternate_bases::symbol::{
+///     structural_variant::Type,
+///     StructuralVariant,
+/// };
+///
+/// let structural_variant: StructuralVariant = "DUP:TANDEM".parse()?;
+/// assert_eq!(structural_variant.ty(), Type::Duplication);
+/// assert_eq!(structural_variant.subtypes(), [String::from("TANDEM")]);
+/// assert_eq!(structural_variant.to_string(), "DUP:TANDEM");
+/// # Ok::<_, noodles_vcf::variant::record::alternate_bases::symbol::structural_variant::ParseError>(())
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StructuralVariant {
+    ty: Type,
+    subtypes: Vec<String>,
+}
+
+impl StructuralVariant {
+    /// Creates a structural variant.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::alternate_bases::symbol::{
+    ///     structural_variant::Type,
+    ///     StructuralVariant,
+    /// };
+    ///
+    /// let structural_variant =
+    ///     StructuralVariant::new(Type::Duplication, vec![String::from("TANDEM")]);
+    /// assert_eq!(structural_variant.to_string(), "DUP:TANDEM");
+    /// ```
+    pub fn new(ty: Type, subtypes: Vec<String>) -> Self {
+        Self { ty, subtypes }
+    }
+
+    /// Returns the type.
+    pub fn ty(&self) -> Type {
+        self.ty
+    }
+
+    /// Returns the subtypes.
+    pub fn subtypes(&self) -> &[String] {
+        &self.subtypes
+    }
+
+    /// Returns whether this structural variant is a subtype of the given structural variant.
+    ///
+    /// This is true when both have the same type and the subtypes of the given structural variant
+    /// are a prefix of the subtypes of this one. A structural variant is a subtype of itself.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record::alternate_bases::symbol::StructuralVariant;
+    ///
+    /// let dup_tandem: StructuralVariant = "DUP:TANDEM".parse()?;
+    /// let dup: StructuralVariant = "DUP".parse()?;
+    /// let del: StructuralVariant = "DEL".parse()?;
+    ///
+    /// assert!(dup_tandem.is_subtype_of(&dup));
+    /// assert!(!dup.is_subtype_of(&dup_tandem));
+    /// assert!(!dup_tandem.is_subtype_of(&del));
+    /// # Ok::<_, noodles_vcf::variant::record::alternate_bases::symbol::structural_variant::ParseError>(())
+    /// ```
+    pub fn is_subtype_of(&self, other: &Self) -> bool {
+        self.ty == other.ty && self.subtypes.starts_with(&other.subtypes)
+    }
+}
+
+impl fmt::Display for StructuralVariant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.ty)?;
+
+        for subtype in &self.subtypes {
+            write!(f, "{DELIMITER}{subtype}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// An error returned when a raw structural variant fails to parse.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The input is empty.
+    Empty,
+    /// The type is invalid.
+    InvalidType(TypeParseError),
+    /// A subtype is empty.
+    EmptySubtype,
+}
+
+impl error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        match self {
+            Self::InvalidType(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => f.write_str("empty input"),
+            Self::InvalidType(_) => f.write_str("invalid type"),
+            Self::EmptySubtype => f.write_str("empty subtype"),
+        }
+    }
+}
+
+impl FromStr for StructuralVariant {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty);
+        }
+
+        let mut components = s.split(DELIMITER);
+
+        let ty = components
+            .next()
+            .unwrap_or_default()
+            .parse()
+            .map_err(ParseError::InvalidType)?;
+
+        let subtypes = components
+            .map(|subtype| {
+                if subtype.is_empty() {
+                    Err(ParseError::EmptySubtype)
+                } else {
+                    Ok(String::from(subtype))
+                }
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self::new(ty, subtypes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_is_subtype_of() -> Result<(), ParseError> {
+        let dup: StructuralVariant = "DUP".parse()?;
+        let dup_tandem: StructuralVariant = "DUP:TANDEM".parse()?;
+        let del: StructuralVariant = "DEL".parse()?;
+
+        assert!(dup_tandem.is_subtype_of(&dup));
+        assert!(dup_tandem.is_subtype_of(&dup_tandem));
+        assert!(!dup_tandem.is_subtype_of(&del));
+        assert!(!dup.is_subtype_of(&dup_tandem));
+
+        let del_me: StructuralVariant = "DEL:ME".parse()?;
+        let del_me_custom: StructuralVariant = "DEL:ME:NDLS".parse()?;
+        let del_custom: StructuralVariant = "DEL:NDLS".parse()?;
+
+        assert!(del_me_custom.is_subtype_of(&del_me));
+        assert!(del_me_custom.is_subtype_of(&del));
+        assert!(!del_me_custom.is_subtype_of(&del_custom));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_fmt() {
+        let structural_variant = StructuralVariant::new(Type::Deletion, Vec::new());
+        assert_eq!(structural_variant.to_string(), "DEL");
+
+        let structural_variant = StructuralVariant::new(
+            Type::Deletion,
+            vec![String::from("ME"), String::from("ALU")],
+        );
+        assert_eq!(structural_variant.to_string(), "DEL:ME:ALU");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(
+            "DEL".parse(),
+            Ok(StructuralVariant::new(Type::Deletion, Vec::new()))
+        );
+        assert_eq!(
+            "INS:ME:NDLS".parse(),
+            Ok(StructuralVariant::new(
+                Type::Insertion,
+                vec![String::from("ME"), String::from("NDLS")]
+            ))
+        );
+
+        assert_eq!("".parse::<StructuralVariant>(), Err(ParseError::Empty));
+        assert_eq!(
+            "NDLS".parse::<StructuralVariant>(),
+            Err(ParseError::InvalidType(TypeParseError::Invalid))
+        );
+        assert_eq!(
+            ":ME".parse::<StructuralVariant>(),
+            Err(ParseError::InvalidType(TypeParseError::Empty))
+        );
+        assert_eq!(
+            "DEL::ALU".parse::<StructuralVariant>(),
+            Err(ParseError::EmptySubtype)
+        );
+    }
+}
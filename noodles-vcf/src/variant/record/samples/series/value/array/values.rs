@@ -92,6 +92,26 @@ fn count(s: &str) -> usize {
     if s.is_empty() {
         0
     } else {
-        s.as_bytes().iter().filter(|&&b| b == DELIMITER).count()
+        s.as_bytes().iter().filter(|&&b| b == DELIMITER).count() + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_len() {
+        fn t(src: &str, expected: usize) {
+            assert_eq!(Values::<'_, i32>::len(&src), expected);
+            assert_eq!(Values::<'_, f32>::len(&src), expected);
+            assert_eq!(Values::<'_, &str>::len(&src), expected);
+        }
+
+        t("", 0);
+        t(".", 1);
+        t("8", 1);
+        t("8,13", 2);
+        t("8,.,21", 3);
     }
 }
@@ -12,6 +12,7 @@ use std::io;
 
 use noodles_core::Position;
 
+use self::samples::sample::value::genotype::Allele;
 pub use self::{
     alternate_bases::AlternateBases, builder::Builder, filters::Filters, ids::Ids, info::Info,
     samples::Samples,
@@ -45,6 +46,29 @@ impl RecordBuf {
         Builder::default()
     }
 
+    /// Converts the record into a builder prepopulated with its fields.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf as vcf;
+    ///
+    /// let record = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::MIN)
+    ///     .set_reference_bases("A")
+    ///     .build();
+    ///
+    /// let record = record.into_builder().set_quality_score(13.0).build();
+    ///
+    /// assert_eq!(record.reference_sequence_name(), "sq0");
+    /// assert_eq!(record.quality_score(), Some(13.0));
+    /// ```
+    pub fn into_builder(self) -> Builder {
+        Builder::from(self)
+    }
+
     /// Returns the reference sequence name.
     ///
     /// # Examples
@@ -233,6 +257,59 @@ impl RecordBuf {
         &mut self.alternate_bases
     }
 
+    /// Returns the bases of a genotype allele.
+    ///
+    /// An allele position of 0 is the reference bases, and any other position `n` is the `n`th
+    /// alternate allele. This returns `None` if the allele is missing (`.`) and an
+    /// [`io::ErrorKind::InvalidInput`] error if the allele position is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{
+    ///         record::samples::series::value::genotype::Phasing,
+    ///         record_buf::{samples::sample::value::genotype::Allele, AlternateBases},
+    ///     },
+    /// };
+    ///
+    /// let record = vcf::variant::RecordBuf::builder()
+    ///     .set_reference_bases("A")
+    ///     .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+    ///     .build();
+    ///
+    /// let allele = Allele::new(Some(1), Phasing::Phased);
+    /// assert_eq!(record.allele_bases(&allele)?, Some("C"));
+    ///
+    /// let allele = Allele::new(None, Phasing::Phased);
+    /// assert!(record.allele_bases(&allele)?.is_none());
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn allele_bases(&self, allele: &Allele) -> io::Result<Option<&str>> {
+        let Some(position) = allele.position() else {
+            return Ok(None);
+        };
+
+        if position == 0 {
+            return Ok(Some(self.reference_bases()));
+        }
+
+        self.alternate_bases()
+            .as_ref()
+            .get(position - 1)
+            .map(|bases| Some(bases.as_str()))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "invalid allele position: expected <= {}, got {position}",
+                        self.alternate_bases().as_ref().len()
+                    ),
+                )
+            })
+    }
+
     /// Returns the quality score of the record.
     ///
     /// # Examples
@@ -370,6 +447,49 @@ impl RecordBuf {
         &mut self.info
     }
 
+    /// Reorders the info fields to match their header declaration order.
+    ///
+    /// Keys that are not declared in the header are moved to the end, in their insertion order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     header::record::value::{map::Info as InfoMap, Map},
+    ///     variant::{
+    ///         record::info::field::key,
+    ///         record_buf::{info::field::Value, Info},
+    ///     },
+    /// };
+    ///
+    /// let header = vcf::Header::builder()
+    ///     .add_info(key::SAMPLES_WITH_DATA_COUNT, Map::<InfoMap>::from(key::SAMPLES_WITH_DATA_COUNT))
+    ///     .add_info(key::TOTAL_DEPTH, Map::<InfoMap>::from(key::TOTAL_DEPTH))
+    ///     .build();
+    ///
+    /// let info: Info = [
+    ///     (String::from(key::TOTAL_DEPTH), Some(Value::Integer(13))),
+    ///     (String::from(key::SAMPLES_WITH_DATA_COUNT), Some(Value::Integer(3))),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder().set_info(info).build();
+    /// record.reorder_info(&header);
+    ///
+    /// let keys: Vec<_> = record.info().keys().map(|key| key.as_str()).collect();
+    /// assert_eq!(keys, [key::SAMPLES_WITH_DATA_COUNT, key::TOTAL_DEPTH]);
+    /// ```
+    pub fn reorder_info(&mut self, header: &Header) {
+        let infos = header.infos();
+        let header_index = |key: &String| infos.get_index_of(key).unwrap_or(usize::MAX);
+
+        self.info
+            .as_mut()
+            .sort_by(|a, _, b, _| header_index(a).cmp(&header_index(b)));
+    }
+
     /// Returns the format of the genotypes of the record.
     ///
     /// # Examples
@@ -402,6 +522,41 @@ impl RecordBuf {
         self.samples.keys()
     }
 
+    /// Serializes the record as a single VCF line.
+    ///
+    /// This is the same line a [`crate::io::Writer`] writes for the record, without the trailing
+    /// newline.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_core::Position;
+    /// use noodles_vcf::{self as vcf, variant::RecordBuf};
+    ///
+    /// let header = vcf::Header::default();
+    ///
+    /// let record = RecordBuf::builder()
+    ///     .set_reference_sequence_name("sq0")
+    ///     .set_variant_start(Position::MIN)
+    ///     .set_reference_bases("A")
+    ///     .build();
+    ///
+    /// assert_eq!(record.to_vcf_line(&header)?, "sq0\t1\t.\tA\t.\t.\t.\t.");
+    /// # Ok::<_, std::io::Error>(())
+    /// ```
+    pub fn to_vcf_line(&self, header: &Header) -> io::Result<String> {
+        use crate::io::writer::{write_record, RecordOptions};
+
+        let mut buf = Vec::new();
+        write_record(&mut buf, header, self, &RecordOptions::default())?;
+
+        if buf.ends_with(b"\n") {
+            buf.pop();
+        }
+
+        String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
     /// Returns the genotypes of the record.
     ///
     /// # Examples
@@ -465,6 +620,135 @@ impl RecordBuf {
     pub fn samples_mut(&mut self) -> &mut Samples {
         &mut self.samples
     }
+
+    /// Appends the values of a sample.
+    ///
+    /// Values are given as (key, value) pairs. Series in the record without a value for the new
+    /// sample are filled with missing values. Keys that are not in the record are added as new
+    /// series, and existing samples are given missing values for them.
+    ///
+    /// The sample name should be added to the header, e.g., using [`Header::add_sample_name`], to
+    /// keep the number of samples consistent.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{
+    ///         record::samples::keys::key,
+    ///         record_buf::{samples::sample::Value, Samples},
+    ///     },
+    /// };
+    ///
+    /// let keys = [String::from(key::GENOTYPE)].into_iter().collect();
+    /// let samples = Samples::new(keys, vec![vec![Some(Value::from("0|0"))]]);
+    ///
+    /// let mut record = vcf::variant::RecordBuf::builder()
+    ///     .set_samples(samples)
+    ///     .build();
+    ///
+    /// record.push_sample([(
+    ///     String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+    ///     Some(Value::from(13)),
+    /// )]);
+    ///
+    /// let samples = record.samples();
+    /// assert_eq!(samples.keys().as_ref().len(), 2);
+    ///
+    /// let sample = samples.get_index(1).expect("missing sample");
+    /// assert_eq!(sample.values(), [None, Some(Value::from(13))]);
+    /// ```
+    pub fn push_sample<I>(&mut self, values: I)
+    where
+        I: IntoIterator<Item = (String, Option<samples::sample::Value>)>,
+    {
+        let samples = &mut self.samples;
+        let mut sample = vec![None; samples.keys.as_ref().len()];
+
+        for (key, value) in values {
+            let (i, inserted) = samples.keys.as_mut().insert_full(key);
+
+            if inserted {
+                for values in &mut samples.values {
+                    values.resize(i, None);
+                    values.push(None);
+                }
+
+                sample.push(value);
+            } else {
+                sample[i] = value;
+            }
+        }
+
+        samples.values.push(sample);
+    }
+
+    /// Returns whether this record is semantically equal to another record.
+    ///
+    /// Positional fields (`CHROM`, `POS`, `ID`, `REF`, `ALT`, `QUAL`, and `FILTER`) are compared
+    /// exactly. The info fields and the fields of each sample are compared by key, i.e., their
+    /// order is ignored. Samples themselves are still compared by position. A sample value that is
+    /// implicitly missing, i.e., a dropped trailing field, is equal to an explicitly missing value
+    /// (`.`).
+    ///
+    /// Unlike `==`, this is not affected by the order of the sample keys (`FORMAT`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::{
+    ///     self as vcf,
+    ///     variant::{
+    ///         record::samples::keys::key,
+    ///         record_buf::{samples::sample::Value, Samples},
+    ///     },
+    /// };
+    ///
+    /// let a = vcf::variant::RecordBuf::builder()
+    ///     .set_samples(Samples::new(
+    ///         [String::from(key::GENOTYPE), String::from(key::READ_DEPTH)]
+    ///             .into_iter()
+    ///             .collect(),
+    ///         vec![vec![Some(Value::from("0|0")), Some(Value::from(8))]],
+    ///     ))
+    ///     .build();
+    ///
+    /// let b = vcf::variant::RecordBuf::builder()
+    ///     .set_samples(Samples::new(
+    ///         [String::from(key::READ_DEPTH), String::from(key::GENOTYPE)]
+    ///             .into_iter()
+    ///             .collect(),
+    ///         vec![vec![Some(Value::from(8)), Some(Value::from("0|0"))]],
+    ///     ))
+    ///     .build();
+    ///
+    /// assert!(a != b);
+    /// assert!(a.semantically_eq(&b));
+    /// ```
+    pub fn semantically_eq(&self, other: &Self) -> bool {
+        self.reference_sequence_name == other.reference_sequence_name
+            && self.variant_start == other.variant_start
+            && self.ids == other.ids
+            && self.reference_bases == other.reference_bases
+            && self.alternate_bases == other.alternate_bases
+            && self.quality_score == other.quality_score
+            && self.filters == other.filters
+            && self.info == other.info
+            && samples_semantically_eq(&self.samples, &other.samples)
+    }
+}
+
+fn samples_semantically_eq(a: &Samples, b: &Samples) -> bool {
+    let keys = a.keys();
+
+    keys == b.keys()
+        && a.values.len() == b.values.len()
+        && a.values().zip(b.values()).all(|(sample_a, sample_b)| {
+            keys.as_ref()
+                .iter()
+                .all(|key| sample_a.get(key).flatten() == sample_b.get(key).flatten())
+        })
 }
 
 impl Default for RecordBuf {
@@ -539,4 +823,301 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_reorder_info() {
+        use crate::{
+            header::record::value::{map::Info as InfoMap, Map},
+            variant::record::info::field::key,
+        };
+
+        let header = Header::builder()
+            .add_info(key::IS_IN_DB_SNP, Map::<InfoMap>::from(key::IS_IN_DB_SNP))
+            .add_info(
+                key::SAMPLES_WITH_DATA_COUNT,
+                Map::<InfoMap>::from(key::SAMPLES_WITH_DATA_COUNT),
+            )
+            .add_info(key::TOTAL_DEPTH, Map::<InfoMap>::from(key::TOTAL_DEPTH))
+            .build();
+
+        let info = [
+            (String::from("nd0"), None),
+            (String::from(key::TOTAL_DEPTH), None),
+            (String::from(key::IS_IN_DB_SNP), None),
+            (String::from("nd1"), None),
+            (String::from(key::SAMPLES_WITH_DATA_COUNT), None),
+        ]
+        .into_iter()
+        .collect();
+
+        let mut record = RecordBuf::builder().set_info(info).build();
+        record.reorder_info(&header);
+
+        let actual: Vec<_> = record.info().keys().map(|key| key.as_str()).collect();
+
+        let expected = [
+            key::IS_IN_DB_SNP,
+            key::SAMPLES_WITH_DATA_COUNT,
+            key::TOTAL_DEPTH,
+            "nd0",
+            "nd1",
+        ];
+
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn test_into_builder() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::variant::{
+            record::{info::field::key as info_key, samples::keys::key as format_key},
+            record_buf::{info::field::Value as InfoValue, samples::sample::Value},
+        };
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_ids([String::from("nd0")].into_iter().collect())
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .set_quality_score(13.0)
+            .set_filters(Filters::pass())
+            .set_info(
+                [(
+                    String::from(info_key::SAMPLES_WITH_DATA_COUNT),
+                    Some(InfoValue::from(1)),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                [String::from(format_key::GENOTYPE)].into_iter().collect(),
+                vec![vec![Some(Value::from("0|1"))]],
+            ))
+            .build();
+
+        assert_eq!(record.clone().into_builder().build(), record);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_to_vcf_line() -> Result<(), Box<dyn std::error::Error>> {
+        use crate::{
+            header::record::value::{
+                map::{Contig, Format, Info as InfoMap},
+                Map,
+            },
+            io::Writer,
+            variant::{
+                io::Write,
+                record::{info::field::key as info_key, samples::keys::key as format_key},
+                record_buf::{info::field::Value as InfoValue, samples::sample::Value},
+            },
+        };
+
+        let header = Header::builder()
+            .add_contig("sq0", Map::<Contig>::new())
+            .add_info(
+                info_key::TOTAL_DEPTH,
+                Map::<InfoMap>::from(info_key::TOTAL_DEPTH),
+            )
+            .add_format(
+                format_key::GENOTYPE,
+                Map::<Format>::from(format_key::GENOTYPE),
+            )
+            .add_sample_name("sample0")
+            .build();
+
+        let record = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::try_from(8)?)
+            .set_ids([String::from("nd0")].into_iter().collect())
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![String::from("C")]))
+            .set_quality_score(13.0)
+            .set_filters(Filters::pass())
+            .set_info(
+                [(
+                    String::from(info_key::TOTAL_DEPTH),
+                    Some(InfoValue::from(5)),
+                )]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                [String::from(format_key::GENOTYPE)].into_iter().collect(),
+                vec![vec![Some(Value::from("0|1"))]],
+            ))
+            .build();
+
+        let mut writer = Writer::new(Vec::new());
+        writer.write_header(&header)?;
+        let header_len = writer.get_ref().len();
+        writer.write_variant_record(&header, &record)?;
+        let expected = String::from_utf8(writer.get_ref()[header_len..].to_vec())?;
+
+        let actual = record.to_vcf_line(&header)?;
+
+        assert_eq!(actual, "sq0\t8\tnd0\tA\tC\t13\tPASS\tDP=5\tGT\t0|1");
+        assert_eq!(format!("{actual}\n"), expected);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_push_sample() {
+        use crate::variant::record::samples::keys::key;
+        use samples::{sample::Value, Keys};
+
+        let keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+        ]
+        .into_iter()
+        .collect();
+
+        let samples = Samples::new(
+            keys,
+            vec![
+                vec![Some(Value::from("0|0")), Some(Value::from(13))],
+                vec![Some(Value::from("0/1")), Some(Value::from(8))],
+            ],
+        );
+
+        let mut record = RecordBuf::builder().set_samples(samples).build();
+
+        record.push_sample([
+            (String::from(key::GENOTYPE), Some(Value::from("1/1"))),
+            (String::from(key::READ_DEPTH), Some(Value::from(21))),
+        ]);
+
+        let expected_keys: Keys = [
+            String::from(key::GENOTYPE),
+            String::from(key::CONDITIONAL_GENOTYPE_QUALITY),
+            String::from(key::READ_DEPTH),
+        ]
+        .into_iter()
+        .collect();
+
+        let expected = Samples::new(
+            expected_keys,
+            vec![
+                vec![Some(Value::from("0|0")), Some(Value::from(13)), None],
+                vec![Some(Value::from("0/1")), Some(Value::from(8)), None],
+                vec![Some(Value::from("1/1")), None, Some(Value::from(21))],
+            ],
+        );
+
+        assert_eq!(record.samples(), &expected);
+    }
+
+    #[test]
+    fn test_semantically_eq() {
+        use crate::variant::record::{info::field::key as info_key, samples::keys::key};
+        use info::field::Value as InfoValue;
+        use samples::sample::Value;
+
+        let a = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_info(
+                [
+                    (
+                        String::from(info_key::TOTAL_DEPTH),
+                        Some(InfoValue::from(13)),
+                    ),
+                    (String::from(info_key::IS_IN_DB_SNP), Some(InfoValue::Flag)),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                [String::from(key::GENOTYPE), String::from(key::READ_DEPTH)]
+                    .into_iter()
+                    .collect(),
+                vec![
+                    vec![Some(Value::from("0|0")), Some(Value::from(8))],
+                    vec![Some(Value::from("0/1")), None],
+                ],
+            ))
+            .build();
+
+        let b = RecordBuf::builder()
+            .set_reference_sequence_name("sq0")
+            .set_variant_start(Position::MIN)
+            .set_reference_bases("A")
+            .set_info(
+                [
+                    (String::from(info_key::IS_IN_DB_SNP), Some(InfoValue::Flag)),
+                    (
+                        String::from(info_key::TOTAL_DEPTH),
+                        Some(InfoValue::from(13)),
+                    ),
+                ]
+                .into_iter()
+                .collect(),
+            )
+            .set_samples(Samples::new(
+                [String::from(key::READ_DEPTH), String::from(key::GENOTYPE)]
+                    .into_iter()
+                    .collect(),
+                vec![
+                    vec![Some(Value::from(8)), Some(Value::from("0|0"))],
+                    vec![None, Some(Value::from("0/1"))],
+                ],
+            ))
+            .build();
+
+        assert_ne!(a, b);
+        assert!(a.semantically_eq(&b));
+        assert!(b.semantically_eq(&a));
+
+        let mut c = b.clone();
+        *c.variant_start_mut() = Position::new(2);
+        assert!(!a.semantically_eq(&c));
+
+        let mut c = b.clone();
+        c.info_mut().insert(
+            String::from(info_key::TOTAL_DEPTH),
+            Some(InfoValue::from(21)),
+        );
+        assert!(!a.semantically_eq(&c));
+
+        let mut c = b.clone();
+        c.samples_mut().values.swap(0, 1);
+        assert!(!a.semantically_eq(&c));
+
+        // implicit (dropped trailing field) and explicit missing values
+        let mut c = a.clone();
+        c.samples_mut().values[1].pop();
+        assert_ne!(a, c);
+        assert!(a.semantically_eq(&c));
+        assert!(c.semantically_eq(&b));
+    }
+
+    #[test]
+    fn test_allele_bases() {
+        use crate::variant::record::samples::series::value::genotype::Phasing;
+
+        let record = RecordBuf::builder()
+            .set_reference_bases("A")
+            .set_alternate_bases(AlternateBases::from(vec![
+                String::from("C"),
+                String::from("GT"),
+            ]))
+            .build();
+
+        let allele = |position| Allele::new(position, Phasing::Unphased);
+
+        assert_eq!(record.allele_bases(&allele(Some(0))).ok(), Some(Some("A")));
+        assert_eq!(record.allele_bases(&allele(Some(1))).ok(), Some(Some("C")));
+        assert_eq!(record.allele_bases(&allele(Some(2))).ok(), Some(Some("GT")));
+        assert_eq!(record.allele_bases(&allele(None)).ok(), Some(None));
+
+        assert!(matches!(
+            record.allele_bases(&allele(Some(3))),
+            Err(e) if e.kind() == io::ErrorKind::InvalidInput
+        ));
+    }
 }
@@ -244,6 +244,22 @@ impl Builder {
     }
 }
 
+impl From<RecordBuf> for Builder {
+    fn from(record: RecordBuf) -> Self {
+        Self {
+            reference_sequence_name: record.reference_sequence_name,
+            variant_start: record.variant_start,
+            ids: record.ids,
+            reference_bases: record.reference_bases,
+            alternate_bases: record.alternate_bases,
+            quality_score: record.quality_score,
+            filters: record.filters,
+            info: record.info,
+            samples: record.samples,
+        }
+    }
+}
+
 impl Default for Builder {
     fn default() -> Self {
         Self {
@@ -43,6 +43,91 @@ impl Filters {
             .map(|filter| filter == PASS)
             .unwrap_or_default()
     }
+
+    /// Adds a filter.
+    ///
+    /// Adding a failed filter removes `PASS`. Adding `PASS` only sets it when there are no failed
+    /// filters.
+    ///
+    /// This returns whether the filter was added.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::Filters;
+    ///
+    /// let mut filters = Filters::pass();
+    /// assert!(filters.add_filter("q10"));
+    /// assert_eq!(filters, [String::from("q10")].into_iter().collect());
+    ///
+    /// assert!(!filters.add_filter("PASS"));
+    /// assert_eq!(filters, [String::from("q10")].into_iter().collect());
+    /// ```
+    pub fn add_filter<I>(&mut self, id: I) -> bool
+    where
+        I: Into<String>,
+    {
+        let id = id.into();
+
+        if id == PASS {
+            if self.0.is_empty() {
+                self.0.insert(id)
+            } else {
+                false
+            }
+        } else {
+            self.0.shift_remove(PASS);
+            self.0.insert(id)
+        }
+    }
+
+    /// Removes a filter.
+    ///
+    /// This returns whether the filter was present. Removing the last filter leaves the filters
+    /// empty, i.e., missing. It does not set `PASS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::Filters;
+    ///
+    /// let mut filters: Filters = [String::from("q10"), String::from("s50")]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// assert!(filters.remove_filter("q10"));
+    /// assert!(!filters.remove_filter("q10"));
+    /// assert!(filters.remove_filter("s50"));
+    /// assert!(filters.as_ref().is_empty());
+    /// ```
+    pub fn remove_filter(&mut self, id: &str) -> bool {
+        self.0.shift_remove(id)
+    }
+
+    /// Adds all filters from another set of filters.
+    ///
+    /// Each filter is added using [`Self::add_filter`], i.e., failed filters take precedence over
+    /// `PASS`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::Filters;
+    ///
+    /// let mut filters: Filters = [String::from("q10")].into_iter().collect();
+    /// let other: Filters = [String::from("s50")].into_iter().collect();
+    /// filters.union_with(&other);
+    ///
+    /// assert_eq!(
+    ///     filters,
+    ///     [String::from("q10"), String::from("s50")].into_iter().collect()
+    /// );
+    /// ```
+    pub fn union_with(&mut self, other: &Self) {
+        for id in &other.0 {
+            self.add_filter(id.clone());
+        }
+    }
 }
 
 impl AsRef<IndexSet<String>> for Filters {
@@ -104,3 +189,66 @@ impl crate::variant::record::Filters for &Filters {
         Box::new(self.0.iter().map(|filter| Ok(filter.as_ref())))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_filter() {
+        let mut filters = Filters::pass();
+        assert!(filters.add_filter("q10"));
+        assert_eq!(filters, [String::from("q10")].into_iter().collect());
+        assert!(!filters.is_pass());
+
+        assert!(!filters.add_filter("q10"));
+        assert!(!filters.add_filter(PASS));
+        assert_eq!(filters, [String::from("q10")].into_iter().collect());
+
+        let mut filters = Filters::default();
+        assert!(filters.add_filter(PASS));
+        assert!(filters.is_pass());
+    }
+
+    #[test]
+    fn test_remove_filter() {
+        let mut filters: Filters = [String::from("q10"), String::from("s50")]
+            .into_iter()
+            .collect();
+
+        assert!(filters.remove_filter("q10"));
+        assert_eq!(filters, [String::from("s50")].into_iter().collect());
+
+        assert!(filters.remove_filter("s50"));
+        assert_eq!(filters, Filters::default());
+
+        assert!(!filters.remove_filter("s50"));
+    }
+
+    #[test]
+    fn test_union_with() {
+        let mut filters: Filters = [String::from("q10"), String::from("s50")]
+            .into_iter()
+            .collect();
+        let other: Filters = [String::from("s50"), String::from("dp5")]
+            .into_iter()
+            .collect();
+        filters.union_with(&other);
+
+        let expected = [
+            String::from("q10"),
+            String::from("s50"),
+            String::from("dp5"),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(filters, expected);
+
+        let mut filters = Filters::pass();
+        filters.union_with(&Filters::pass());
+        assert!(filters.is_pass());
+
+        filters.union_with(&[String::from("q10")].into_iter().collect());
+        assert_eq!(filters, [String::from("q10")].into_iter().collect());
+    }
+}
@@ -169,6 +169,94 @@ impl Info {
         self.0.insert(key, value)
     }
 
+    /// Returns whether the flag with the given key is set.
+    ///
+    /// A flag is set when the field is present and its value is [`Value::Flag`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::info::field::key,
+    ///     record_buf::{info::field::Value, Info},
+    /// };
+    ///
+    /// let db = (String::from(key::IS_IN_DB_SNP), Some(Value::Flag));
+    /// let info: Info = [db].into_iter().collect();
+    ///
+    /// assert!(info.has_flag(key::IS_IN_DB_SNP));
+    /// assert!(!info.has_flag(key::IS_SOMATIC_MUTATION));
+    /// ```
+    pub fn has_flag<K>(&self, key: &K) -> bool
+    where
+        K: Hash + indexmap::Equivalent<String> + ?Sized,
+    {
+        matches!(self.0.get(key), Some(Some(Value::Flag)))
+    }
+
+    /// Sets the flag with the given key.
+    ///
+    /// If the field is already present, its value is replaced with [`Value::Flag`], and its
+    /// position is preserved. This returns whether the flag was not already set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::{info::field::key, Info as _},
+    ///     record_buf::Info,
+    /// };
+    ///
+    /// let mut info = Info::default();
+    ///
+    /// assert!(info.set_flag(key::IS_IN_DB_SNP));
+    /// assert!(info.has_flag(key::IS_IN_DB_SNP));
+    ///
+    /// assert!(!info.set_flag(key::IS_IN_DB_SNP));
+    /// assert_eq!(info.len(), 1);
+    /// ```
+    pub fn set_flag<K>(&mut self, key: K) -> bool
+    where
+        K: Into<String>,
+    {
+        !matches!(
+            self.0.insert(key.into(), Some(Value::Flag)),
+            Some(Some(Value::Flag))
+        )
+    }
+
+    /// Clears the flag with the given key.
+    ///
+    /// The field is removed if its value is [`Value::Flag`]. Fields with other values are not
+    /// removed. This returns whether the flag was set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::info::field::key,
+    ///     record_buf::{info::field::Value, Info},
+    /// };
+    ///
+    /// let db = (String::from(key::IS_IN_DB_SNP), Some(Value::Flag));
+    /// let mut info: Info = [db].into_iter().collect();
+    ///
+    /// assert!(info.clear_flag(key::IS_IN_DB_SNP));
+    /// assert!(!info.has_flag(key::IS_IN_DB_SNP));
+    /// assert!(!info.clear_flag(key::IS_IN_DB_SNP));
+    /// ```
+    pub fn clear_flag<K>(&mut self, key: &K) -> bool
+    where
+        K: Hash + indexmap::Equivalent<String> + ?Sized,
+    {
+        if self.has_flag(key) {
+            self.0.shift_remove(key);
+            true
+        } else {
+            false
+        }
+    }
+
     /// Returns an iterator over all keys.
     ///
     /// # Examples
@@ -351,4 +439,34 @@ mod tests {
 
         assert_eq!(info, expected);
     }
+
+    #[test]
+    fn test_set_flag_and_clear_flag() {
+        let mut info: Info = [
+            (String::from(key::TOTAL_DEPTH), Some(Value::from(13))),
+            (String::from(key::IS_SOMATIC_MUTATION), Some(Value::Flag)),
+        ]
+        .into_iter()
+        .collect();
+
+        assert!(!info.has_flag(key::IS_IN_DB_SNP));
+
+        assert!(info.set_flag(key::IS_IN_DB_SNP));
+        assert!(info.has_flag(key::IS_IN_DB_SNP));
+        assert!(!info.set_flag(key::IS_IN_DB_SNP));
+        assert!(info.keys().eq([
+            key::TOTAL_DEPTH,
+            key::IS_SOMATIC_MUTATION,
+            key::IS_IN_DB_SNP
+        ]));
+
+        assert!(info.clear_flag(key::IS_IN_DB_SNP));
+        assert!(!info.has_flag(key::IS_IN_DB_SNP));
+        assert!(!info.clear_flag(key::IS_IN_DB_SNP));
+        assert!(info.keys().eq([key::TOTAL_DEPTH, key::IS_SOMATIC_MUTATION]));
+
+        assert!(!info.has_flag(key::TOTAL_DEPTH));
+        assert!(!info.clear_flag(key::TOTAL_DEPTH));
+        assert_eq!(info.get(key::TOTAL_DEPTH), Some(Some(&Value::from(13))));
+    }
 }
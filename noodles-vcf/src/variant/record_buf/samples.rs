@@ -8,6 +8,7 @@ use std::io;
 
 use self::sample::Value;
 pub use self::{keys::Keys, sample::Sample, series::Series};
+use super::AlternateBases;
 use crate::Header;
 
 /// A variant record samples buffer.
@@ -182,6 +183,111 @@ impl Samples {
             Series::new(name, &self.values[..], i)
         })
     }
+
+    /// Returns the allele counts across all samples.
+    ///
+    /// This counts each allele index in the genotype (`GT`) of every sample. The resulting list
+    /// is indexed by allele, where index 0 is the reference allele, and its length is the number
+    /// of alleles, i.e., 1 (REF) + the number of alternate bases (ALT). Missing alleles (`.`) and
+    /// samples without a genotype are not counted.
+    ///
+    /// An error is returned if a genotype has an allele index greater than the number of alternate
+    /// bases.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::{samples::sample::Value, AlternateBases, Samples},
+    /// };
+    ///
+    /// let keys = [String::from(key::GENOTYPE)].into_iter().collect();
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![
+    ///         vec![Some(Value::Genotype("0/1".parse()?))],
+    ///         vec![Some(Value::Genotype("1/1".parse()?))],
+    ///     ],
+    /// );
+    ///
+    /// let alternate_bases = AlternateBases::from(vec![String::from("C"), String::from("G")]);
+    /// assert_eq!(samples.allele_counts(&alternate_bases)?, [1, 3, 0]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_counts(&self, alternate_bases: &AlternateBases) -> io::Result<Vec<usize>> {
+        use crate::variant::record::samples::keys::key;
+
+        let mut counts = vec![0; alternate_bases.as_ref().len() + 1];
+
+        let Some(series) = self.select(key::GENOTYPE) else {
+            return Ok(counts);
+        };
+
+        for i in 0..self.values.len() {
+            let Some(Some(Value::Genotype(genotype))) = series.get(i) else {
+                continue;
+            };
+
+            for position in genotype
+                .as_ref()
+                .iter()
+                .filter_map(|allele| allele.position())
+            {
+                let count = counts.get_mut(position).ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidData,
+                        format!("invalid allele index: {position}"),
+                    )
+                })?;
+
+                *count += 1;
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Returns the allele frequencies across all samples.
+    ///
+    /// Each frequency is the allele count (see [`Self::allele_counts`]) divided by the total
+    /// number of called alleles. Missing alleles are excluded from both. If there are no called
+    /// alleles, this returns an empty list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::{samples::sample::Value, AlternateBases, Samples},
+    /// };
+    ///
+    /// let keys = [String::from(key::GENOTYPE)].into_iter().collect();
+    /// let samples = Samples::new(
+    ///     keys,
+    ///     vec![
+    ///         vec![Some(Value::Genotype("0/1".parse()?))],
+    ///         vec![Some(Value::Genotype("1/1".parse()?))],
+    ///     ],
+    /// );
+    ///
+    /// let alternate_bases = AlternateBases::from(vec![String::from("C")]);
+    /// assert_eq!(samples.allele_frequencies(&alternate_bases)?, [0.25, 0.75]);
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn allele_frequencies(&self, alternate_bases: &AlternateBases) -> io::Result<Vec<f32>> {
+        let counts = self.allele_counts(alternate_bases)?;
+        let total: usize = counts.iter().sum();
+
+        if total == 0 {
+            return Ok(Vec::new());
+        }
+
+        Ok(counts
+            .into_iter()
+            .map(|n| n as f32 / total as f32)
+            .collect())
+    }
 }
 
 impl crate::variant::record::Samples for Samples {
@@ -285,3 +391,63 @@ impl From<Samples> for (Keys, Vec<Vec<Option<Value>>>) {
         (samples.keys, samples.values)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build_samples() -> Result<Samples, Box<dyn std::error::Error>> {
+        use crate::variant::record::samples::keys::key;
+
+        let keys = [String::from(key::GENOTYPE)].into_iter().collect();
+
+        Ok(Samples::new(
+            keys,
+            vec![
+                vec![Some(Value::Genotype("0/1".parse()?))],
+                vec![Some(Value::Genotype("1/1".parse()?))],
+                vec![Some(Value::Genotype("./.".parse()?))],
+            ],
+        ))
+    }
+
+    #[test]
+    fn test_allele_counts() -> Result<(), Box<dyn std::error::Error>> {
+        let samples = build_samples()?;
+
+        let alternate_bases = AlternateBases::from(vec![String::from("C")]);
+        assert_eq!(samples.allele_counts(&alternate_bases)?, [1, 3]);
+
+        let alternate_bases = AlternateBases::from(vec![String::from("C"), String::from("G")]);
+        assert_eq!(samples.allele_counts(&alternate_bases)?, [1, 3, 0]);
+
+        assert!(matches!(
+            samples.allele_counts(&AlternateBases::default()),
+            Err(e) if e.kind() == io::ErrorKind::InvalidData
+        ));
+
+        assert_eq!(
+            Samples::default().allele_counts(&AlternateBases::default())?,
+            [0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_allele_frequencies() -> Result<(), Box<dyn std::error::Error>> {
+        let samples = build_samples()?;
+
+        let alternate_bases = AlternateBases::from(vec![String::from("C"), String::from("G")]);
+        assert_eq!(
+            samples.allele_frequencies(&alternate_bases)?,
+            [0.25, 0.75, 0.0]
+        );
+
+        assert!(Samples::default()
+            .allele_frequencies(&AlternateBases::default())?
+            .is_empty());
+
+        Ok(())
+    }
+}
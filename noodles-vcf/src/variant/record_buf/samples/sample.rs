@@ -41,6 +41,180 @@ impl<'g> Sample<'g> {
             .get_index_of(key)
             .and_then(|i| self.values.get(i).map(|value| value.as_ref()))
     }
+
+    /// Returns the phase set of the sample.
+    ///
+    /// This is the phase set ID (`PS`) paired with whether the genotype (`GT`) is phased within
+    /// that set. A phase set is only meaningful for phased genotypes, so records with the same
+    /// phase set ID should only be grouped into a phase block when their genotypes are phased.
+    ///
+    /// This returns `None` if the genotype or phase set ID is missing or not of the expected
+    /// type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::samples::{sample::Value, Keys, Sample},
+    /// };
+    ///
+    /// let keys: Keys = [
+    ///     String::from(key::GENOTYPE),
+    ///     String::from(key::PHASE_SET),
+    /// ]
+    /// .into_iter()
+    /// .collect();
+    /// let values = [Some(Value::Genotype("0|1".parse()?)), Some(Value::from(8))];
+    ///
+    /// let sample = Sample::new(&keys, &values);
+    /// assert_eq!(sample.phase_set(), Some((8, true)));
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn phase_set(&self) -> Option<(i32, bool)> {
+        use crate::variant::record::samples::keys::key;
+
+        let is_phased = match self.get(key::GENOTYPE) {
+            Some(Some(Value::Genotype(genotype))) => genotype.is_phased(),
+            _ => return None,
+        };
+
+        match self.get(key::PHASE_SET) {
+            Some(Some(Value::Integer(id))) => Some((*id, is_phased)),
+            _ => None,
+        }
+    }
+
+    /// Returns the Phred-scaled genotype likelihoods (`PL`) of the sample.
+    ///
+    /// This returns `None` if the field is missing or not an integer array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::samples::{sample::{value::Array, Value}, Keys, Sample},
+    /// };
+    ///
+    /// let keys: Keys = [String::from(key::ROUNDED_GENOTYPE_LIKELIHOODS)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let values = [Some(Value::Array(Array::Integer(vec![Some(10), Some(0), Some(25)])))];
+    ///
+    /// let sample = Sample::new(&keys, &values);
+    /// assert_eq!(
+    ///     sample.rounded_genotype_likelihoods(),
+    ///     Some(&[Some(10), Some(0), Some(25)][..])
+    /// );
+    /// ```
+    pub fn rounded_genotype_likelihoods(&self) -> Option<&'g [Option<i32>]> {
+        use self::value::Array;
+        use crate::variant::record::samples::keys::key;
+
+        match self.get(key::ROUNDED_GENOTYPE_LIKELIHOODS) {
+            Some(Some(Value::Array(Array::Integer(values)))) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the log10-scaled genotype likelihoods (`GL`) of the sample.
+    ///
+    /// This returns `None` if the field is missing or not a float array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::samples::{sample::{value::Array, Value}, Keys, Sample},
+    /// };
+    ///
+    /// let keys: Keys = [String::from(key::GENOTYPE_LIKELIHOODS)].into_iter().collect();
+    /// let values = [Some(Value::Array(Array::Float(vec![Some(-1.0), Some(0.0), Some(-2.5)])))];
+    ///
+    /// let sample = Sample::new(&keys, &values);
+    /// assert_eq!(
+    ///     sample.genotype_likelihoods(),
+    ///     Some(&[Some(-1.0), Some(0.0), Some(-2.5)][..])
+    /// );
+    /// ```
+    pub fn genotype_likelihoods(&self) -> Option<&'g [Option<f32>]> {
+        use self::value::Array;
+        use crate::variant::record::samples::keys::key;
+
+        match self.get(key::GENOTYPE_LIKELIHOODS) {
+            Some(Some(Value::Array(Array::Float(values)))) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the most likely diploid genotype of the sample.
+    ///
+    /// This is the genotype with the smallest Phred-scaled likelihood (`PL`) or, if `PL` is
+    /// missing, the largest log10-scaled likelihood (`GL`). Missing likelihoods are skipped, and
+    /// ties are resolved by the first genotype.
+    ///
+    /// The genotype is returned as its pair of allele indices `(j, k)`, where `j <= k`. Genotype
+    /// likelihoods of a diploid sample are ordered such that `(j, k)` is at index
+    /// `k * (k + 1) / 2 + j`, e.g., `0/0`, `0/1`, `1/1`, `0/2`, `1/2`, `2/2`, etc.
+    ///
+    /// This returns `None` if neither field is present or all likelihoods are missing.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::{
+    ///     record::samples::keys::key,
+    ///     record_buf::samples::{sample::{value::Array, Value}, Keys, Sample},
+    /// };
+    ///
+    /// let keys: Keys = [String::from(key::ROUNDED_GENOTYPE_LIKELIHOODS)]
+    ///     .into_iter()
+    ///     .collect();
+    /// let values = [Some(Value::Array(Array::Integer(vec![Some(10), Some(0), Some(25)])))];
+    ///
+    /// let sample = Sample::new(&keys, &values);
+    /// assert_eq!(sample.most_likely_genotype(), Some((0, 1)));
+    /// ```
+    pub fn most_likely_genotype(&self) -> Option<(usize, usize)> {
+        let i = if let Some(values) = self.rounded_genotype_likelihoods() {
+            values
+                .iter()
+                .enumerate()
+                .filter_map(|(i, value)| value.map(|n| (i, n)))
+                .min_by_key(|(_, n)| *n)
+                .map(|(i, _)| i)?
+        } else if let Some(values) = self.genotype_likelihoods() {
+            values
+                .iter()
+                .enumerate()
+                .filter_map(|(i, value)| value.map(|n| (i, n)))
+                .fold(None, |best: Option<(usize, f32)>, (i, n)| match best {
+                    Some((_, m)) if m >= n => best,
+                    _ => Some((i, n)),
+                })
+                .map(|(i, _)| i)?
+        } else {
+            return None;
+        };
+
+        Some(diploid_genotype_alleles(i))
+    }
+}
+
+// § 1.6.2 "Genotype fields" (2023-08-23): "...the ordering of genotypes for the likelihoods is
+// given by: F(j/k) = (k*(k+1)/2)+j."
+fn diploid_genotype_alleles(i: usize) -> (usize, usize) {
+    let mut k = 0;
+
+    while (k + 1) * (k + 2) / 2 <= i {
+        k += 1;
+    }
+
+    let j = i - k * (k + 1) / 2;
+
+    (j, k)
 }
 
 impl<'g> crate::variant::record::samples::Sample for Sample<'g> {
@@ -85,3 +259,121 @@ impl<'g> crate::variant::record::samples::Sample for Sample<'g> {
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::variant::record::samples::keys::key;
+
+    #[test]
+    fn test_phase_set() -> Result<(), Box<dyn std::error::Error>> {
+        let keys: Keys = [String::from(key::GENOTYPE), String::from(key::PHASE_SET)]
+            .into_iter()
+            .collect();
+
+        let records = [
+            [Some(Value::Genotype("0|1".parse()?)), Some(Value::from(8))],
+            [Some(Value::Genotype("1|0".parse()?)), Some(Value::from(8))],
+            [Some(Value::Genotype("0/1".parse()?)), Some(Value::from(8))],
+            [Some(Value::Genotype("0|1".parse()?)), Some(Value::from(13))],
+        ];
+
+        let mut phase_blocks: Vec<(i32, Vec<usize>)> = Vec::new();
+
+        for (i, values) in records.iter().enumerate() {
+            let sample = Sample::new(&keys, values);
+
+            if let Some((id, true)) = sample.phase_set() {
+                match phase_blocks
+                    .iter_mut()
+                    .find(|(block_id, _)| *block_id == id)
+                {
+                    Some((_, indices)) => indices.push(i),
+                    None => phase_blocks.push((id, vec![i])),
+                }
+            }
+        }
+
+        assert_eq!(phase_blocks, [(8, vec![0, 1]), (13, vec![3])]);
+
+        let values = [Some(Value::Genotype("0|1".parse()?)), None];
+        assert!(Sample::new(&keys, &values).phase_set().is_none());
+
+        let values = [None, Some(Value::from(8))];
+        assert!(Sample::new(&keys, &values).phase_set().is_none());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_most_likely_genotype() {
+        use value::Array;
+
+        let keys: Keys = [String::from(key::ROUNDED_GENOTYPE_LIKELIHOODS)]
+            .into_iter()
+            .collect();
+
+        let values = [Some(Value::Array(Array::Integer(vec![
+            Some(10),
+            Some(0),
+            Some(25),
+        ])))];
+        assert_eq!(
+            Sample::new(&keys, &values).most_likely_genotype(),
+            Some((0, 1))
+        );
+
+        // 0/0, 0/1, 1/1, 0/2, 1/2, 2/2
+        let values = [Some(Value::Array(Array::Integer(vec![
+            Some(40),
+            Some(30),
+            Some(50),
+            Some(20),
+            Some(0),
+            Some(60),
+        ])))];
+        assert_eq!(
+            Sample::new(&keys, &values).most_likely_genotype(),
+            Some((1, 2))
+        );
+
+        let values = [Some(Value::Array(Array::Integer(vec![None, None, None])))];
+        assert!(Sample::new(&keys, &values).most_likely_genotype().is_none());
+
+        let keys: Keys = [String::from(key::GENOTYPE_LIKELIHOODS)]
+            .into_iter()
+            .collect();
+        let values = [Some(Value::Array(Array::Float(vec![
+            Some(-2.0),
+            Some(-1.5),
+            Some(-0.1),
+        ])))];
+        assert_eq!(
+            Sample::new(&keys, &values).most_likely_genotype(),
+            Some((1, 1))
+        );
+
+        let keys = Keys::default();
+        assert!(Sample::new(&keys, &[]).most_likely_genotype().is_none());
+    }
+
+    #[test]
+    fn test_diploid_genotype_alleles() {
+        let actual: Vec<_> = (0..10).map(diploid_genotype_alleles).collect();
+
+        let expected = [
+            (0, 0),
+            (0, 1),
+            (1, 1),
+            (0, 2),
+            (1, 2),
+            (2, 2),
+            (0, 3),
+            (1, 3),
+            (2, 3),
+            (3, 3),
+        ];
+
+        assert_eq!(actual, expected);
+    }
+}
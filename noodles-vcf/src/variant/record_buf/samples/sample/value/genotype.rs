@@ -12,6 +12,32 @@ use std::{io, str::FromStr};
 #[derive(Clone, Debug, Default, Eq, PartialEq)]
 pub struct Genotype(Vec<Allele>);
 
+impl Genotype {
+    /// Returns whether the genotype is phased.
+    ///
+    /// A genotype is phased if every allele is phased, i.e., every separator is `|`. The phasing of
+    /// the first allele is implicit unless it has an explicit prefix (VCF 4.4+), e.g., `/1` is
+    /// unphased. Haploid calls without a prefix are phased.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use noodles_vcf::variant::record_buf::samples::sample::value::Genotype;
+    ///
+    /// let genotype: Genotype = "0|1".parse()?;
+    /// assert!(genotype.is_phased());
+    ///
+    /// let genotype: Genotype = "0/1".parse()?;
+    /// assert!(!genotype.is_phased());
+    /// # Ok::<_, Box<dyn std::error::Error>>(())
+    /// ```
+    pub fn is_phased(&self) -> bool {
+        self.0
+            .iter()
+            .all(|allele| allele.phasing() == Phasing::Phased)
+    }
+}
+
 impl AsRef<[Allele]> for Genotype {
     fn as_ref(&self) -> &[Allele] {
         &self.0
@@ -74,6 +100,19 @@ impl crate::variant::record::samples::series::value::Genotype for &Genotype {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_is_phased() -> Result<(), ParseError> {
+        assert!("0|1".parse::<Genotype>()?.is_phased());
+        assert!("|0|1".parse::<Genotype>()?.is_phased());
+        assert!(!"/0|1".parse::<Genotype>()?.is_phased());
+        assert!("1".parse::<Genotype>()?.is_phased());
+        assert!("|1".parse::<Genotype>()?.is_phased());
+        assert!(!"/1".parse::<Genotype>()?.is_phased());
+        assert!(!"0/1".parse::<Genotype>()?.is_phased());
+        assert!(!"0|1/2".parse::<Genotype>()?.is_phased());
+        Ok(())
+    }
+
     #[test]
     fn test_from_str() {
         assert_eq!(
@@ -137,4 +176,52 @@ mod tests {
             Err(ParseError::InvalidAllele(_))
         ));
     }
+
+    #[test]
+    fn test_from_str_with_haploid_calls() {
+        assert_eq!(
+            ".".parse(),
+            Ok(Genotype(vec![Allele::new(None, Phasing::Phased)]))
+        );
+
+        assert_eq!(
+            "1".parse(),
+            Ok(Genotype(vec![Allele::new(Some(1), Phasing::Phased)]))
+        );
+
+        assert_eq!(
+            "|1".parse(),
+            Ok(Genotype(vec![Allele::new(Some(1), Phasing::Phased)]))
+        );
+
+        assert_eq!(
+            "/1".parse(),
+            Ok(Genotype(vec![Allele::new(Some(1), Phasing::Unphased)]))
+        );
+
+        assert_eq!(
+            "|.".parse(),
+            Ok(Genotype(vec![Allele::new(None, Phasing::Phased)]))
+        );
+    }
+
+    #[test]
+    fn test_from_str_with_invalid_input() {
+        assert_eq!("".parse::<Genotype>(), Err(ParseError::Empty));
+
+        assert!(matches!(
+            "é".parse::<Genotype>(),
+            Err(ParseError::InvalidAllele(_))
+        ));
+
+        assert!(matches!(
+            "0/".parse::<Genotype>(),
+            Err(ParseError::InvalidAllele(_))
+        ));
+
+        assert!(matches!(
+            "0/é".parse::<Genotype>(),
+            Err(ParseError::InvalidAllele(_))
+        ));
+    }
 }
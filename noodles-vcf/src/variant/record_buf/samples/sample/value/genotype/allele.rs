@@ -137,13 +137,24 @@ impl FromStr for Allele {
             return Err(ParseError::Empty);
         }
 
-        let phasing = parse_phasing(&s[..1])?;
-        let position = parse_position(&s[1..])?;
+        let (raw_phasing, raw_position) = split_phasing(s);
+        let phasing = parse_phasing(raw_phasing)?;
+        let position = parse_position(raw_position)?;
 
         Ok(Allele::new(position, phasing))
     }
 }
 
+// Splits the leading phasing indicator from the position. If the first character is multibyte, it
+// cannot be a phasing indicator, and the input is returned as is.
+pub(super) fn split_phasing(s: &str) -> (&str, &str) {
+    if s.is_char_boundary(1) {
+        s.split_at(1)
+    } else {
+        (s, "")
+    }
+}
+
 pub(super) fn parse_phasing(s: &str) -> Result<Phasing, ParseError> {
     const PHASED: &str = "|";
     const UNPHASED: &str = "/";
@@ -180,5 +191,6 @@ mod tests {
             Err(ParseError::InvalidPosition(_))
         ));
         assert_eq!(":0".parse::<Allele>(), Err(ParseError::InvalidPhasing));
+        assert_eq!("é".parse::<Allele>(), Err(ParseError::InvalidPhasing));
     }
 }
@@ -93,11 +93,13 @@ fn is_phasing_indicator(c: char) -> bool {
 }
 
 fn parse_first_allele(s: &str) -> Result<(Option<usize>, Option<Phasing>), allele::ParseError> {
-    use super::allele::{parse_phasing, parse_position};
+    use super::allele::{parse_phasing, parse_position, split_phasing};
 
-    match parse_phasing(&s[..1]) {
+    let (raw_phasing, raw_position) = split_phasing(s);
+
+    match parse_phasing(raw_phasing) {
         Ok(phasing) => {
-            let position = parse_position(&s[1..])?;
+            let position = parse_position(raw_position)?;
             Ok((position, Some(phasing)))
         }
         Err(_) => {